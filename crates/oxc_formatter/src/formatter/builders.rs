@@ -475,6 +475,23 @@ impl Format<'_> for LineSuffixBoundary {
     }
 }
 
+/// Marks the position in the source code that the following text originates from.
+///
+/// This doesn't change the printed output, but the printer maps the output position of the
+/// next text to `position` when source map generation is enabled.
+pub const fn source_position(position: TextSize) -> SourcePosition {
+    SourcePosition(position)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SourcePosition(TextSize);
+
+impl Format<'_> for SourcePosition {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult<()> {
+        f.write_element(FormatElement::SourcePosition(self.0))
+    }
+}
+
 /// Marks some content with a label.
 ///
 /// This does not directly influence how this content will be printed, but some
@@ -243,6 +243,19 @@ impl<'a> Comments<'a> {
     pub fn increment_printed_count(&mut self) {
         self.printed_count += 1;
     }
+
+    /// Returns the number of comments that have been printed.
+    #[inline]
+    pub fn printed_count(&self) -> usize {
+        self.printed_count
+    }
+
+    /// Marks the first `count` comments as printed, e.g. when formatting a node on its own
+    /// whose preceding comments were printed as part of another document.
+    #[inline]
+    pub fn set_printed_count(&mut self, count: usize) {
+        self.printed_count = count;
+    }
 }
 
 #[inline]
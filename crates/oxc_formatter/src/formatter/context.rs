@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, ops::Range};
 
 use oxc_allocator::Allocator;
 use oxc_ast::{
@@ -24,9 +24,21 @@ pub struct FormatContext<'ast> {
 
     cached_function_body: Option<(Span, FormatElement<'ast>)>,
 
+    /// The elements of each top level statement in the formatted document.
+    statement_ranges: Vec<StatementRange>,
+
     allocator: &'ast Allocator,
 }
 
+/// The elements of a top level statement in a formatted document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementRange {
+    /// The range of the statement's elements in the document.
+    pub elements: Range<usize>,
+    /// The number of comments of the program printed before the statement.
+    pub printed_comments: usize,
+}
+
 impl std::fmt::Debug for FormatContext<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FormatContext")
@@ -51,6 +63,7 @@ impl<'ast> FormatContext<'ast> {
             source_type: program.source_type,
             comments: Comments::new(program.source_text, &program.comments),
             cached_function_body: None,
+            statement_ranges: Vec::new(),
             allocator,
         }
     }
@@ -99,10 +112,29 @@ impl<'ast> FormatContext<'ast> {
         self.cached_function_body = Some((body.span(), formatted));
     }
 
+    /// Returns the elements of each top level statement in the formatted document,
+    /// in the order of the statements in the program's body.
+    pub fn statement_ranges(&self) -> &[StatementRange] {
+        &self.statement_ranges
+    }
+
+    pub(crate) fn statement_ranges_mut(&mut self) -> &mut [StatementRange] {
+        &mut self.statement_ranges
+    }
+
+    pub(crate) fn push_statement_range(&mut self, range: StatementRange) {
+        self.statement_ranges.push(range);
+    }
+
     pub(crate) fn increment_printed_count(&mut self) {
         self.comments.increment_printed_count();
     }
 
+    /// Marks the first `count` comments of the program as printed.
+    pub(crate) fn set_printed_count(&mut self, count: usize) {
+        self.comments.set_printed_count(count);
+    }
+
     pub fn allocator(&self) -> &'ast Allocator {
         self.allocator
     }
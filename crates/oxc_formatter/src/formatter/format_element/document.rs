@@ -1,5 +1,5 @@
 #![expect(clippy::mutable_key_type)]
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 
 use rustc_hash::FxHashMap;
 
@@ -131,6 +131,21 @@ impl Document<'_> {
     }
 }
 
+impl<'a> Document<'a> {
+    /// Replaces the elements in `range` with `replacement`.
+    ///
+    /// # Panics
+    /// If `range` is out of the bounds of the document.
+    pub(crate) fn splice(&mut self, range: Range<usize>, replacement: Vec<FormatElement<'a>>) {
+        self.elements.splice(range, replacement);
+    }
+
+    /// Consumes `self` and returns its elements.
+    pub(crate) fn into_elements(self) -> Vec<FormatElement<'a>> {
+        self.elements
+    }
+}
+
 impl<'a> From<Vec<FormatElement<'a>>> for Document<'a> {
     fn from(elements: Vec<FormatElement<'a>>) -> Self {
         Self { elements }
@@ -46,6 +46,10 @@ pub enum FormatElement<'a> {
         slice: TokenText,
     },
 
+    /// Marks the source position of the text that follows it. The printer maps the output
+    /// position of that text to this position if source map generation is enabled.
+    SourcePosition(TextSize),
+
     /// Prevents that line suffixes move past this boundary. Forces the printer to print any pending
     /// line suffixes, potentially by inserting a hard line break.
     LineSuffixBoundary,
@@ -77,6 +81,9 @@ impl std::fmt::Debug for FormatElement<'_> {
             FormatElement::LocatedTokenText { slice, .. } => {
                 fmt.debug_tuple("LocatedTokenText").field(slice).finish()
             }
+            FormatElement::SourcePosition(position) => {
+                fmt.debug_tuple("SourcePosition").field(position).finish()
+            }
             FormatElement::LineSuffixBoundary => fmt.write_str("LineSuffixBoundary"),
             FormatElement::BestFitting(best_fitting) => {
                 fmt.debug_tuple("BestFitting").field(&best_fitting).finish()
@@ -249,7 +256,8 @@ impl FormatElements for FormatElement<'_> {
             // Traverse into the most flat version because the content is guaranteed to expand when even
             // the most flat version contains some content that forces a break.
             FormatElement::BestFitting(best_fitting) => best_fitting.most_flat().will_break(),
-            FormatElement::LineSuffixBoundary
+            FormatElement::SourcePosition(_)
+            | FormatElement::LineSuffixBoundary
             | FormatElement::Space
             | FormatElement::Tag(_)
             | FormatElement::HardSpace => false,
@@ -62,7 +62,7 @@ pub use self::comments::Comments;
 use self::printer::Printer;
 pub use self::{
     arguments::{Argument, Arguments},
-    context::FormatContext,
+    context::{FormatContext, StatementRange},
     diagnostics::{ActualStart, FormatError, InvalidDocumentError, PrintError},
     formatter::Formatter,
     state::{FormatState, FormatStateSnapshot},
@@ -104,6 +104,10 @@ impl<'a> Formatted<'a> {
 }
 
 impl Formatted<'_> {
+    /// Prints the formatted document.
+    ///
+    /// # Errors
+    /// If the document is invalid.
     pub fn print(&self) -> PrintResult<Printed> {
         let print_options = self.context.options().as_print_options();
 
@@ -117,17 +121,63 @@ impl Formatted<'_> {
         Ok(printed)
     }
 
-    pub fn print_with_indent(&self, indent: u16) -> PrintResult<Printed> {
-        todo!()
-        // let print_options = self.context.options().as_print_options();
-        // let printed = Printer::new(print_options).print_with_indent(&self.document, indent)?;
+    /// Prints the document with the top level statement at `index` replaced by `statement`,
+    /// the formatted document of a single statement.
+    ///
+    /// Returns `None` if the document has no top level statement at `index`.
+    pub fn print_with_statement(
+        &self,
+        index: usize,
+        statement: &Formatted<'_>,
+    ) -> Option<PrintResult<Printed>> {
+        let print_options = self.context.options().as_print_options();
+        let range = self.context.statement_ranges().get(index)?.elements.clone();
 
-        // let printed = match self.context.source_map() {
-        // Some(source_map) => source_map.map_printed(printed),
-        // None => printed,
-        // };
+        Some(Printer::new(print_options).print_with_replacement(
+            &self.document,
+            range,
+            statement.document.as_ref(),
+        ))
+    }
 
-        // Ok(printed)
+    /// Prints the formatted document starting at the indentation level `indent`. The first line
+    /// isn't indented, as it continues the line the document is inserted into.
+    ///
+    /// # Errors
+    /// If the document is invalid.
+    pub fn print_with_indent(&self, indent: u16) -> PrintResult<Printed> {
+        let print_options = self.context.options().as_print_options();
+        Printer::new(print_options).print_with_indent(&self.document, indent)
+    }
+}
+
+impl<'a> Formatted<'a> {
+    /// Replaces the elements of the top level statement at `index` with the elements of
+    /// `statement`, the formatted document of a single statement, so that the document can be
+    /// updated again after another statement changed.
+    ///
+    /// # Panics
+    /// If the document has no top level statement at `index`.
+    pub(crate) fn replace_statement(&mut self, index: usize, statement: Formatted<'a>) {
+        let replaced = self.context.statement_ranges()[index].clone();
+        let replacement = statement.document.into_elements();
+        let end = replaced.elements.start + replacement.len();
+        self.document.splice(replaced.elements.clone(), replacement);
+
+        let ranges = self.context.statement_ranges_mut();
+        ranges[index].elements.end = end;
+        if let Some(next) = ranges.get(index + 1) {
+            // The statements after the replaced one move by the difference in the number of
+            // elements and printed comments between the old and the new statement.
+            let old_printed_comments = next.printed_comments;
+            let new_printed_comments = statement.context.comments().printed_count();
+            for range in &mut ranges[index + 1..] {
+                range.elements = range.elements.start - replaced.elements.end + end
+                    ..range.elements.end - replaced.elements.end + end;
+                range.printed_comments =
+                    range.printed_comments - old_printed_comments + new_printed_comments;
+            }
+        }
     }
 }
 pub type PrintResult<T> = Result<T, PrintError>;
@@ -136,17 +186,28 @@ pub type PrintResult<T> = Result<T, PrintError>;
 pub struct Printed {
     code: String,
     range: Option<TextRange>,
+    sourcemap: Vec<SourceMarker>,
     verbatim_ranges: Vec<TextRange>,
 }
 
 impl Printed {
-    pub fn new(code: String, range: Option<TextRange>, verbatim_source: Vec<TextRange>) -> Self {
-        Self { code, range, verbatim_ranges: verbatim_source }
+    pub fn new(
+        code: String,
+        range: Option<TextRange>,
+        sourcemap: Vec<SourceMarker>,
+        verbatim_source: Vec<TextRange>,
+    ) -> Self {
+        Self { code, range, sourcemap, verbatim_ranges: verbatim_source }
     }
 
     /// Construct an empty formatter result
     pub fn new_empty() -> Self {
-        Self { code: String::new(), range: None, verbatim_ranges: Vec::new() }
+        Self {
+            code: String::new(),
+            range: None,
+            sourcemap: Vec::new(),
+            verbatim_ranges: Vec::new(),
+        }
     }
 
     /// Range of the input source file covered by this formatted code,
@@ -165,6 +226,20 @@ impl Printed {
         self.code
     }
 
+    /// Returns a list of [SourceMarker] mapping byte positions
+    /// in the output string to the input source code.
+    /// It's not guaranteed that the markers are sorted by source position.
+    ///
+    /// Empty unless source map generation is enabled in the printer options.
+    pub fn sourcemap(&self) -> &[SourceMarker] {
+        &self.sourcemap
+    }
+
+    /// Takes the source markers, replacing them with an empty list.
+    pub fn take_sourcemap(&mut self) -> Vec<SourceMarker> {
+        std::mem::take(&mut self.sourcemap)
+    }
+
     /// The text in the formatted code that has been formatted as verbatim.
     pub fn verbatim(&self) -> impl Iterator<Item = (TextRange, &str)> {
         panic!();
@@ -183,6 +258,15 @@ impl Printed {
     }
 }
 
+/// Lightweight sourcemap marker between source and output tokens
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SourceMarker {
+    /// Position of the marker in the original source
+    pub source: TextSize,
+    /// Position of the marker in the output code
+    pub dest: TextSize,
+}
+
 // Public return type of the formatter
 pub type FormatResult<F> = Result<F, FormatError>;
 
@@ -2,9 +2,10 @@ mod call_stack;
 mod line_suffixes;
 mod printer_options;
 mod queue;
+mod source_markers;
 mod stack;
 
-use std::num::NonZeroU8;
+use std::{num::NonZeroU8, ops::Range};
 
 pub use printer_options::*;
 use unicode_width::UnicodeWidthChar;
@@ -27,6 +28,7 @@ use super::{
         queue::{
             AllPredicate, FitsEndPredicate, FitsQueue, PrintQueue, Queue, SingleEntryPredicate,
         },
+        source_markers::SourceMarkers,
     },
 };
 use crate::options::IndentStyle;
@@ -40,7 +42,11 @@ pub struct Printer<'a> {
 
 impl<'a> Printer<'a> {
     pub fn new(options: PrinterOptions) -> Self {
-        Self { options, state: PrinterState::default() }
+        let state = PrinterState {
+            source_markers: SourceMarkers::new(options.source_map),
+            ..PrinterState::default()
+        };
+        Self { options, state }
     }
 
     /// Prints the passed in element as well as all its content
@@ -51,12 +57,36 @@ impl<'a> Printer<'a> {
     /// Prints the passed in element as well as all its content,
     /// starting at the specified indentation level
     pub fn print_with_indent(
-        mut self,
+        self,
         document: &'a Document<'a>,
         indent: u16,
     ) -> PrintResult<Printed> {
+        self.print_queue(PrintQueue::new(document.as_ref()), indent)
+    }
+
+    /// Prints the passed in document with the elements in `range` replaced by `replacement`.
+    ///
+    /// The document isn't copied: the elements before and after `range` and the replacement are
+    /// queued as separate slices, so that re-formatting a part of a document only requires
+    /// building the elements of that part.
+    ///
+    /// # Panics
+    /// If `range` is out of the bounds of the document.
+    pub fn print_with_replacement(
+        self,
+        document: &'a Document<'a>,
+        range: Range<usize>,
+        replacement: &'a [FormatElement<'a>],
+    ) -> PrintResult<Printed> {
+        let elements = document.as_ref();
+        let mut queue = PrintQueue::new(&elements[range.end..]);
+        queue.extend_back(replacement);
+        queue.extend_back(&elements[..range.start]);
+        self.print_queue(queue, 0)
+    }
+
+    fn print_queue(mut self, mut queue: PrintQueue<'a>, indent: u16) -> PrintResult<Printed> {
         let mut stack = PrintCallStack::new(PrintElementArgs::new());
-        let mut queue: PrintQueue<'a> = PrintQueue::new(document.as_ref());
         let mut indent_stack = PrintIndentStack::new(Indention::Level(indent));
 
         while let Some(element) = queue.pop() {
@@ -67,7 +97,29 @@ impl<'a> Printer<'a> {
             }
         }
 
-        Ok(Printed::new(self.state.buffer, None, self.state.verbatim_markers))
+        self.collapse_trailing_line_breaks();
+
+        Ok(Printed::new(
+            self.state.buffer,
+            None,
+            self.state.source_markers.finish(),
+            self.state.verbatim_markers,
+        ))
+    }
+
+    /// Collapses the line breaks printed by [FormatElement::Line]s at the end of the output so
+    /// that it ends in exactly one line ending, e.g. when the document ends with multiple hard or
+    /// empty lines.
+    ///
+    /// Line endings that are part of a text, e.g. of text taken verbatim from the source, are
+    /// left untouched, as is output that doesn't end with a line break.
+    fn collapse_trailing_line_breaks(&mut self) {
+        let end = self.state.text_end + self.options.line_ending.as_str().len();
+
+        if self.state.buffer.len() > end {
+            self.state.buffer.truncate(end);
+            self.state.source_markers.truncate(end);
+        }
     }
 
     /// Prints a single element and push the following elements to queue
@@ -98,8 +150,12 @@ impl<'a> Printer<'a> {
                 self.print_text(text);
             }
             FormatElement::LocatedTokenText { slice, source_position } => {
+                self.state.source_markers.set_pending(*source_position);
                 self.print_text(slice);
             }
+            FormatElement::SourcePosition(position) => {
+                self.state.source_markers.set_pending(*position);
+            }
             FormatElement::Line(line_mode) => {
                 if args.mode().is_flat() {
                     match line_mode {
@@ -327,7 +383,9 @@ impl<'a> Printer<'a> {
             self.state.pending_space = false;
         }
 
+        self.state.source_markers.flush(self.state.buffer.len());
         self.print_str(text);
+        self.state.text_end = self.state.buffer.len();
     }
 
     fn flush_line_suffixes(
@@ -692,7 +750,11 @@ struct PrinterState<'a> {
     measured_group_fits: bool,
     line_width: usize,
     has_empty_line: bool,
+    /// The length of the buffer after the last printed text. Everything after it has been
+    /// printed by [FormatElement::Line]s.
+    text_end: usize,
     line_suffixes: LineSuffixes<'a>,
+    source_markers: SourceMarkers,
     verbatim_markers: Vec<TextRange>,
     group_modes: GroupModes,
     // Re-used queue to measure if a group fits. Optimisation to avoid re-allocating a new
@@ -844,7 +906,6 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
         let saved_indent_stack = std::mem::take(&mut printer.state.fits_indent_stack);
         let saved_stack_tem_indent = std::mem::take(&mut printer.state.fits_stack_tem_indent);
         debug_assert!(saved_stack.is_empty());
-        debug_assert!(saved_queue.is_empty());
         debug_assert!(saved_indent_stack.is_empty());
         debug_assert!(saved_stack_tem_indent.is_empty());
 
@@ -883,12 +944,14 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                 Fits::No => return Ok(false),
                 Fits::Maybe => {
                     if predicate.is_end(element)? {
-                        break;
+                        return Ok(true);
                     }
                 }
             }
         }
 
+        // Reached the end of the document without exceeding the line width.
+        debug_assert!(self.queue.is_empty());
         Ok(true)
     }
 
@@ -1020,6 +1083,8 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
             }
             FormatElement::LocatedTokenText { slice, .. } => return Ok(self.fits_text(slice)),
 
+            FormatElement::SourcePosition(_) => {}
+
             FormatElement::LineSuffixBoundary => {
                 if self.state.has_line_suffix {
                     return Ok(Fits::No);
@@ -1194,9 +1259,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
     }
 
     fn finish(self) {
-        let mut queue = self.queue.finish();
-        queue.clear();
-        self.printer.state.fits_queue = queue;
+        self.printer.state.fits_queue = self.queue.finish();
 
         let mut stack = self.stack.finish();
         stack.clear();
@@ -1270,3 +1333,293 @@ struct FitsState {
     has_line_suffix: bool,
     line_width: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Printer, PrinterOptions};
+    use crate::{
+        formatter::{
+            FormatElement, SourceMarker,
+            format_element::{
+                LineMode,
+                document::Document,
+                tag::{Group, Tag},
+            },
+        },
+        options::LineEnding,
+    };
+
+    fn print(options: PrinterOptions, elements: Vec<FormatElement<'static>>) -> String {
+        let document = Document::from(elements);
+        Printer::new(options).print(&document).unwrap().as_code().to_string()
+    }
+
+    #[test]
+    fn print_with_replacement() {
+        let document = Document::from(vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::StaticText { text: "b" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::StaticText { text: "c" },
+            FormatElement::Line(LineMode::Hard),
+        ]);
+        let replacement = [FormatElement::StaticText { text: "d" }];
+
+        let printed = Printer::new(PrinterOptions::default())
+            .print_with_replacement(&document, 2..3, &replacement)
+            .unwrap();
+
+        assert_eq!(printed.as_code(), "a\nd\nc\n");
+    }
+
+    #[test]
+    fn collapses_trailing_line_breaks() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::Line(LineMode::Empty),
+            FormatElement::Line(LineMode::Empty),
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a\n");
+    }
+
+    #[test]
+    fn keeps_trailing_line_breaks_in_text() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::StaticText { text: "\n\n" },
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a\n\n\n");
+    }
+
+    #[test]
+    fn keeps_trailing_line_breaks_in_verbatim_text() {
+        // Text taken as is from the source, followed by the line breaks of the document's end.
+        let elements = vec![
+            FormatElement::DynamicText { text: "`a\n\n" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::Line(LineMode::Empty),
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "`a\n\n\n");
+    }
+
+    #[test]
+    fn collapses_trailing_crlf_line_breaks() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Line(LineMode::Empty),
+            FormatElement::Line(LineMode::Hard),
+        ];
+        let options = PrinterOptions::default().with_line_ending(LineEnding::Crlf);
+
+        assert_eq!(print(options, elements), "a\r\n");
+    }
+
+    #[test]
+    fn keeps_empty_lines_before_the_end() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Line(LineMode::Empty),
+            FormatElement::StaticText { text: "b" },
+            FormatElement::Line(LineMode::Empty),
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a\n\nb\n");
+    }
+
+    #[test]
+    fn does_not_add_a_trailing_line_break() {
+        let elements = vec![FormatElement::StaticText { text: "a" }];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a");
+    }
+
+    const LONG_COMMENT: &str =
+        "// a trailing comment which is too long to fit on the same line as the code before it";
+
+    /// `[a, b]` as a group, followed by `trailing`, and a hard line break.
+    fn group_followed_by(trailing: Vec<FormatElement<'static>>) -> Vec<FormatElement<'static>> {
+        let mut elements = vec![
+            FormatElement::Tag(Tag::StartGroup(Group::new())),
+            FormatElement::StaticText { text: "[" },
+            FormatElement::Line(LineMode::Soft),
+            FormatElement::StaticText { text: "a," },
+            FormatElement::Line(LineMode::SoftOrSpace),
+            FormatElement::StaticText { text: "b" },
+            FormatElement::Line(LineMode::Soft),
+            FormatElement::StaticText { text: "]" },
+            FormatElement::Tag(Tag::EndGroup),
+        ];
+        elements.extend(trailing);
+        elements.push(FormatElement::Line(LineMode::Hard));
+        elements
+    }
+
+    #[test]
+    fn prints_line_suffix_before_line_break() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a;" },
+            FormatElement::Tag(Tag::StartLineSuffix),
+            FormatElement::Space,
+            FormatElement::StaticText { text: "// comment" },
+            FormatElement::Tag(Tag::EndLineSuffix),
+            FormatElement::StaticText { text: "b" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::StaticText { text: "c;" },
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a;b // comment\nc;");
+    }
+
+    #[test]
+    fn line_suffix_does_not_break_group() {
+        let elements = group_followed_by(vec![
+            FormatElement::StaticText { text: ";" },
+            FormatElement::Tag(Tag::StartLineSuffix),
+            FormatElement::Space,
+            FormatElement::StaticText { text: LONG_COMMENT },
+            FormatElement::Tag(Tag::EndLineSuffix),
+        ]);
+
+        assert_eq!(print(PrinterOptions::default(), elements), format!("[a, b]; {LONG_COMMENT}\n"));
+    }
+
+    #[test]
+    fn text_after_group_breaks_group() {
+        let elements = group_followed_by(vec![
+            FormatElement::StaticText { text: ";" },
+            FormatElement::Space,
+            FormatElement::StaticText { text: LONG_COMMENT },
+        ]);
+
+        assert_eq!(
+            print(PrinterOptions::default(), elements),
+            format!("[\na,\nb\n]; {LONG_COMMENT}\n")
+        );
+    }
+
+    #[test]
+    fn line_suffix_boundary_flushes_line_suffixes() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Tag(Tag::StartLineSuffix),
+            FormatElement::Space,
+            FormatElement::StaticText { text: "// comment" },
+            FormatElement::Tag(Tag::EndLineSuffix),
+            FormatElement::LineSuffixBoundary,
+            FormatElement::StaticText { text: "b" },
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a // comment\nb");
+    }
+
+    #[test]
+    fn line_suffix_boundary_without_line_suffixes() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a" },
+            FormatElement::LineSuffixBoundary,
+            FormatElement::StaticText { text: "b" },
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "ab");
+    }
+
+    #[test]
+    fn flushes_line_suffixes_at_the_end() {
+        let elements = vec![
+            FormatElement::StaticText { text: "a;" },
+            FormatElement::Tag(Tag::StartLineSuffix),
+            FormatElement::Space,
+            FormatElement::StaticText { text: "// comment" },
+            FormatElement::Tag(Tag::EndLineSuffix),
+        ];
+
+        assert_eq!(print(PrinterOptions::default(), elements), "a; // comment");
+    }
+
+    fn print_sourcemap(
+        options: PrinterOptions,
+        elements: Vec<FormatElement<'static>>,
+    ) -> Vec<SourceMarker> {
+        let document = Document::from(elements);
+        Printer::new(options.with_source_map(true)).print(&document).unwrap().take_sourcemap()
+    }
+
+    #[test]
+    fn source_map() {
+        // `a;b;` printed as `a;\n  b;`
+        let elements = vec![
+            FormatElement::SourcePosition(0),
+            FormatElement::StaticText { text: "a" },
+            FormatElement::SourcePosition(1),
+            FormatElement::StaticText { text: ";" },
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::SourcePosition(2),
+            FormatElement::StaticText { text: "b" },
+            FormatElement::SourcePosition(3),
+            FormatElement::StaticText { text: ";" },
+            FormatElement::Tag(Tag::EndIndent),
+        ];
+
+        assert_eq!(
+            print_sourcemap(PrinterOptions::default(), elements),
+            vec![
+                SourceMarker { source: 0, dest: 0 },
+                SourceMarker { source: 1, dest: 1 },
+                SourceMarker { source: 2, dest: 5 },
+                SourceMarker { source: 3, dest: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn source_map_uses_byte_offsets() {
+        let elements = vec![
+            FormatElement::SourcePosition(0),
+            FormatElement::StaticText { text: "é" },
+            FormatElement::Line(LineMode::Empty),
+            FormatElement::SourcePosition(4),
+            FormatElement::StaticText { text: "b" },
+        ];
+        let options = PrinterOptions::default().with_line_ending(LineEnding::Crlf);
+
+        assert_eq!(
+            print_sourcemap(options, elements),
+            vec![SourceMarker { source: 0, dest: 0 }, SourceMarker { source: 4, dest: 6 }]
+        );
+    }
+
+    #[test]
+    fn source_map_skips_positions_without_text() {
+        let elements = vec![
+            FormatElement::SourcePosition(0),
+            FormatElement::SourcePosition(2),
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::SourcePosition(4),
+        ];
+
+        assert_eq!(
+            print_sourcemap(PrinterOptions::default(), elements),
+            vec![SourceMarker { source: 2, dest: 0 }]
+        );
+    }
+
+    #[test]
+    fn no_source_map_by_default() {
+        let document = Document::from(vec![
+            FormatElement::SourcePosition(0),
+            FormatElement::StaticText { text: "a" },
+        ]);
+        let printed = Printer::new(PrinterOptions::default()).print(&document).unwrap();
+
+        assert_eq!(printed.as_code(), "a");
+        assert!(printed.sourcemap().is_empty());
+    }
+}
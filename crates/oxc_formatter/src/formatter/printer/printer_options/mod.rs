@@ -18,6 +18,9 @@ pub struct PrinterOptions {
 
     /// Whether the printer should use tabs or spaces to indent code and if spaces, by how many.
     pub indent_style: IndentStyle,
+
+    /// Whether the printer should collect a source map of the printed output.
+    pub source_map: bool,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -81,6 +84,12 @@ impl PrinterOptions {
         self
     }
 
+    pub fn with_source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+
+        self
+    }
+
     pub(crate) fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
@@ -103,6 +112,7 @@ impl Default for PrinterOptions {
             print_width: PrintWidth::default(),
             indent_style: IndentStyle::default(),
             line_ending: LineEnding::Lf,
+            source_map: false,
         }
     }
 }
@@ -104,6 +104,21 @@ pub(super) trait Queue<'a> {
     {
         QueueContentIterator::new(self, kind)
     }
+
+    /// Iterates over all elements until it finds the matching end tag of the specified kind,
+    /// without removing them from the queue.
+    #[cfg_attr(not(test), expect(dead_code))]
+    fn iter_content_peek<'q>(&'q self, kind: TagKind) -> QueuePeekContentIterator<'a, 'q, Self>
+    where
+        Self: Sized,
+    {
+        let current = match self.stack().top() {
+            Some(&top_slice) => vec![&top_slice[self.next_index()..]],
+            None => Vec::new(),
+        };
+
+        QueuePeekContentIterator { queue: self, kind, depth: 1, next_slice: 1, current }
+    }
 }
 
 /// Queue with the elements to print.
@@ -160,17 +175,40 @@ pub(super) struct FitsQueue<'a, 'print> {
 }
 
 impl<'a, 'print> FitsQueue<'a, 'print> {
+    /// Creates a queue for measuring the not yet printed elements of `print_queue`.
+    ///
+    /// `saved` stores the slices pushed while measuring. It should be the vector returned by
+    /// [FitsQueue::finish] of the previous measurement, so that measurements reuse the same
+    /// allocation instead of allocating a new vector every time. It must be empty.
     pub(super) fn new(
         print_queue: &'print PrintQueue<'a>,
         saved: Vec<&'a [FormatElement]>,
     ) -> Self {
+        debug_assert!(saved.is_empty());
         let stack = StackedStack::with_vec(&print_queue.slices, saved);
 
         Self { stack, next_index: print_queue.next_index }
     }
 
+    /// Ends the measurement and returns the vector to pass to the next [FitsQueue::new].
+    ///
+    /// The vector is cleared but keeps its capacity.
     pub(super) fn finish(self) -> Vec<&'a [FormatElement<'a>]> {
-        self.stack.into_vec()
+        let mut saved = self.stack.into_vec();
+        saved.clear();
+        saved
+    }
+
+    /// Returns the number of slices that are left to measure, including the not yet measured
+    /// slices of the underlying [PrintQueue].
+    #[cfg(test)]
+    pub(super) fn remaining_slices(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns `true` if all elements have been measured.
+    pub(super) fn is_empty(&self) -> bool {
+        self.stack.is_empty()
     }
 }
 
@@ -249,6 +287,81 @@ where
 
 impl<'a, Q> FusedIterator for QueueContentIterator<'a, '_, Q> where Q: Queue<'a> {}
 
+/// Iterator over the content of a [Queue] that, unlike [QueueContentIterator], leaves the
+/// queue untouched.
+///
+/// Instead of pushing [FormatElement::Interned] content onto the queue, it keeps its own stack
+/// of the slices it is currently visiting.
+pub(super) struct QueuePeekContentIterator<'a, 'q, Q: Queue<'a>> {
+    queue: &'q Q,
+    kind: TagKind,
+    depth: usize,
+    /// Index, counted from the top of the queue's stack, of the next slice to visit once
+    /// `current` is exhausted.
+    next_slice: usize,
+    /// The not yet visited elements of the slices that are currently being visited,
+    /// with the innermost interned content last.
+    current: Vec<&'a [FormatElement<'a>]>,
+}
+
+impl<'a, Q> Iterator for QueuePeekContentIterator<'a, '_, Q>
+where
+    Q: Queue<'a>,
+{
+    type Item = &'a FormatElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.depth == 0 {
+            return None;
+        }
+
+        // Resolve the next element, descending into interned content.
+        let top = loop {
+            match self.current.last_mut() {
+                Some(slice) => match *slice {
+                    [FormatElement::Interned(interned), rest @ ..] => {
+                        *slice = rest;
+                        self.current.push(interned);
+                    }
+                    [element, rest @ ..] => {
+                        *slice = rest;
+                        break Some(element);
+                    }
+                    [] => {
+                        self.current.pop();
+                    }
+                },
+                None => match self.queue.stack().get_from_top(self.next_slice) {
+                    Some(&slice) => {
+                        self.next_slice += 1;
+                        self.current.push(slice);
+                    }
+                    None => break None,
+                },
+            }
+        };
+
+        match top.expect("Missing end signal.") {
+            element @ FormatElement::Tag(tag) if tag.kind() == self.kind => {
+                if tag.is_start() {
+                    self.depth += 1;
+                } else {
+                    self.depth -= 1;
+
+                    if self.depth == 0 {
+                        return None;
+                    }
+                }
+
+                Some(element)
+            }
+            element => Some(element),
+        }
+    }
+}
+
+impl<'a, Q> FusedIterator for QueuePeekContentIterator<'a, '_, Q> where Q: Queue<'a> {}
+
 /// A predicate determining when to end measuring if some content fits on the line.
 ///
 /// Called for every [`element`](FormatElement) in the [FitsQueue] when measuring if a content
@@ -322,3 +435,140 @@ impl FitsEndPredicate for SingleEntryPredicate {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FitsQueue, PrintQueue, Queue};
+    use crate::formatter::{
+        FormatElement,
+        format_element::{
+            Interned,
+            tag::{Tag, TagKind},
+        },
+    };
+
+    fn assert_peek_matches_consume(elements: &[FormatElement]) {
+        let mut queue = PrintQueue::new(elements);
+        queue.pop();
+
+        let peeked: Vec<_> = queue.iter_content_peek(TagKind::Indent).collect();
+        let consumed: Vec<_> = queue.clone().iter_content(TagKind::Indent).collect();
+
+        assert_eq!(peeked.len(), consumed.len());
+        assert!(peeked.iter().zip(&consumed).all(|(a, b)| std::ptr::eq(*a, *b)));
+
+        // Peeking must leave the queue untouched.
+        let second: &FormatElement = &elements[1];
+        assert!(std::ptr::eq(queue.pop().unwrap(), second));
+    }
+
+    #[test]
+    fn peek_content() {
+        let elements = [
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::StaticText { text: "b" },
+            FormatElement::Tag(Tag::EndIndent),
+            FormatElement::Tag(Tag::EndIndent),
+            FormatElement::StaticText { text: "c" },
+        ];
+
+        assert_peek_matches_consume(&elements);
+    }
+
+    #[test]
+    fn peek_interned_content() {
+        let interned = Interned::new(vec![
+            FormatElement::StaticText { text: "b" },
+            FormatElement::Interned(Interned::new(vec![FormatElement::StaticText { text: "c" }])),
+            FormatElement::Interned(Interned::new(vec![])),
+            FormatElement::Tag(Tag::EndIndent),
+        ]);
+        let elements = [
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Interned(interned),
+            FormatElement::StaticText { text: "d" },
+        ];
+
+        assert_peek_matches_consume(&elements);
+    }
+
+    #[test]
+    fn peek_content_across_slices() {
+        let elements = [
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::StaticText { text: "a" },
+            FormatElement::Tag(Tag::EndIndent),
+        ];
+        let pushed = [FormatElement::StaticText { text: "b" }];
+
+        let mut queue = PrintQueue::new(&elements);
+        queue.pop();
+        queue.extend_back(&pushed);
+
+        let peeked: Vec<_> = queue.iter_content_peek(TagKind::Indent).collect();
+        let consumed: Vec<_> = queue.clone().iter_content(TagKind::Indent).collect();
+
+        assert_eq!(peeked.len(), 2);
+        assert!(peeked.iter().zip(&consumed).all(|(a, b)| std::ptr::eq(*a, *b)));
+        let pushed: &FormatElement = &pushed[0];
+        assert!(std::ptr::eq(queue.pop().unwrap(), pushed));
+    }
+
+    #[test]
+    fn fits_queue_reuses_saved_vec() {
+        let elements = [
+            FormatElement::StaticText { text: "a" },
+            FormatElement::StaticText { text: "b" },
+            FormatElement::StaticText { text: "c" },
+        ];
+        let pushed = [FormatElement::StaticText { text: "d" }];
+        let print_queue = PrintQueue::new(&elements);
+
+        let mut saved = Vec::new();
+        let mut first_capacity = None;
+        for _ in 0..100 {
+            let mut queue = FitsQueue::new(&print_queue, saved);
+            for _ in 0..10 {
+                queue.extend_back(&pushed);
+            }
+            while queue.pop().is_some() {}
+
+            saved = queue.finish();
+            assert!(saved.is_empty());
+            let capacity = *first_capacity.get_or_insert(saved.capacity());
+            assert!(capacity > 0);
+            assert_eq!(saved.capacity(), capacity);
+        }
+
+        // Measuring never consumes the print queue.
+        assert!(!print_queue.is_empty());
+    }
+
+    #[test]
+    fn fits_queue_remaining_slices() {
+        let elements =
+            [FormatElement::StaticText { text: "a" }, FormatElement::StaticText { text: "b" }];
+        let pushed = [FormatElement::StaticText { text: "c" }];
+        let print_queue = PrintQueue::new(&elements);
+
+        let mut queue = FitsQueue::new(&print_queue, Vec::new());
+        assert_eq!(queue.remaining_slices(), 1);
+
+        queue.extend_back(&pushed);
+        assert_eq!(queue.remaining_slices(), 2);
+
+        assert!(queue.pop().is_some());
+        assert_eq!(queue.remaining_slices(), 1);
+        assert!(!queue.is_empty());
+
+        while queue.pop().is_some() {}
+        assert_eq!(queue.remaining_slices(), 0);
+        assert!(queue.is_empty());
+
+        let _ = queue.finish();
+        assert!(!print_queue.is_empty());
+    }
+}
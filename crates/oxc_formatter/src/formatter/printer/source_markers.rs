@@ -0,0 +1,54 @@
+use super::super::{SourceMarker, TextSize};
+
+/// Collects the [SourceMarker]s of the printed output if source map generation is enabled.
+#[derive(Debug, Default)]
+pub(super) struct SourceMarkers {
+    enabled: bool,
+    /// The source position of the next printed text.
+    pending: Option<TextSize>,
+    markers: Vec<SourceMarker>,
+}
+
+impl SourceMarkers {
+    pub(super) fn new(enabled: bool) -> Self {
+        Self { enabled, pending: None, markers: Vec::new() }
+    }
+
+    /// Sets the source position of the next printed text.
+    ///
+    /// The marker is only recorded once that text gets printed, so that it points past any
+    /// indention or space printed before the text.
+    pub(super) fn set_pending(&mut self, source: TextSize) {
+        if self.enabled {
+            self.pending = Some(source);
+        }
+    }
+
+    /// Maps the output offset `dest` to the pending source position, if any.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(super) fn flush(&mut self, dest: usize) {
+        let Some(source) = self.pending.take() else {
+            return;
+        };
+
+        let marker = SourceMarker { source, dest: dest as u32 };
+        if self.markers.last() != Some(&marker) {
+            self.markers.push(marker);
+        }
+    }
+
+    /// Moves the markers pointing past `len` to the end of the output, e.g. after the printer
+    /// removed trailing line endings.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(super) fn truncate(&mut self, len: usize) {
+        let len = len as u32;
+        for marker in self.markers.iter_mut().rev().take_while(|marker| marker.dest > len) {
+            marker.dest = len;
+        }
+        self.markers.dedup();
+    }
+
+    pub(super) fn finish(self) -> Vec<SourceMarker> {
+        self.markers
+    }
+}
@@ -7,7 +7,13 @@ pub(super) trait Stack<T> {
     fn push(&mut self, value: T);
 
     /// Returns the last element if any
-    fn top(&self) -> Option<&T>;
+    fn top(&self) -> Option<&T> {
+        self.get_from_top(0)
+    }
+
+    /// Returns the element `index` positions below the last element if any,
+    /// where `0` is the last element.
+    fn get_from_top(&self, index: usize) -> Option<&T>;
 }
 
 impl<T> Stack<T> for Vec<T> {
@@ -19,8 +25,8 @@ impl<T> Stack<T> for Vec<T> {
         self.push(value);
     }
 
-    fn top(&self) -> Option<&T> {
-        self.last()
+    fn get_from_top(&self, index: usize) -> Option<&T> {
+        self.iter().rev().nth(index)
     }
 }
 
@@ -49,6 +55,17 @@ impl<'a, T> StackedStack<'a, T> {
     pub(super) fn into_vec(self) -> Vec<T> {
         self.stack
     }
+
+    /// Returns the number of elements in the stack, counting both the elements of the
+    /// `original` stack that haven't been popped yet and the pushed elements.
+    pub(super) fn len(&self) -> usize {
+        self.original.len() + self.stack.len()
+    }
+
+    /// Returns `true` if there are neither elements left in the `original` stack nor pushed elements.
+    pub(super) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl<T> Stack<T> for StackedStack<'_, T>
@@ -69,8 +86,11 @@ where
         self.stack.push(value);
     }
 
-    fn top(&self) -> Option<&T> {
-        self.stack.last().or_else(|| self.original.last())
+    fn get_from_top(&self, index: usize) -> Option<&T> {
+        match index.checked_sub(self.stack.len()) {
+            None => self.stack.iter().rev().nth(index),
+            Some(original_index) => self.original.iter().rev().nth(original_index),
+        }
     }
 }
 
@@ -111,6 +131,21 @@ mod tests {
         assert_eq!(original, vec![1, 2, 3]);
     }
 
+    #[test]
+    fn get_from_top_of_stacked_stack() {
+        let original = vec![1, 2, 3];
+        let mut restorable = StackedStack::new(&original);
+
+        restorable.push(4);
+        restorable.push(5);
+
+        assert_eq!(restorable.get_from_top(0), Some(&5));
+        assert_eq!(restorable.get_from_top(1), Some(&4));
+        assert_eq!(restorable.get_from_top(2), Some(&3));
+        assert_eq!(restorable.get_from_top(4), Some(&1));
+        assert_eq!(restorable.get_from_top(5), None);
+    }
+
     #[test]
     fn restore_stack() {
         let original = vec![1, 2, 3];
@@ -127,4 +162,42 @@ mod tests {
 
         assert_eq!(original, vec![1, 2, 3]);
     }
+
+    #[test]
+    fn len_of_stacked_stack() {
+        let original = vec![1, 2, 3];
+        let mut restorable = StackedStack::with_vec(&original, vec![4, 5]);
+
+        assert_eq!(restorable.len(), 5);
+        assert!(!restorable.is_empty());
+
+        restorable.push(6);
+        assert_eq!(restorable.len(), 6);
+
+        assert_eq!(restorable.pop(), Some(6));
+        assert_eq!(restorable.pop(), Some(5));
+        assert_eq!(restorable.pop(), Some(4));
+        assert_eq!(restorable.pop(), Some(3));
+        assert_eq!(restorable.len(), 2);
+
+        assert_eq!(restorable.pop(), Some(2));
+        assert_eq!(restorable.pop(), Some(1));
+        assert_eq!(restorable.len(), 0);
+        assert!(restorable.is_empty());
+
+        assert_eq!(original, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn empty_stacked_stack() {
+        let original: Vec<i32> = Vec::new();
+        let mut restorable = StackedStack::new(&original);
+
+        assert_eq!(restorable.len(), 0);
+        assert!(restorable.is_empty());
+
+        restorable.push(1);
+        assert_eq!(restorable.len(), 1);
+        assert!(!restorable.is_empty());
+    }
 }
@@ -33,11 +33,11 @@ use oxc_ast::{AstKind, ast::*};
 use rustc_hash::{FxHashMap, FxHashSet};
 use write::FormatWrite;
 
-pub use crate::options::*;
 use crate::{
     formatter::FormatContext,
     generated::ast_nodes::{AstNode, AstNodes},
 };
+pub use crate::{formatter::Formatted, options::*};
 
 pub struct Formatter<'a> {
     allocator: &'a Allocator,
@@ -65,4 +65,194 @@ impl<'a> Formatter<'a> {
         .unwrap();
         formatted.print().unwrap().into_code()
     }
+
+    /// Formats `program` into a document, which can be printed or updated with
+    /// [`Formatter::reformat_statement`] after one of its top level statements changed.
+    pub fn format_document(&self, program: &'a Program<'a>) -> Formatted<'a> {
+        let parent = self.allocator.alloc(AstNodes::Dummy());
+        let program_node = AstNode::new(program, parent, self.allocator);
+
+        let context = FormatContext::new(program, self.allocator, self.options.clone());
+        formatter::format(
+            program,
+            context,
+            formatter::Arguments::new(&[formatter::Argument::new(&program_node)]),
+        )
+        .unwrap()
+    }
+
+    /// Re-formats the top level statement at `index` of `program` and prints it together with
+    /// the other statements of `previous`, the document of the program before that statement
+    /// changed.
+    ///
+    /// Only the changed statement is formatted, the elements of all other statements are reused
+    /// from `previous`. The output is the same as formatting `program` from scratch as long as
+    /// nothing but the statement at `index` changed, including the comments and blank lines
+    /// around it. The new elements are spliced into `previous`, so that it can be used to
+    /// re-format another statement of `program` afterwards.
+    ///
+    /// Returns `None` if `program` or `previous` have no top level statement at `index`, or if
+    /// the statement can't be formatted.
+    pub fn reformat_statement(
+        &self,
+        previous: &mut Formatted<'a>,
+        program: &'a Program<'a>,
+        index: usize,
+    ) -> Option<String> {
+        let parent = self.allocator.alloc(AstNodes::Dummy());
+        let program_node = AstNode::new(program, parent, self.allocator);
+        let statement = program_node.body().iter().nth(index)?;
+        let range = previous.context().statement_ranges().get(index)?;
+
+        // The comments before the statement are unchanged, and were printed by the statements
+        // before it.
+        let mut context = FormatContext::new(program, self.allocator, self.options.clone());
+        context.set_printed_count(range.printed_comments);
+        let formatted = formatter::format(
+            program,
+            context,
+            formatter::Arguments::new(&[formatter::Argument::new(statement)]),
+        )
+        .ok()?;
+        let printed = previous.print_with_statement(index, &formatted)?.ok()?;
+        previous.replace_statement(index, formatted);
+        Some(printed.into_code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Program;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::{
+        FormatOptions, Formatter,
+        formatter::printer::{Printer, PrinterOptions},
+    };
+
+    fn parse<'a>(allocator: &'a Allocator, source_text: &'a str) -> &'a Program<'a> {
+        let ret = Parser::new(allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        allocator.alloc(ret.program)
+    }
+
+    #[test]
+    fn reformat_statement() {
+        let allocator = Allocator::default();
+        let options = FormatOptions::default();
+
+        let before = parse(
+            &allocator,
+            "import a from 'a';\n\nconst b = a( 1 );\nfunction c() { return b }\n\nc();\n",
+        );
+        let mut previous = Formatter::new(&allocator, options.clone()).format_document(before);
+        let unchanged = previous.print().unwrap().into_code();
+
+        let after = parse(
+            &allocator,
+            "import a from 'a';\n\nconst b = a( 1 );\nfunction c() { if (b) { return [b,b,b] } return someFunctionWithALongName(b, 'a long string argument', 12345) }\n\nc();\n",
+        );
+        let incremental = Formatter::new(&allocator, options.clone())
+            .reformat_statement(&mut previous, after, 2)
+            .unwrap();
+        let full = Formatter::new(&allocator, options).build(after);
+
+        assert_eq!(incremental, full);
+        assert_ne!(incremental, unchanged);
+        assert_eq!(previous.print().unwrap().into_code(), full);
+    }
+
+    #[test]
+    fn reformat_statement_with_comments() {
+        let allocator = Allocator::default();
+        let options = FormatOptions::default();
+
+        let before = parse(
+            &allocator,
+            "// a\nconst a = 1; // b\n\n/* c */\nconst b = 2;\n// d\nconst c = 3;\n// e\n",
+        );
+        let mut previous = Formatter::new(&allocator, options.clone()).format_document(before);
+
+        let after = parse(
+            &allocator,
+            "// a\nconst a = 1; // b\n\n/* c */\nconst b = [ 2, /* f */ 3 ];\n// d\nconst c = 3;\n// e\n",
+        );
+        let incremental = Formatter::new(&allocator, options.clone())
+            .reformat_statement(&mut previous, after, 1)
+            .unwrap();
+        assert_eq!(incremental, Formatter::new(&allocator, options.clone()).build(after));
+
+        // The statements after the changed one are updated for the comment that was added.
+        let after = parse(
+            &allocator,
+            "// a\nconst a = 1; // b\n\n/* c */\nconst b = [ 2, /* f */ 3 ];\n// d\nconst c = { d:  3 };\n// e\n",
+        );
+        let incremental = Formatter::new(&allocator, options.clone())
+            .reformat_statement(&mut previous, after, 2)
+            .unwrap();
+        assert_eq!(incremental, Formatter::new(&allocator, options).build(after));
+    }
+
+    #[test]
+    fn reformat_statement_out_of_range() {
+        let allocator = Allocator::default();
+        let options = FormatOptions::default();
+
+        let before = parse(&allocator, "const a = 1;\n");
+        let mut previous = Formatter::new(&allocator, options.clone()).format_document(before);
+
+        let after = parse(&allocator, "const a = 1;\nconst b = 2;\n");
+        let formatter = Formatter::new(&allocator, options);
+        assert!(formatter.reformat_statement(&mut previous, after, 1).is_none());
+        assert!(formatter.reformat_statement(&mut previous, after, 2).is_none());
+    }
+
+    #[test]
+    fn print_with_indent() {
+        let allocator = Allocator::default();
+        let options = FormatOptions::default();
+
+        let program = parse(&allocator, "a();\nif (b) { c() }\n");
+        let formatted = Formatter::new(&allocator, options).format_document(program);
+
+        assert_eq!(
+            formatted.print_with_indent(1).unwrap().into_code(),
+            "a();\n  if (b) {\n    c();\n  }\n"
+        );
+    }
+
+    #[test]
+    fn source_map_round_trips() {
+        let allocator = Allocator::default();
+        let options = FormatOptions::default();
+
+        let source_text = "const a =  \"x\";\nif (a) { foo( \"y\" ) }\nbar(\"z\",   \"w\");\n";
+        let program = parse(&allocator, source_text);
+        let formatted = Formatter::new(&allocator, options.clone()).format_document(program);
+        let printed = Printer::new(options.as_print_options().with_source_map(true))
+            .print(formatted.document())
+            .unwrap();
+
+        let code = printed.as_code();
+        assert_eq!(code, "const a = \"x\";\nif (a) {\n  foo(\"y\");\n}\nbar(\"z\", \"w\");\n");
+        assert_eq!(code, Formatter::new(&allocator, options).build(program));
+
+        // Every string literal is mapped back to the same literal in the source.
+        let literal = |text: &str| -> String {
+            let end = text[1..].find('"').unwrap() + 2;
+            text[..end].to_string()
+        };
+        let mapped = printed
+            .sourcemap()
+            .iter()
+            .map(|marker| {
+                let source = literal(&source_text[marker.source as usize..]);
+                assert_eq!(literal(&code[marker.dest as usize..]), source);
+                source
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(mapped, ["\"x\"", "\"y\"", "\"z\"", "\"w\""]);
+    }
 }
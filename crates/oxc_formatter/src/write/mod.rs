@@ -31,7 +31,7 @@ use oxc_syntax::identifier::{ZWNBSP, is_identifier_name, is_line_terminator};
 use crate::{
     format_args,
     formatter::{
-        Buffer, Format, FormatResult, Formatter,
+        Buffer, Format, FormatResult, Formatter, StatementRange,
         prelude::*,
         separated::FormatSeparatedIter,
         token::number::{NumberFormatOptions, format_number_token},
@@ -581,15 +581,36 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, Statement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let is_program_body = matches!(self.parent, AstNodes::Program(_));
         let source_text = f.context().source_text();
         let mut join = f.join_nodes_with_hardline();
         for stmt in self {
-            join.entry(stmt.span(), stmt);
+            if is_program_body {
+                join.entry(stmt.span(), &FormatProgramStatement(stmt));
+            } else {
+                join.entry(stmt.span(), stmt);
+            }
         }
         join.finish()
     }
 }
 
+/// Formats a top level statement and records the range of its elements, so that it can be
+/// re-formatted on its own later, see [`crate::Formatter::reformat_statement`].
+struct FormatProgramStatement<'a, 'b>(&'b AstNode<'a, Statement<'a>>);
+
+impl<'a> Format<'a> for FormatProgramStatement<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let start = f.elements().len();
+        let printed_comments = f.comments().printed_count();
+        write!(f, self.0)?;
+        let end = f.elements().len();
+        f.context_mut()
+            .push_statement_range(StatementRange { elements: start..end, printed_comments });
+        Ok(())
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, Hashbang<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, ["#!", dynamic_text(self.value().as_str())])?;
@@ -586,6 +586,43 @@ pub fn could_be_error(ctx: &LintContext, expr: &Expression) -> bool {
     }
 }
 
+/// Whether `expr` creates an array, e.g. `[1, 2]`, `new Array(3)` or `Array.from(foo)`.
+pub fn is_array_value(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::ArrayExpression(_) => true,
+        // `new Array()`
+        Expression::NewExpression(new_expr) => is_global_array(&new_expr.callee, ctx),
+        // `Array()`, `Array.from()` and `Array.of()`
+        Expression::CallExpression(call_expr) => {
+            if is_global_array(&call_expr.callee, ctx) {
+                return true;
+            }
+            call_expr.callee.as_member_expression().is_some_and(|member_expr| {
+                matches!(member_expr.static_property_name(), Some("from" | "of"))
+                    && is_global_array(member_expr.object(), ctx)
+            })
+        }
+        _ => false,
+    }
+}
+
+fn is_global_array(expr: &Expression, ctx: &LintContext) -> bool {
+    let Expression::Identifier(ident) = expr else {
+        return false;
+    };
+    ident.name == "Array" && ctx.is_reference_to_global_variable(ident)
+}
+
+/// The span of `ident` without the type annotation of `pattern`, e.g. `x` in `let x: number`.
+///
+/// The parser includes the type annotation in the span of the identifier.
+pub fn binding_identifier_span(pattern: &BindingPattern, ident: &BindingIdentifier) -> Span {
+    pattern
+        .type_annotation
+        .as_ref()
+        .map_or(ident.span, |annotation| Span::new(ident.span.start, annotation.span.start))
+}
+
 pub fn is_callee<'a>(node: &AstNode<'a>, semantic: &Semantic<'a>) -> bool {
     let parent = outermost_paren_parent(node, semantic);
     parent.is_some_and(|node | matches!(node.kind(), AstKind::CallExpression(call_expr) if call_expr.callee.span().contains_inclusive(node.kind().span())))
@@ -637,7 +674,7 @@ pub fn is_default_this_binding<'a>(
                 return false;
             }
 
-            func.id.is_some()
+            func.id.is_none()
         }
         AstKind::StaticBlock(_) => {
             return false;
@@ -652,7 +689,8 @@ pub fn is_default_this_binding<'a>(
         let parent = semantic.nodes().parent_node(current_node.id());
         let parent_kind = parent.kind();
         match parent_kind {
-            AstKind::ChainExpression(_)
+            AstKind::Argument(_)
+            | AstKind::ChainExpression(_)
             | AstKind::ConditionalExpression(_)
             | AstKind::LogicalExpression(_)
             | AstKind::ParenthesizedExpression(_) => {
@@ -895,3 +933,25 @@ pub fn get_function_name_with_kind<'a>(
 
     Cow::Owned(tokens.join(" "))
 }
+
+/// Gets the span to report the given function node on: its name if it has one, including the
+/// key of a method or the variable it is assigned to, or its signature otherwise.
+pub fn get_function_name_span(node: &AstNode, parent_node: &AstNode) -> Span {
+    let params = match node.kind() {
+        AstKind::Function(func) => {
+            if let Some(id) = &func.id {
+                return id.span;
+            }
+            &func.params
+        }
+        AstKind::ArrowFunctionExpression(arrow_func) => &arrow_func.params,
+        _ => return node.span(),
+    };
+    match parent_node.kind() {
+        AstKind::MethodDefinition(method) => method.key.span(),
+        AstKind::ObjectProperty(prop) => prop.key.span(),
+        AstKind::PropertyDefinition(prop) => prop.key.span(),
+        AstKind::VariableDeclarator(decl) => decl.id.span(),
+        _ => Span::new(node.span().start, params.span.end),
+    }
+}
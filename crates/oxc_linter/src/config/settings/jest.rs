@@ -0,0 +1,53 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configure Jest plugin rules.
+///
+/// Derived from [eslint-plugin-jest](https://github.com/jest-community/eslint-plugin-jest#jest-version-setting)
+#[derive(Debug, Clone, Deserialize, Default, Serialize, JsonSchema)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct JestPluginSettings {
+    /// The version of Jest used in the project, e.g. `29` or `"29.7.0"`.
+    ///
+    /// Rules which depend on the Jest version, such as `jest/no-deprecated-functions`,
+    /// use it unless a version is set in their own configuration.
+    ///
+    /// Example:
+    ///
+    /// ```json
+    /// {
+    ///   "settings": {
+    ///     "jest": {
+    ///       "version": 27
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default)]
+    version: Option<JestVersion>,
+}
+
+impl JestPluginSettings {
+    /// The major version of Jest, if it is set.
+    pub fn get_version(&self) -> Option<usize> {
+        self.version.as_ref()?.major()
+    }
+}
+
+/// A Jest version, either a major version like `29` or a version string like `"29.7.0"`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(untagged)]
+pub enum JestVersion {
+    Number(usize),
+    String(String),
+}
+
+impl JestVersion {
+    /// The major version, e.g. `26` for `"26.0.0-next.11"`.
+    pub fn major(&self) -> Option<usize> {
+        match self {
+            Self::Number(version) => Some(*version),
+            Self::String(version) => version.split('.').next()?.parse().ok(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod jest;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
@@ -7,8 +8,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings,
+    jest::JestPluginSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
+    next::NextPluginSettings, react::ReactPluginSettings,
 };
 
 /// # Oxlint Plugin Settings
@@ -33,6 +34,9 @@ use self::{
 ///         "Link": "a",
 ///         "Button": "button"
 ///       }
+///     },
+///     "jest": {
+///       "version": 29
 ///     }
 ///   }
 /// }
@@ -52,6 +56,9 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
+
+    #[serde(default)]
+    pub jest: JestPluginSettings,
 }
 
 #[cfg(test)]
@@ -83,6 +90,9 @@ mod test {
             "next": {
                 "rootDir": "app"
             },
+            "jest": {
+                "version": "27.5.1"
+            },
             "react": {
                 "formComponents": [
                     "CustomForm",
@@ -101,6 +111,7 @@ mod test {
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".into()));
         assert_eq!(settings.jsx_a11y.components.get("Link"), Some(&"Anchor".into()));
         assert!(settings.next.get_root_dirs().contains(&"app".to_string()));
+        assert_eq!(settings.jest.get_version(), Some(27));
         assert_eq!(
             settings.react.get_form_component_attrs("CustomForm").unwrap(),
             as_attrs::<CompactStr, _>(vec![])
@@ -125,5 +136,22 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.jest.get_version().is_none());
+    }
+
+    #[test]
+    fn test_parse_jest_version() {
+        let settings =
+            OxlintSettings::deserialize(&serde_json::json!({ "jest": { "version": 26 } })).unwrap();
+        assert_eq!(settings.jest.get_version(), Some(26));
+
+        let settings = OxlintSettings::deserialize(
+            &serde_json::json!({ "jest": { "version": "26.0.0-next.11" } }),
+        )
+        .unwrap();
+        assert_eq!(settings.jest.get_version(), Some(26));
+
+        let settings = OxlintSettings::deserialize(&serde_json::json!({ "jest": {} })).unwrap();
+        assert!(settings.jest.get_version().is_none());
     }
 }
@@ -4,7 +4,7 @@ use std::{ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::GLOBALS;
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::{Comment, ast::IdentifierReference};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
@@ -14,7 +14,7 @@ use oxc_span::{GetSpan, Span};
 use crate::rule::RuleFixMeta;
 use crate::{
     AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
-    config::GlobalValue,
+    config::{GlobalValue, LintPlugins},
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
 };
@@ -134,6 +134,15 @@ impl<'a> LintContext<'a> {
         span.source_text(self.parent.semantic.source_text())
     }
 
+    /// Get the text of a [`Comment`], without its `//` or `/* */` delimiters.
+    ///
+    /// Returns an empty string if the comment's content is not within the source
+    /// text or does not fall on UTF-8 character boundaries.
+    pub fn comment_text(&self, comment: &Comment) -> &'a str {
+        let span = comment.content_span();
+        self.parent.semantic.source_text().get(span.start as usize..span.end as usize).unwrap_or("")
+    }
+
     /// Path to the file currently being linted.
     #[inline]
     pub fn file_path(&self) -> &Path {
@@ -146,6 +155,12 @@ impl<'a> LintContext<'a> {
         &self.parent.config.settings
     }
 
+    /// Plugins enabled for the file being linted.
+    #[inline]
+    pub fn plugins(&self) -> &LintPlugins {
+        self.parent.plugins()
+    }
+
     /// Sets of global variables that have been enabled or disabled.
     #[inline]
     pub fn globals(&self) -> &OxlintGlobals {
@@ -221,7 +236,11 @@ impl<'a> LintContext<'a> {
 
     /// Add a diagnostic message to the list of diagnostics. Outputs a diagnostic with the current rule
     /// name, severity, and a link to the rule's documentation URL.
-    fn add_diagnostic(&self, mut message: Message<'a>) {
+    fn add_diagnostic(&self, message: Message<'a>) {
+        self.add_diagnostic_with_severity(message, self.severity);
+    }
+
+    fn add_diagnostic_with_severity(&self, mut message: Message<'a>, severity: Severity) {
         if self.parent.disable_directives.contains(self.current_rule_name, message.span()) {
             return;
         }
@@ -234,8 +253,8 @@ impl<'a> LintContext<'a> {
                 self.current_plugin_name,
                 self.current_rule_name
             ));
-        if message.error.severity != self.severity {
-            message.error = message.error.with_severity(self.severity);
+        if message.error.severity != severity {
+            message.error = message.error.with_severity(severity);
         }
 
         self.parent.push_diagnostic(message);
@@ -249,6 +268,17 @@ impl<'a> LintContext<'a> {
         self.add_diagnostic(Message::new(diagnostic, PossibleFixes::None));
     }
 
+    /// Report a lint rule violation, keeping the severity of `diagnostic`
+    /// instead of the severity the rule is configured with.
+    ///
+    /// Only use this for rules that let users configure the severity of
+    /// individual reports. Otherwise, use [`LintContext::diagnostic`].
+    #[inline]
+    pub fn diagnostic_with_own_severity(&self, diagnostic: OxcDiagnostic) {
+        let severity = diagnostic.severity;
+        self.add_diagnostic_with_severity(Message::new(diagnostic, PossibleFixes::None), severity);
+    }
+
     /// Report a lint rule violation and provide an automatic fix.
     ///
     /// The second argument is a [closure] that takes a [`RuleFixer`] and
@@ -176,6 +176,43 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         self.insert_text_at(span.end, text.into())
     }
 
+    /// Creates a fix command that inserts text before the leading keyword of
+    /// the declaration covered by `span`.
+    ///
+    /// Leading `export` and `default` modifiers are skipped, since keywords
+    /// such as `declare` must come after them. For example, inserting
+    /// `"declare "` into `export namespace Foo {}` produces
+    /// `export declare namespace Foo {}`. Any other modifiers (e.g. `abstract`
+    /// or `async`) stay after the inserted text.
+    pub fn insert_text_before_keyword<S: Into<Cow<'a, str>>>(
+        &self,
+        span: Span,
+        text: S,
+    ) -> RuleFix<'a> {
+        self.insert_text_at(self.find_keyword_start(span), text.into())
+    }
+
+    /// Find the start of the first keyword in `span` that is not an `export`
+    /// or `default` modifier.
+    #[expect(clippy::cast_possible_truncation)]
+    fn find_keyword_start(&self, span: Span) -> u32 {
+        let mut rest = self.source_range(span);
+        loop {
+            let trimmed = rest.trim_start();
+            let after_modifier = ["export", "default"].into_iter().find_map(|modifier| {
+                trimmed
+                    .strip_prefix(modifier)
+                    .filter(|after| after.starts_with(char::is_whitespace))
+            });
+            let Some(after) = after_modifier else {
+                rest = trimmed;
+                break;
+            };
+            rest = after;
+        }
+        span.end - rest.len() as u32
+    }
+
     /// Creates a fix command that inserts text at the specified index in the source text.
     fn insert_text_at(&self, index: u32, text: Cow<'a, str>) -> RuleFix<'a> {
         let fix = Fix::new(text, Span::empty(index));
@@ -26,6 +26,8 @@ pub mod table;
 use std::{path::Path, rc::Rc, sync::Arc};
 
 use oxc_semantic::{AstNode, Semantic};
+use oxc_span::CompactStr;
+use rustc_hash::FxHashSet;
 
 pub use crate::{
     config::{
@@ -72,11 +74,14 @@ pub struct Linter {
     options: LintOptions,
     // config: Arc<LintConfig>,
     config: ConfigStore,
+    /// Names of rules whose diagnostics are always reported as errors,
+    /// regardless of the severity they are configured with.
+    deny_list: FxHashSet<CompactStr>,
 }
 
 impl Linter {
     pub fn new(options: LintOptions, config: ConfigStore) -> Self {
-        Self { options, config }
+        Self { options, config, deny_list: FxHashSet::default() }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -92,6 +97,21 @@ impl Linter {
         self
     }
 
+    /// Always report the given rules as errors, even if they are configured as warnings.
+    ///
+    /// This is useful for e.g. CI, where rules like `no-focused-tests` should fail the build
+    /// while only being warnings locally. Rules are matched by name, without the plugin prefix.
+    /// Rules that are turned off are not enabled by this.
+    #[must_use]
+    pub fn with_deny_list<I, S>(mut self, rule_names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<CompactStr>,
+    {
+        self.deny_list = rule_names.into_iter().map(Into::into).collect();
+        self
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -114,10 +134,10 @@ impl Linter {
         let ctx_host =
             Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
 
-        let rules = rules
-            .iter()
-            .filter(|(rule, _)| rule.should_run(&ctx_host))
-            .map(|(rule, severity)| (rule, Rc::clone(&ctx_host).spawn(rule, *severity)));
+        let rules =
+            rules.iter().filter(|(rule, _)| rule.should_run(&ctx_host)).map(|(rule, severity)| {
+                (rule, Rc::clone(&ctx_host).spawn(rule, self.severity_of(rule, *severity)))
+            });
 
         let semantic = ctx_host.semantic();
 
@@ -197,11 +217,69 @@ impl Linter {
 
         ctx_host.take_diagnostics()
     }
+
+    /// Resolves the severity `rule` is reported with, escalating rules in the deny list.
+    fn severity_of(&self, rule: &RuleEnum, configured: AllowWarnDeny) -> AllowWarnDeny {
+        if configured.is_warn_deny() && self.deny_list.contains(rule.name()) {
+            AllowWarnDeny::Deny
+        } else {
+            configured
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::Oxlintrc;
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::Severity;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use rustc_hash::FxHashMap;
+
+    use super::{
+        AllowWarnDeny, BuiltinLintPlugins, ConfigStore, ConfigStoreBuilder, LintOptions, Linter,
+        ModuleRecord, Oxlintrc, rules::RULES,
+    };
+
+    fn lint_focused_test(linter: &Linter) -> Vec<Severity> {
+        let allocator = Allocator::default();
+        let path = Path::new("foo.test.js");
+        let source_type = SourceType::from_path(path).unwrap();
+        let ret = Parser::new(&allocator, "it.only('foo', () => {});", source_type).parse();
+        let semantic =
+            SemanticBuilder::new().with_cfg(true).build(allocator.alloc(ret.program)).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        linter
+            .run(path, Rc::new(semantic), module_record)
+            .into_iter()
+            .map(|message| message.error.severity)
+            .collect()
+    }
+
+    #[test]
+    fn test_deny_list_escalates_severity() {
+        let rule = RULES
+            .iter()
+            .find(|rule| rule.plugin_name() == "jest" && rule.name() == "no-focused-tests")
+            .cloned()
+            .unwrap();
+        let config = ConfigStore::new(
+            ConfigStoreBuilder::empty()
+                .with_builtin_plugins(BuiltinLintPlugins::JEST)
+                .with_rule(rule, AllowWarnDeny::Warn)
+                .build(),
+            FxHashMap::default(),
+        );
+        let linter = Linter::new(LintOptions::default(), config);
+
+        assert_eq!(lint_focused_test(&linter), vec![Severity::Warning]);
+
+        let linter = linter.with_deny_list(["no-focused-tests"]);
+        assert_eq!(lint_focused_test(&linter), vec![Severity::Error]);
+    }
 
     #[test]
     fn test_schema_json() {
@@ -299,6 +299,99 @@ mod test {
         }
     }
 
+    /// Parses the code examples of a rule's documentation with the language of their code block,
+    /// and returns a description of each example that fails to parse.
+    ///
+    /// Examples in other languages, e.g. `json`, or without a language are not checked.
+    fn find_invalid_examples(documentation: &str) -> Vec<String> {
+        use cow_utils::CowUtils;
+        use oxc_allocator::Allocator;
+        use oxc_parser::Parser;
+        use oxc_span::SourceType;
+
+        let mut invalid = vec![];
+        let mut lines = documentation.lines();
+        while let Some(line) = lines.next() {
+            let Some(lang) = line.trim().strip_prefix("```") else {
+                continue;
+            };
+            let code = lines.by_ref().take_while(|line| line.trim() != "```").collect::<Vec<_>>();
+            let code = code.join("\n");
+            let extension = match lang.trim().cow_to_ascii_lowercase().as_ref() {
+                "js" | "javascript" => "js",
+                "jsx" => "jsx",
+                "ts" | "typescript" => "ts",
+                "tsx" => "tsx",
+                _ => continue,
+            };
+
+            // Examples may be either modules or scripts, e.g. when showing `with` statements.
+            let source_type = SourceType::from_extension(extension).unwrap();
+            let parses =
+                [source_type, source_type.with_script(true)].into_iter().any(|source_type| {
+                    let allocator = Allocator::default();
+                    Parser::new(&allocator, &code, source_type).parse().errors.is_empty()
+                });
+            if !parses {
+                invalid.push(format!("```{lang}\n{code}\n```"));
+            }
+        }
+        invalid
+    }
+
+    #[test]
+    #[cfg(feature = "ruledocs")]
+    fn ensure_documentation_examples_parse() {
+        use crate::rules::RULES;
+
+        for rule in RULES.iter() {
+            let Some(documentation) = rule.documentation() else {
+                continue;
+            };
+            let invalid = find_invalid_examples(documentation);
+            assert!(
+                invalid.is_empty(),
+                "Rule '{}' has examples with syntax errors:\n{}",
+                rule.name(),
+                invalid.join("\n")
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_invalid_examples() {
+        let documentation = "
+### Examples
+
+Examples of **incorrect** code for this rule:
+```js
+debugger;
+```
+
+Examples of **correct** code for this rule:
+```ts
+let a: number = 1;
+```
+
+```json
+{ \"not\": javascript }
+```
+";
+        assert!(find_invalid_examples(documentation).is_empty());
+
+        let documentation = "
+Examples of **incorrect** code for this rule:
+```js
+if (foo) {
+```
+
+```javascript
+with (foo) {}
+```
+";
+        assert_eq!(find_invalid_examples(documentation), vec!["```js\nif (foo) {\n```"]);
+    }
+
     #[test]
     fn test_deserialize_rule_category() {
         let tests = [
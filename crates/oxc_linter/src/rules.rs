@@ -42,6 +42,8 @@ mod eslint {
     pub mod array_callback_return;
     pub mod arrow_body_style;
     pub mod block_scoped_var;
+    pub mod class_methods_use_this;
+    pub mod consistent_return;
     pub mod curly;
     pub mod default_case;
     pub mod default_case_last;
@@ -103,9 +105,11 @@ mod eslint {
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
+    pub mod no_implicit_globals;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
     pub mod no_invalid_regexp;
+    pub mod no_invalid_this;
     pub mod no_irregular_whitespace;
     pub mod no_iterator;
     pub mod no_label_var;
@@ -126,10 +130,12 @@ mod eslint {
     pub mod no_obj_calls;
     pub mod no_object_constructor;
     pub mod no_plusplus;
+    pub mod no_promise_executor_return;
     pub mod no_proto;
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
+    pub mod no_restricted_exports;
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
     pub mod no_return_assign;
@@ -143,11 +149,14 @@ mod eslint {
     pub mod no_ternary;
     pub mod no_this_before_super;
     pub mod no_throw_literal;
+    pub mod no_unassigned_vars;
     pub mod no_undef;
     pub mod no_undefined;
     pub mod no_unexpected_multiline;
+    pub mod no_unmodified_loop_condition;
     pub mod no_unneeded_ternary;
     pub mod no_unreachable;
+    pub mod no_unreachable_loop;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
@@ -158,15 +167,18 @@ mod eslint {
     pub mod no_useless_backreference;
     pub mod no_useless_call;
     pub mod no_useless_catch;
+    pub mod no_useless_computed_key;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
     pub mod no_useless_escape;
     pub mod no_useless_rename;
     pub mod no_var;
     pub mod no_void;
+    pub mod no_warning_comments;
     pub mod no_with;
     pub mod operator_assignment;
     pub mod prefer_exponentiation_operator;
+    pub mod prefer_named_capture_group;
     pub mod prefer_numeric_literals;
     pub mod prefer_object_has_own;
     pub mod prefer_object_spread;
@@ -198,29 +210,43 @@ mod typescript {
     pub mod consistent_type_definitions;
     pub mod consistent_type_imports;
     pub mod explicit_function_return_type;
+    pub mod method_signature_style;
+    pub mod no_array_delete;
+    pub mod no_base_to_string;
     pub mod no_confusing_non_null_assertion;
     pub mod no_duplicate_enum_values;
+    pub mod no_duplicate_type_constituents;
     pub mod no_dynamic_delete;
     pub mod no_empty_interface;
     pub mod no_empty_object_type;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_for_in_array;
     pub mod no_import_type_side_effects;
     pub mod no_inferrable_types;
+    pub mod no_meaningless_void_operator;
     pub mod no_misused_new;
+    pub mod no_mixed_enums;
     pub mod no_namespace;
     pub mod no_non_null_asserted_nullish_coalescing;
     pub mod no_non_null_asserted_optional_chain;
     pub mod no_non_null_assertion;
+    pub mod no_redundant_type_constituents;
     pub mod no_require_imports;
     pub mod no_this_alias;
+    pub mod no_unnecessary_boolean_literal_compare;
     pub mod no_unnecessary_parameter_property_assignment;
+    pub mod no_unnecessary_qualifier;
+    pub mod no_unnecessary_template_expression;
+    pub mod no_unnecessary_type_assertion;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
+    pub mod no_unsafe_enum_comparison;
     pub mod no_unsafe_function_type;
 
     pub mod no_useless_empty_export;
+    pub mod no_useless_template_literals;
     pub mod no_var_requires;
     pub mod no_wrapper_object_types;
     pub mod prefer_as_const;
@@ -229,8 +255,14 @@ mod typescript {
     pub mod prefer_function_type;
     pub mod prefer_literal_enum_member;
     pub mod prefer_namespace_keyword;
+    pub mod prefer_optional_chain;
+    pub mod prefer_readonly;
+    pub mod prefer_reduce_type_parameter;
+    pub mod prefer_return_this_type;
     pub mod prefer_ts_expect_error;
+    pub mod require_array_sort_compare;
     pub mod triple_slash_reference;
+    pub mod unified_signatures;
 }
 
 mod jest {
@@ -480,6 +512,7 @@ mod oxc {
     pub mod bad_min_max_func;
     pub mod bad_object_literal_comparison;
     pub mod bad_replace_all_arg;
+    pub mod cognitive_complexity;
     pub mod const_comparisons;
     pub mod double_comparisons;
     pub mod erasing_op;
@@ -490,11 +523,17 @@ mod oxc {
     pub mod no_async_endpoint_handlers;
     pub mod no_barrel_file;
     pub mod no_const_enum;
+    pub mod no_duplicate_string;
+    pub mod no_gratuitous_expressions;
+    pub mod no_identical_functions;
+    pub mod no_inverted_boolean_check;
     pub mod no_map_spread;
     pub mod no_optional_chaining;
     pub mod no_rest_spread_properties;
+    pub mod no_small_switch;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
+    pub mod prefer_immediate_return;
     pub mod uninvoked_array_callback;
 }
 
@@ -578,6 +617,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
     eslint::arrow_body_style,
     eslint::block_scoped_var,
+    eslint::class_methods_use_this,
+    eslint::consistent_return,
     eslint::curly,
     eslint::default_case,
     eslint::default_case_last,
@@ -616,6 +657,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_nested_ternary,
     eslint::no_object_constructor,
     eslint::no_restricted_imports,
+    eslint::no_unmodified_loop_condition,
     eslint::no_unneeded_ternary,
     eslint::no_useless_backreference,
     eslint::no_useless_call,
@@ -649,9 +691,11 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
+    eslint::no_implicit_globals,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
     eslint::no_invalid_regexp,
+    eslint::no_invalid_this,
     eslint::no_irregular_whitespace,
     eslint::no_iterator,
     eslint::no_label_var,
@@ -666,10 +710,12 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_nonoctal_decimal_escape,
     eslint::no_obj_calls,
     eslint::no_plusplus,
+    eslint::no_promise_executor_return,
     eslint::no_proto,
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_restricted_exports,
     eslint::no_restricted_globals,
     eslint::no_return_assign,
     eslint::no_script_url,
@@ -682,10 +728,12 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_ternary,
     eslint::no_this_before_super,
     eslint::no_throw_literal,
+    eslint::no_unassigned_vars,
     eslint::no_undef,
     eslint::no_undefined,
     eslint::no_unexpected_multiline,
     eslint::no_unreachable,
+    eslint::no_unreachable_loop,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
@@ -694,16 +742,19 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unused_private_class_members,
     eslint::no_unused_vars,
     eslint::no_useless_catch,
+    eslint::no_useless_computed_key,
     eslint::no_useless_concat,
     eslint::no_useless_constructor,
     eslint::no_useless_escape,
     eslint::no_useless_rename,
     eslint::no_var,
     eslint::no_void,
+    eslint::no_warning_comments,
     eslint::no_with,
     eslint::operator_assignment,
     eslint::prefer_promise_reject_errors,
     eslint::prefer_exponentiation_operator,
+    eslint::prefer_named_capture_group,
     eslint::prefer_numeric_literals,
     eslint::prefer_object_has_own,
     eslint::prefer_object_spread,
@@ -876,6 +927,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::bad_min_max_func,
     oxc::bad_object_literal_comparison,
     oxc::bad_replace_all_arg,
+    oxc::cognitive_complexity,
     oxc::const_comparisons,
     oxc::double_comparisons,
     oxc::erasing_op,
@@ -886,11 +938,17 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_async_endpoint_handlers,
     oxc::no_barrel_file,
     oxc::no_const_enum,
+    oxc::no_duplicate_string,
+    oxc::no_gratuitous_expressions,
+    oxc::no_identical_functions,
+    oxc::no_inverted_boolean_check,
     oxc::no_map_spread,
     oxc::no_optional_chaining,
     oxc::no_rest_spread_properties,
+    oxc::no_small_switch,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
+    oxc::prefer_immediate_return,
     oxc::uninvoked_array_callback,
     promise::avoid_new,
     promise::catch_or_return,
@@ -957,28 +1015,42 @@ oxc_macros::declare_all_lint_rules! {
     typescript::consistent_type_definitions,
     typescript::consistent_type_imports,
     typescript::explicit_function_return_type,
+    typescript::method_signature_style,
     typescript::no_inferrable_types,
+    typescript::no_array_delete,
+    typescript::no_base_to_string,
     typescript::no_confusing_non_null_assertion,
     typescript::no_duplicate_enum_values,
+    typescript::no_duplicate_type_constituents,
     typescript::no_dynamic_delete,
     typescript::no_empty_interface,
     typescript::no_empty_object_type,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_for_in_array,
     typescript::no_import_type_side_effects,
+    typescript::no_meaningless_void_operator,
     typescript::no_misused_new,
+    typescript::no_mixed_enums,
     typescript::no_namespace,
     typescript::no_non_null_asserted_nullish_coalescing,
     typescript::no_non_null_asserted_optional_chain,
     typescript::no_non_null_assertion,
+    typescript::no_redundant_type_constituents,
     typescript::no_require_imports,
     typescript::no_this_alias,
+    typescript::no_unnecessary_boolean_literal_compare,
     typescript::no_unnecessary_parameter_property_assignment,
+    typescript::no_unnecessary_qualifier,
+    typescript::no_unnecessary_template_expression,
+    typescript::no_unnecessary_type_assertion,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
+    typescript::no_unsafe_enum_comparison,
     typescript::no_unsafe_function_type,
     typescript::no_useless_empty_export,
+    typescript::no_useless_template_literals,
     typescript::no_var_requires,
     typescript::no_wrapper_object_types,
     typescript::prefer_as_const,
@@ -987,8 +1059,14 @@ oxc_macros::declare_all_lint_rules! {
     typescript::prefer_function_type,
     typescript::prefer_literal_enum_member,
     typescript::prefer_namespace_keyword,
+    typescript::prefer_optional_chain,
+    typescript::prefer_readonly,
+    typescript::prefer_reduce_type_parameter,
+    typescript::prefer_return_this_type,
     typescript::prefer_ts_expect_error,
+    typescript::require_array_sort_compare,
     typescript::triple_slash_reference,
+    typescript::unified_signatures,
     unicorn::catch_error_name,
     unicorn::consistent_assert,
     unicorn::consistent_date_clone,
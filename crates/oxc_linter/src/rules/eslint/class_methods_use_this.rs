@@ -0,0 +1,305 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        Class, Expression, Function, MethodDefinitionKind, MethodDefinitionType, PropertyKey,
+        Super, ThisExpression,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn class_methods_use_this_diagnostic(name: Option<&str>, span: Span) -> OxcDiagnostic {
+    let message = match name {
+        Some(name) => format!("Expected `this` to be used by class method `{name}`."),
+        None => "Expected `this` to be used by class method.".to_string(),
+    };
+    OxcDiagnostic::warn(message)
+        .with_help("Consider converting this method to a static method or a standalone function.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ClassMethodsUseThis(Box<ClassMethodsUseThisConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ClassMethodsUseThisConfig {
+    except_methods: Vec<CompactStr>,
+    enforce_for_class_fields: bool,
+    ignore_override_methods: bool,
+}
+
+impl Default for ClassMethodsUseThisConfig {
+    fn default() -> Self {
+        Self {
+            except_methods: Vec::new(),
+            enforce_for_class_fields: true,
+            ignore_override_methods: false,
+        }
+    }
+}
+
+impl std::ops::Deref for ClassMethodsUseThis {
+    type Target = ClassMethodsUseThisConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that class methods utilize `this`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a class method does not use `this`, it can sometimes be made into a static function.
+    /// If you do convert the method into a static function, instances of the class that call
+    /// that particular method have to be converted to a static call as well
+    /// (`MyClass.callStaticMethod()`).
+    ///
+    /// It's possible to have a class method which doesn't use `this`, such as when it is
+    /// meant to be overridden by subclasses. The `exceptMethods` and `ignoreOverrideMethods`
+    /// options allow these methods.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// class A {
+    ///   foo() {
+    ///     console.log("Hello World");
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// class A {
+    ///   foo() {
+    ///     this.bar = "Hello World";
+    ///   }
+    /// }
+    ///
+    /// class B {
+    ///   static foo() {
+    ///     console.log("Hello World");
+    ///   }
+    /// }
+    ///
+    /// class C {
+    ///   constructor() {
+    ///     // constructors are ignored
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### exceptMethods
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Names of methods to ignore. Private methods are written with their `#` prefix,
+    /// e.g. `{ "exceptMethods": ["foo", "#bar"] }`.
+    ///
+    /// #### enforceForClassFields
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Enforce that arrow functions and function expressions used as instance field
+    /// initializers utilize `this`.
+    ///
+    /// #### ignoreOverrideMethods
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignore members marked with the TypeScript `override` modifier.
+    ClassMethodsUseThis,
+    eslint,
+    restriction
+);
+
+impl Rule for ClassMethodsUseThis {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str, default: bool| {
+            config.and_then(|config| config.get(name)).and_then(Value::as_bool).unwrap_or(default)
+        };
+
+        Self(Box::new(ClassMethodsUseThisConfig {
+            except_methods: config
+                .and_then(|config| config.get("exceptMethods"))
+                .and_then(Value::as_array)
+                .map(|methods| {
+                    methods.iter().filter_map(Value::as_str).map(CompactStr::from).collect()
+                })
+                .unwrap_or_default(),
+            enforce_for_class_fields: get_bool("enforceForClassFields", true),
+            ignore_override_methods: get_bool("ignoreOverrideMethods", false),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let mut finder = ThisFinder::default();
+        let key = match node.kind() {
+            AstKind::MethodDefinition(method) => {
+                if method.r#static
+                    || method.kind == MethodDefinitionKind::Constructor
+                    || method.r#type == MethodDefinitionType::TSAbstractMethodDefinition
+                    || (self.ignore_override_methods && method.r#override)
+                {
+                    return;
+                }
+                let Some(body) = &method.value.body else {
+                    return;
+                };
+                finder.visit_function_body(body);
+                &method.key
+            }
+            AstKind::PropertyDefinition(prop) => {
+                if !self.enforce_for_class_fields
+                    || prop.r#static
+                    || (self.ignore_override_methods && prop.r#override)
+                {
+                    return;
+                }
+                match &prop.value {
+                    Some(Expression::ArrowFunctionExpression(arrow)) => {
+                        finder.visit_function_body(&arrow.body);
+                    }
+                    Some(Expression::FunctionExpression(func)) => {
+                        let Some(body) = &func.body else {
+                            return;
+                        };
+                        finder.visit_function_body(body);
+                    }
+                    _ => return,
+                }
+                &prop.key
+            }
+            _ => return,
+        };
+
+        if finder.found {
+            return;
+        }
+
+        let name = method_name(key);
+        if name.as_ref().is_some_and(|name| self.except_methods.iter().any(|m| m == name)) {
+            return;
+        }
+
+        ctx.diagnostic(class_methods_use_this_diagnostic(name.as_deref(), key.span()));
+    }
+}
+
+/// Name of the method as written in `exceptMethods`, i.e. with a `#` prefix for private methods.
+fn method_name<'a>(key: &PropertyKey<'a>) -> Option<Cow<'a, str>> {
+    match key {
+        PropertyKey::PrivateIdentifier(ident) => Some(Cow::Owned(format!("#{}", ident.name))),
+        _ => key.static_name(),
+    }
+}
+
+/// Looks for `this` or `super` referring to the instance of the class being checked.
+#[derive(Default)]
+struct ThisFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        // Non-arrow functions have their own `this`.
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        // Only the `extends` clause of a nested class is evaluated with the outer `this`.
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("class A { constructor() {} }", None),
+        ("class A { foo() { this } }", None),
+        ("class A { foo() { this.bar = 'bar'; } }", None),
+        ("class A { foo() { bar(this); } }", None),
+        ("class A extends B { foo() { super.foo(); } }", None),
+        ("class A { foo() { if (true) { return this; } } }", None),
+        ("class A { static foo() {} }", None),
+        ("class A { foo() { return () => this; } }", None),
+        ("({ a() {} });", None),
+        ("class A { foo() { () => this; } }", None),
+        ("({ a: function () {} });", None),
+        ("class A { foo() { class B extends this.Base {} } }", None),
+        ("class A { foo() { this.bar = 'bar'; } }", Some(json!([{ "exceptMethods": ["bar"] }]))),
+        ("class A { foo() {} }", Some(json!([{ "exceptMethods": ["foo"] }]))),
+        (
+            "class A { foo() {} hasOwnProperty() {} }",
+            Some(json!([{ "exceptMethods": ["foo", "hasOwnProperty"] }])),
+        ),
+        ("class A { #bar() {} }", Some(json!([{ "exceptMethods": ["#bar"] }]))),
+        ("class A { foo = function () { this.bar; } }", None),
+        ("class A { foo = () => { this.bar; } }", None),
+        ("class A { foo = () => { super.toString(); } }", None),
+        ("class A { static foo = function () {} }", None),
+        ("class A { static foo = () => {} }", None),
+        ("class A { foo = 1 }", None),
+        ("class A { foo = () => {} }", Some(json!([{ "enforceForClassFields": false }]))),
+        ("class A { foo = function () {} }", Some(json!([{ "enforceForClassFields": false }]))),
+        (
+            "class A extends B { override foo() {} }",
+            Some(json!([{ "ignoreOverrideMethods": true }])),
+        ),
+        (
+            "class A extends B { override foo = () => {} }",
+            Some(json!([{ "ignoreOverrideMethods": true }])),
+        ),
+        ("abstract class A { abstract foo(): void; }", None),
+    ];
+
+    let fail = vec![
+        ("class A { foo() {} }", None),
+        ("class A { foo() { /** comment */ } }", None),
+        ("class A { foo(a) { var b = 1; } }", None),
+        ("class A { foo() { function bar() { this; } } }", None),
+        ("class A { foo() { class B { bar() { this; } } } }", None),
+        ("class A { get foo() { return 1; } }", None),
+        ("class A { set foo(a) {} }", None),
+        ("class A { #foo() {} }", None),
+        ("class A { 'foo'() {} }", None),
+        ("class A { [foo]() {} }", None),
+        ("class A { foo() {} bar() {} }", Some(json!([{ "exceptMethods": ["bar"] }]))),
+        ("class A { foo() {} }", Some(json!([{ "exceptMethods": ["#foo"] }]))),
+        ("class A { foo = function () {} }", None),
+        ("class A { foo = () => {} }", None),
+        ("class A { #foo = () => {} }", None),
+        ("class A extends B { override foo() {} }", None),
+    ];
+
+    Tester::new(ClassMethodsUseThis::NAME, ClassMethodsUseThis::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
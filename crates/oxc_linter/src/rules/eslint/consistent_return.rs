@@ -0,0 +1,332 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ArrowFunctionExpression, Expression, Function, ReturnStatement, UnaryOperator},
+};
+use oxc_ast_visit::Visit;
+use oxc_cfg::{
+    EdgeType, ErrorEdgeKind, InstructionKind,
+    graph::visit::{Control, DfsEvent, set_depth_first_search},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, ast_util::get_function_name_with_kind, context::LintContext, rule::Rule};
+
+fn missing_return_value_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected a return value from {name}."))
+        .with_help("Other `return` statements of this function return a value.")
+        .with_label(span)
+}
+
+fn unexpected_return_value_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected no return value from {name}."))
+        .with_help("Other `return` statements of this function don't return a value.")
+        .with_label(span)
+}
+
+fn missing_return_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected to return a value at the end of {name}."))
+        .with_help("Add a `return` statement at the end of the function.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentReturn {
+    treat_undefined_as_unspecified: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `return` statements to either always or never specify values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unlike statically-typed languages which enforce that a function returns a specified
+    /// type of value, JavaScript allows different code paths in a function to return
+    /// different types of values.
+    ///
+    /// A confusing aspect of JavaScript is that a function returns `undefined` if any of the
+    /// following are true:
+    /// - it does not execute a `return` statement before it exits
+    /// - it executes `return` which does not specify a value explicitly
+    /// - it executes `return undefined`
+    /// - it executes `return void` followed by an expression (for example, a function call)
+    ///
+    /// If any code paths in a function return a value explicitly but some code paths do not,
+    /// this might be a typing mistake, especially in a large function.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function doSomething(condition) {
+    ///   if (condition) {
+    ///     return true;
+    ///   } else {
+    ///     return;
+    ///   }
+    /// }
+    ///
+    /// function doSomethingElse(condition) {
+    ///   if (condition) {
+    ///     return true;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function doSomething(condition) {
+    ///   if (condition) {
+    ///     return true;
+    ///   } else {
+    ///     return false;
+    ///   }
+    /// }
+    ///
+    /// function Foo() {
+    ///   if (!(this instanceof Foo)) {
+    ///     return new Foo();
+    ///   }
+    ///   this.a = 0;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### treatUndefinedAsUnspecified
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Treat `return undefined` and `return void expr` like a `return` without a value.
+    ///
+    /// Example:
+    /// ```json
+    /// "eslint/consistent-return": [
+    ///   "error",
+    ///   { "treatUndefinedAsUnspecified": true }
+    /// ]
+    /// ```
+    ConsistentReturn,
+    eslint,
+    suspicious
+);
+
+impl Rule for ConsistentReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let treat_undefined_as_unspecified = value
+            .get(0)
+            .and_then(|config| config.get("treatUndefinedAsUnspecified"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { treat_undefined_as_unspecified }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let mut collector = ReturnCollector {
+            treat_undefined_as_unspecified: self.treat_undefined_as_unspecified,
+            returns: vec![],
+        };
+        let head = match node.kind() {
+            AstKind::Function(func) => {
+                let Some(body) = &func.body else {
+                    return;
+                };
+                collector.visit_function_body(body);
+                function_head_span(func, node, ctx)
+            }
+            AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => {
+                collector.visit_function_body(&arrow.body);
+                arrow_head_span(arrow, ctx)
+            }
+            _ => return,
+        };
+
+        let Some((&(_, has_return_value), rest)) = collector.returns.split_first() else {
+            return;
+        };
+        let name = get_function_name_with_kind(node, ctx.nodes().parent_node(node.id()));
+
+        for &(span, returns_value) in rest {
+            match (has_return_value, returns_value) {
+                (true, false) => ctx.diagnostic(missing_return_value_diagnostic(&name, span)),
+                (false, true) => {
+                    ctx.diagnostic(unexpected_return_value_diagnostic(&name, span));
+                }
+                _ => {}
+            }
+        }
+
+        if has_return_value && can_return_implicitly(node, ctx) {
+            ctx.diagnostic(missing_return_diagnostic(&name, head));
+        }
+    }
+}
+
+/// Whether the end of the function body is reachable, i.e. the function can finish without
+/// executing a `return` or `throw` statement.
+fn can_return_implicitly(node: &AstNode, ctx: &LintContext) -> bool {
+    let cfg = ctx.cfg();
+    let graph = cfg.graph();
+    set_depth_first_search(graph, Some(node.cfg_id()), |event| match event {
+        DfsEvent::TreeEdge(a, b) => {
+            let is_normal_flow = graph.edges_connecting(a, b).any(|edge| {
+                matches!(
+                    edge.weight(),
+                    EdgeType::Normal
+                        | EdgeType::Jump
+                        | EdgeType::Backedge
+                        | EdgeType::Join
+                        | EdgeType::Error(ErrorEdgeKind::Explicit)
+                )
+            });
+            if is_normal_flow { Control::Continue } else { Control::Prune }
+        }
+        DfsEvent::Discover(basic_block_id, _) => {
+            if cfg
+                .basic_block(basic_block_id)
+                .instructions()
+                .iter()
+                .any(|it| matches!(it.kind, InstructionKind::ImplicitReturn))
+            {
+                Control::Break(())
+            } else {
+                Control::Continue
+            }
+        }
+        _ => Control::Continue,
+    })
+    .break_value()
+    .is_some()
+}
+
+/// The name of a function, or its `function` keyword if it is anonymous.
+#[expect(clippy::cast_possible_truncation)]
+fn function_head_span(func: &Function, node: &AstNode, ctx: &LintContext) -> Span {
+    match ctx.nodes().parent_kind(node.id()) {
+        AstKind::MethodDefinition(method) => return method.key.span(),
+        AstKind::ObjectProperty(prop) if prop.method || prop.kind.is_accessor() => {
+            return prop.key.span();
+        }
+        _ => {}
+    }
+    if let Some(id) = &func.id {
+        return id.span;
+    }
+    let before_params = Span::new(func.span.start, func.params.span.start);
+    ctx.source_range(before_params)
+        .find("function")
+        .map_or(before_params, |offset| Span::sized(func.span.start + offset as u32, 8))
+}
+
+/// The `=>` token of an arrow function.
+#[expect(clippy::cast_possible_truncation)]
+fn arrow_head_span(arrow: &ArrowFunctionExpression, ctx: &LintContext) -> Span {
+    let between = Span::new(arrow.params.span.end, arrow.body.span.start);
+    ctx.source_range(between)
+        .find("=>")
+        .map_or(arrow.span, |offset| Span::sized(between.start + offset as u32, 2))
+}
+
+/// Collects the `return` statements of a function body, excluding those of nested functions,
+/// along with whether they return a value.
+struct ReturnCollector {
+    treat_undefined_as_unspecified: bool,
+    returns: Vec<(Span, bool)>,
+}
+
+impl ReturnCollector {
+    fn has_return_value(&self, ret: &ReturnStatement) -> bool {
+        let Some(argument) = &ret.argument else {
+            return false;
+        };
+        if !self.treat_undefined_as_unspecified {
+            return true;
+        }
+        match argument.without_parentheses() {
+            Expression::Identifier(ident) => ident.name != "undefined",
+            Expression::UnaryExpression(unary) => unary.operator != UnaryOperator::Void,
+            _ => true,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ReturnCollector {
+    fn visit_return_statement(&mut self, ret: &ReturnStatement<'a>) {
+        self.returns.push((ret.span, self.has_return_value(ret)));
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { return; }", None),
+        ("function foo() { if (true) return; }", None),
+        ("function foo() { if (true) return; else return; }", None),
+        ("function foo() { if (true) { return 1; } else { return 2; } }", None),
+        ("function foo() { if (true) return 1; return 2; }", None),
+        ("function foo() { if (true) return 1; throw new Error(); }", None),
+        ("function foo() { while (x) { return 1; } return 2; }", None),
+        ("function foo() { switch (x) { case 1: return 1; default: return 2; } }", None),
+        ("function foo() { try { return 1; } catch (e) { return 2; } }", None),
+        ("function foo() { var bar = function () { return; }; return 1; }", None),
+        ("function foo() { var bar = () => { return; }; return 1; }", None),
+        ("function foo() { var bar = () => 1; if (x) return; }", None),
+        ("function foo() {}", None),
+        ("var foo = () => { return 1; };", None),
+        ("var foo = () => 1;", None),
+        ("class A { foo() { if (x) return 1; return 2; } }", None),
+        ("var obj = { foo() { return; } };", None),
+        (
+            "function foo() { if (x) return undefined; return; }",
+            Some(json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (x) return void 0; return; }",
+            Some(json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (x) return void 0; }",
+            Some(json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (x) return; return undefined; }",
+            Some(json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) return 1; else return; }", None),
+        ("function foo() { if (true) return; else return 1; }", None),
+        ("var foo = () => { if (true) return 1; else return; };", None),
+        ("function foo() { if (true) return 1; }", None),
+        ("var foo = function () { if (true) return 1; };", None),
+        ("var foo = () => { if (true) return 1; };", None),
+        ("class A { foo() { if (x) return 1; } }", None),
+        ("var obj = { foo() { if (x) return 1; } };", None),
+        ("function foo() { while (x) { return 1; } }", None),
+        ("function foo() { switch (x) { case 1: return 1; } }", None),
+        ("function foo() { if (x) return; return undefined; }", None),
+        (
+            "function foo() { if (x) return undefined; return 1; }",
+            Some(json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (x) return 1; return void 0; }",
+            Some(json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+    ];
+
+    Tester::new(ConsistentReturn::NAME, ConsistentReturn::PLUGIN, pass, fail).test_and_snapshot();
+}
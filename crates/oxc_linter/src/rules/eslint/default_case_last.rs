@@ -75,17 +75,13 @@ impl Rule for DefaultCaseLast {
         let AstKind::SwitchStatement(switch) = node.kind() else {
             return;
         };
-        let cases = &switch.cases;
-        let index_of_default = cases.iter().position(|c| c.test.is_none());
-
-        if let Some(index) = index_of_default {
-            if index != cases.len() - 1 {
-                let default_clause = &cases[index];
-                ctx.diagnostic(default_case_last_diagnostic(Span::sized(
-                    default_clause.span.start,
-                    7,
-                )));
-            }
+        let Some((index, default_clause)) =
+            switch.cases.iter().enumerate().find(|(_, case)| case.is_default_case())
+        else {
+            return;
+        };
+        if index != switch.cases.len() - 1 {
+            ctx.diagnostic(default_case_last_diagnostic(Span::sized(default_clause.span.start, 7)));
         }
     }
 }
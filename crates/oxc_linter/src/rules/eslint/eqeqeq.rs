@@ -74,8 +74,8 @@ declare_oxc_lint!(
     ///
     /// Examples of **incorrect** code for this option:
     /// ```js
-    /// a == b
-    /// [] == true
+    /// a == b;
+    /// [] == true;
     /// ```
     ///
     /// Examples of **correct** code for this option:
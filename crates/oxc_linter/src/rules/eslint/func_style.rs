@@ -132,6 +132,7 @@ declare_oxc_lint!(
     /// var foo = function() {
     ///     // ...
     /// };
+    /// ```
     ///
     /// Examples of correct code for this rule with the "declaration" option:
     /// ```js
@@ -50,7 +50,7 @@ declare_oxc_lint!(
     ///   for (;;) { // Nested 1 deep
     ///     while (true) { // Nested 2 deep
     ///       if (true) { // Nested 3 deep
-    ///         if (true) { // Nested 4 deep }
+    ///         if (true) { /* Nested 4 deep */ }
     ///       }
     ///     }
     ///   }
@@ -62,7 +62,7 @@ declare_oxc_lint!(
     /// function foo() {
     ///   for (;;) { // Nested 1 deep
     ///     while (true) { // Nested 2 deep
-    ///       if (true) { // Nested 3 deep }
+    ///       if (true) { /* Nested 3 deep */ }
     ///     }
     ///   }
     /// }
@@ -78,7 +78,7 @@ declare_oxc_lint!(
     ///     class C {
     ///       static {
     ///         if (true) { // Nested 1 deep
-    ///           if (true) { // Nested 2 deep }
+    ///           if (true) { /* Nested 2 deep */ }
     ///         }
     ///       }
     ///     }
@@ -40,10 +40,18 @@ declare_oxc_lint!(
     /// class C {
     ///   constructor() { this.value = 42; }
     /// }
+    ///
+    /// // a bare `return` is allowed for flow control
+    /// class D {
+    ///   constructor(value) {
+    ///     if (!value) return;
+    ///     this.value = value;
+    ///   }
+    /// }
     /// ```
     NoConstructorReturn,
     eslint,
-    pedantic
+    correctness
 );
 
 impl Rule for NoConstructorReturn {
@@ -102,11 +110,25 @@ fn test() {
         "class C { constructor() { this.fn = () => { return true } } }",
         "class C { constructor() { return } }",
         "class C { constructor() { { return } } }",
+        "class C { constructor() { for (const a of b) { if (a) return } } }",
+        "class C { constructor() { this.obj = { method() { return 1 } } } }",
+        "class C { constructor() { class D { method() { return 1 } } } }",
+        "class C { constructor() { class D { get value() { return 1 } } } }",
+        "class C { constructor() { this.ids = list.map((item) => { return item.id }) } }",
+        "class C { constructor() { this.load = async () => { return await fetch(url) } } }",
+        "class C { constructor(a) { if (a) { return; } this.a = a; return; } }",
     ];
 
     let fail = vec![
         "class C { constructor() { return '' } }",
         "class C { constructor(a) { if (!a) { return '' } else { a() } } }",
+        "class C { constructor() { return this } }",
+        "const C = class { constructor() { return {} } }",
+        "class C { constructor(a) { switch (a) { case 1: return 1; default: return } } }",
+        "class C { constructor() { try { return foo() } catch { } } }",
+        "class C { constructor() { class D { constructor() { return 1 } } } }",
+        "class C { constructor() { return { a: 1 } } }",
+        "class C extends B { constructor() { super(); return Object.create(null) } }",
     ];
 
     Tester::new(NoConstructorReturn::NAME, NoConstructorReturn::PLUGIN, pass, fail)
@@ -36,9 +36,16 @@ fn no_duplicate_exports_diagnostic(
         ])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct NoDuplicateImports {
     include_exports: bool,
+    consider_type_imports_separate: bool,
+}
+
+impl Default for NoDuplicateImports {
+    fn default() -> Self {
+        Self { include_exports: false, consider_type_imports_separate: true }
+    }
 }
 
 declare_oxc_lint!(
@@ -105,6 +112,20 @@ declare_oxc_lint!(
     /// // cannot be written differently
     /// export * from 'module';
     /// ```
+    ///
+    /// #### considerTypeImportsSeparate
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// When `true`, TypeScript type-only imports (`import type ... from 'module'`) and exports
+    /// are tracked separately from value imports and exports, so having one of each for the same
+    /// module is not reported. Set to `false` to report them as duplicates too.
+    ///
+    /// Examples of **correct** code with the default `true`:
+    /// ```ts
+    /// import type { Foo } from 'module';
+    /// import { bar } from 'module';
+    /// ```
     NoDuplicateImports,
     eslint,
     style,
@@ -134,19 +155,40 @@ impl Rule for NoDuplicateImports {
                 .and_then(|v| v.get("includeExports"))
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(false),
+            consider_type_imports_separate: value
+                .and_then(|v| v.get("considerTypeImportsSeparate"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
         }
     }
 
     fn run_once(&self, ctx: &LintContext) {
         let module_record = ctx.module_record();
-        let mut import_map: FxHashMap<&CompactStr, Vec<(ImportType, Span, ModuleType)>> =
-            FxHashMap::default();
+        // Keyed by the module source and whether the statement is type-only, which is always
+        // `false` unless type-only imports and exports are considered separate.
+        let mut import_map: FxHashMap<(&CompactStr, bool), Vec<_>> = FxHashMap::default();
         let mut previous_span: Option<Span> = None;
         let mut side_effect_import_map: FxHashMap<&CompactStr, Vec<Span>> = FxHashMap::default();
 
+        // An import statement is type-only if all of its bindings are types,
+        // e.g. `import type { a } from 'mod'`.
+        let type_only_statements = if self.consider_type_imports_separate {
+            module_record.import_entries.iter().fold(
+                FxHashMap::default(),
+                |mut acc: FxHashMap<Span, bool>, entry| {
+                    *acc.entry(entry.statement_span).or_insert(true) &= entry.is_type;
+                    acc
+                },
+            )
+        } else {
+            FxHashMap::default()
+        };
+
         for entry in &module_record.import_entries {
             let source = &entry.module_request.name;
             let span = entry.module_request.span;
+            let key =
+                (source, type_only_statements.get(&entry.statement_span).copied().unwrap_or(false));
 
             let import_type = match &entry.import_name {
                 ImportImportName::Name(_) => ImportType::Named,
@@ -157,7 +199,7 @@ impl Rule for NoDuplicateImports {
             if previous_span != Some(span) {
                 previous_span = Some(span);
 
-                if let Some(existing) = import_map.get(source) {
+                if let Some(existing) = import_map.get(&key) {
                     if can_merge_imports(&import_type, existing) {
                         ctx.diagnostic(no_duplicate_imports_diagnostic(
                             source,
@@ -169,7 +211,7 @@ impl Rule for NoDuplicateImports {
                 }
             }
 
-            import_map.entry(source).or_default().push((import_type, span, ModuleType::Import));
+            import_map.entry(key).or_default().push((import_type, span, ModuleType::Import));
         }
 
         if module_record.import_entries.is_empty() {
@@ -202,9 +244,10 @@ impl Rule for NoDuplicateImports {
                 };
                 let source = &module_request.name;
                 let span = entry.span;
+                let key = (source, self.consider_type_imports_separate && entry.is_type);
 
                 if entry.import_name.is_all_but_default() {
-                    if let Some(existing) = import_map.get(source) {
+                    if let Some(existing) = import_map.get(&key) {
                         if existing.iter().any(|(t, _, _)| matches!(t, ImportType::AllButDefault)) {
                             ctx.diagnostic(no_duplicate_exports_diagnostic(
                                 source,
@@ -222,14 +265,14 @@ impl Rule for NoDuplicateImports {
                         ));
                         continue;
                     }
-                    import_map.entry(source).or_default().push((
+                    import_map.entry(key).or_default().push((
                         ImportType::AllButDefault,
                         span,
                         ModuleType::Export,
                     ));
                     continue;
                 }
-                if let Some(existing) = import_map.get(source) {
+                if let Some(existing) = import_map.get(&key) {
                     if existing
                         .iter()
                         .any(|(t, _, _)| matches!(t, ImportType::Named | ImportType::SideEffect))
@@ -243,7 +286,7 @@ impl Rule for NoDuplicateImports {
                     }
                 }
 
-                import_map.entry(source).or_default().push((
+                import_map.entry(key).or_default().push((
                     ImportType::SideEffect,
                     span,
                     ModuleType::Export,
@@ -256,8 +299,9 @@ impl Rule for NoDuplicateImports {
                 };
                 let source = &module_request.name;
                 let span = entry.span;
+                let key = (source, self.consider_type_imports_separate && entry.is_type);
 
-                if let Some(existing) = import_map.get(source) {
+                if let Some(existing) = import_map.get(&key) {
                     if entry.import_name == ExportImportName::All {
                         if existing.iter().any(|(t, _, _)| {
                             matches!(t, ImportType::Default | ImportType::Namespace)
@@ -293,7 +337,7 @@ impl Rule for NoDuplicateImports {
                     }
                 }
 
-                import_map.entry(source).or_default().push((
+                import_map.entry(key).or_default().push((
                     ImportType::Named,
                     span,
                     ModuleType::Export,
@@ -440,6 +484,27 @@ fn test() {
             ",
             Some(serde_json::json!([{ "includeExports": true }])),
         ),
+        (
+            "
+                import type { A } from 'x';
+                import { b } from 'x';
+            ",
+            None,
+        ),
+        (
+            "
+                import { b } from 'x';
+                import type { A } from 'x';
+            ",
+            Some(serde_json::json!([{ "considerTypeImportsSeparate": true }])),
+        ),
+        (
+            "
+                import { b } from 'x';
+                export type { A } from 'x';
+            ",
+            Some(serde_json::json!([{ "includeExports": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -523,7 +588,7 @@ fn test() {
             // https://github.com/oxc-project/oxc/pull/11320#issuecomment-2912286528
             r#"import type { PriorityDialogCustomClassNames, WeightDialogCustomClassNames } from "./HostEditDialogs";
             import { PriorityDialog, WeightDialog } from "./HostEditDialogs";"#,
-            None,
+            Some(serde_json::json!([{ "considerTypeImportsSeparate": false }])),
         ),
         (
             "
@@ -540,6 +605,20 @@ fn test() {
             ",
             None,
         ),
+        (
+            "
+                import type { A } from 'x';
+                import type { B } from 'x';
+            ",
+            None,
+        ),
+        (
+            "
+                import type { A } from 'x';
+                import { b } from 'x';
+            ",
+            Some(serde_json::json!([{ "considerTypeImportsSeparate": false }])),
+        ),
     ];
 
     Tester::new(NoDuplicateImports::NAME, NoDuplicateImports::PLUGIN, pass, fail)
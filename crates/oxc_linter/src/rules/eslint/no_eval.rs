@@ -314,6 +314,32 @@ fn test() {
         ),
         ("this.noeval('foo');", None, None, None),
         ("function foo() { 'use strict'; this.eval('foo'); }", None, None, None),
+        // Anonymous functions assigned to a capitalized name are constructors.
+        (
+            "var Foo = function () { this.eval('foo'); };",
+            allow_indirect_with_false(),
+            None,
+            Some(PathBuf::from("foo.cjs")),
+        ),
+        (
+            "Foo = function () { this.eval('foo'); };",
+            allow_indirect_with_false(),
+            None,
+            Some(PathBuf::from("foo.cjs")),
+        ),
+        // `this` is bound to the `thisArg` argument.
+        (
+            "foo.forEach(function () { this.eval('foo'); }, obj);",
+            allow_indirect_with_false(),
+            None,
+            Some(PathBuf::from("foo.cjs")),
+        ),
+        (
+            "Array.from(foo, function () { this.eval('foo'); }, obj);",
+            allow_indirect_with_false(),
+            None,
+            Some(PathBuf::from("foo.cjs")),
+        ),
         ("'use strict'; this.eval('foo');", None, None, None), // { "parserOptions": { "ecmaFeatures": { "globalReturn": true } } },
         ("this.eval('foo');", None, None, None), // { "ecmaVersion": 6, "sourceType": "module" },
         ("function foo() { this.eval('foo'); }", None, None, None),
@@ -520,6 +546,19 @@ fn test() {
             None,
             Some(PathBuf::from("foo.cjs")),
         ),
+        // Named functions are only constructors if their own name is capitalized.
+        (
+            "Foo = function foo() { this.eval('foo'); };",
+            allow_indirect_with_false(),
+            None,
+            Some(PathBuf::from("foo.cjs")),
+        ),
+        (
+            "var Foo = function bar() { this.eval('foo'); };",
+            allow_indirect_with_false(),
+            None,
+            Some(PathBuf::from("foo.cjs")),
+        ),
     ];
 
     Tester::new(NoEval::NAME, NoEval::PLUGIN, pass, fail).test_and_snapshot();
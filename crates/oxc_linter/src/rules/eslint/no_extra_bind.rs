@@ -1,12 +1,12 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression, Function, ThisExpression},
+    ast::{Argument, Expression, Function, MemberExpression, ThisExpression},
 };
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeFlags;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
 
@@ -56,7 +56,7 @@ declare_oxc_lint!(
     NoExtraBind,
     eslint,
     suspicious,
-    pending
+    fix
 );
 
 impl Rule for NoExtraBind {
@@ -83,7 +83,7 @@ impl Rule for NoExtraBind {
             return;
         };
         let obj = member_expr.object().get_inner_expression();
-        match obj {
+        let is_unnecessary = match obj {
             Expression::FunctionExpression(func_expr) => {
                 let Some(body) = &func_expr.body else {
                     return;
@@ -91,18 +91,73 @@ impl Rule for NoExtraBind {
                 let mut finder = ThisFinder { found: false };
                 finder.visit_function_body(body);
                 // don't use this expression
-                if !finder.found {
-                    ctx.diagnostic(no_extra_bind_diagnostic(span));
-                }
+                !finder.found
             }
-            Expression::ArrowFunctionExpression(_) => {
-                ctx.diagnostic(no_extra_bind_diagnostic(span));
-            }
-            _ => {}
+            Expression::ArrowFunctionExpression(_) => true,
+            _ => false,
+        };
+        if !is_unnecessary {
+            return;
         }
+
+        let diagnostic = no_extra_bind_diagnostic(span);
+        let Some(access_start) = member_access_start(member_expr, ctx) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        // Removing the arguments must not remove side effects or comments.
+        if !call_expr.arguments.first().is_some_and(is_side_effect_free)
+            || ctx.has_comments_between(Span::new(access_start, call_expr.span.end))
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            // Keep the parentheses around the callee, e.g. in `(function() {}.bind)(a)`.
+            let callee_end = call_expr.callee.span().end;
+            let replacement = format!(
+                "{}{}",
+                ctx.source_range(Span::new(call_expr.span.start, access_start)),
+                ctx.source_range(Span::new(member_expr.span().end, callee_end))
+            );
+            fixer.replace(call_expr.span, replacement)
+        });
     }
 }
 
+/// The start of the `.`, `?.` or `[` token of a `.bind` member access.
+fn member_access_start(member_expr: &MemberExpression, ctx: &LintContext) -> Option<u32> {
+    let object_end = member_expr.object().span().end;
+    let property_start = match member_expr {
+        MemberExpression::StaticMemberExpression(member) => member.property.span.start,
+        MemberExpression::ComputedMemberExpression(member) => member.expression.span().start,
+        MemberExpression::PrivateFieldExpression(_) => return None,
+    };
+    let comments = ctx.comments_range(object_end..property_start).collect::<Vec<_>>();
+    ctx.source_range(Span::new(object_end, property_start)).char_indices().find_map(|(i, c)| {
+        let position = object_end + u32::try_from(i).ok()?;
+        let in_comment = comments
+            .iter()
+            .any(|comment| comment.span.start <= position && position < comment.span.end);
+        (matches!(c, '.' | '?' | '[') && !in_comment).then_some(position)
+    })
+}
+
+fn is_side_effect_free(argument: &Argument) -> bool {
+    matches!(
+        argument,
+        Argument::BooleanLiteral(_)
+            | Argument::NullLiteral(_)
+            | Argument::NumericLiteral(_)
+            | Argument::BigIntLiteral(_)
+            | Argument::RegExpLiteral(_)
+            | Argument::StringLiteral(_)
+            | Argument::Identifier(_)
+            | Argument::ThisExpression(_)
+            | Argument::FunctionExpression(_)
+    )
+}
+
 struct ThisFinder {
     found: bool,
 }
@@ -174,59 +229,64 @@ fn test() {
         "var a = (function() { return 1; }?.['bind'])(b)", // { "ecmaVersion": 2020 }
         "var a = function() { function v() { this } }.bind(a)",
     ];
-    // pending
-    // let fix = vec![
-    //     ("var a = function() { return 1; }.bind(b)", "var a = function() { return 1; }", None),
-    //     ("var a = function() { return 1; }['bind'](b)", "var a = function() { return 1; }", None),
-    //     ("var a = function() { return 1; }[`bind`](b)", "var a = function() { return 1; }", None),
-    //     ("var a = (() => { return 1; }).bind(b)", "var a = (() => { return 1; })", None),
-    //     ("var a = (() => { return this; }).bind(b)", "var a = (() => { return this; })", None),
-    //     (
-    //         "var a = function() { (function(){ this.c }) }.bind(b)",
-    //         "var a = function() { (function(){ this.c }) }",
-    //         None,
-    //     ),
-    //     (
-    //         "var a = function() { function c(){ this.d } }.bind(b)",
-    //         "var a = function() { function c(){ this.d } }",
-    //         None,
-    //     ),
-    //     ("var a = function() { return 1; }.bind(this)", "var a = function() { return 1; }", None),
-    //     (
-    //         "var a = function() { (function(){ (function(){ this.d }.bind(c)) }) }.bind(b)",
-    //         "var a = function() { (function(){ (function(){ this.d }.bind(c)) }) }",
-    //         None,
-    //     ),
-    //     (
-    //         "var a = (function() { return 1; }).bind(this)",
-    //         "var a = (function() { return 1; })",
-    //         None,
-    //     ),
-    //     (
-    //         "var a = (function() { return 1; }.bind)(this)",
-    //         "var a = (function() { return 1; })",
-    //         None,
-    //     ),
-    //     ("var a = function() {}/**/.bind(b)", "var a = function() {}/**/", None),
-    //     ("var a = function() {}/**/['bind'](b)", "var a = function() {}/**/", None),
-    //     (
-    //         "var a = function() {}//comment
-    // 		.bind(b)",
-    //         "var a = function() {}//comment
-    // 		",
-    //         None,
-    //     ),
-    //     ("var a = function() {}.bind(b)/**/", "var a = function() {}/**/", None),
-    //     ("var a = function() { return 1; }.bind?.(b)", "var a = function() { return 1; }", None),
-    //     ("var a = function() { return 1; }?.bind(b)", "var a = function() { return 1; }", None),
-    //     ("var a = (function() { return 1; }?.bind)(b)", "var a = (function() { return 1; })", None),
-    //     ("var a = function() { return 1; }['bind']?.(b)", "var a = function() { return 1; }", None),
-    //     ("var a = function() { return 1; }?.['bind'](b)", "var a = function() { return 1; }", None),
-    //     (
-    //         "var a = (function() { return 1; }?.['bind'])(b)",
-    //         "var a = (function() { return 1; })",
-    //         None,
-    //     ),
-    // ];
-    Tester::new(NoExtraBind::NAME, NoExtraBind::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("var a = function() { return 1; }.bind(b)", "var a = function() { return 1; }", None),
+        ("var a = function() { return 1; }['bind'](b)", "var a = function() { return 1; }", None),
+        ("var a = function() { return 1; }[`bind`](b)", "var a = function() { return 1; }", None),
+        ("var a = (() => { return 1; }).bind(b)", "var a = (() => { return 1; })", None),
+        ("var a = (() => { return this; }).bind(b)", "var a = (() => { return this; })", None),
+        (
+            "var a = function() { (function(){ this.c }) }.bind(b)",
+            "var a = function() { (function(){ this.c }) }",
+            None,
+        ),
+        (
+            "var a = function() { function c(){ this.d } }.bind(b)",
+            "var a = function() { function c(){ this.d } }",
+            None,
+        ),
+        ("var a = function() { return 1; }.bind(this)", "var a = function() { return 1; }", None),
+        (
+            "var a = function() { (function(){ (function(){ this.d }.bind(c)) }) }.bind(b)",
+            "var a = function() { (function(){ (function(){ this.d }.bind(c)) }) }",
+            None,
+        ),
+        (
+            "var a = (function() { return 1; }).bind(this)",
+            "var a = (function() { return 1; })",
+            None,
+        ),
+        (
+            "var a = (function() { return 1; }.bind)(this)",
+            "var a = (function() { return 1; })",
+            None,
+        ),
+        ("var a = function() {}/**/.bind(b)", "var a = function() {}/**/", None),
+        ("var a = function() {}/**/['bind'](b)", "var a = function() {}/**/", None),
+        (
+            "var a = function() {}//comment
+    		.bind(b)",
+            "var a = function() {}//comment
+    		",
+            None,
+        ),
+        ("var a = function() {}.bind(b)/**/", "var a = function() {}/**/", None),
+        ("var a = function() { return 1; }.bind?.(b)", "var a = function() { return 1; }", None),
+        ("var a = function() { return 1; }?.bind(b)", "var a = function() { return 1; }", None),
+        ("var a = (function() { return 1; }?.bind)(b)", "var a = (function() { return 1; })", None),
+        ("var a = function() { return 1; }['bind']?.(b)", "var a = function() { return 1; }", None),
+        ("var a = function() { return 1; }?.['bind'](b)", "var a = function() { return 1; }", None),
+        (
+            "var a = (function() { return 1; }?.['bind'])(b)",
+            "var a = (function() { return 1; })",
+            None,
+        ),
+        ("var a = function() {}.bind(b())", "var a = function() {}.bind(b())", None),
+        ("var a = function() {}.bind(b.c)", "var a = function() {}.bind(b.c)", None),
+        ("var a = function() {}./**/bind(b)", "var a = function() {}./**/bind(b)", None),
+        ("var a = function() {}.bind(b/**/)", "var a = function() {}.bind(b/**/)", None),
+    ];
+    Tester::new(NoExtraBind::NAME, NoExtraBind::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
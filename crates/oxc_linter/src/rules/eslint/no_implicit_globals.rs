@@ -0,0 +1,215 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolFlags;
+use oxc_span::Span;
+
+use crate::{
+    config::GlobalValue,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn global_non_lexical_binding_diagnostic(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected {kind} declaration in the global scope."))
+        .with_help("Wrap it in an IIFE for a local variable, or assign it as a global property for a global variable.")
+        .with_label(span)
+}
+
+fn global_lexical_binding_diagnostic(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected {kind} declaration in the global scope."))
+        .with_help("Wrap it in a block to keep it local.")
+        .with_label(span)
+}
+
+fn global_variable_leak_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Global variable leak: `{name}` is not declared."))
+        .with_help("Declare the variable if it is intended to be local.")
+        .with_label(span)
+}
+
+fn assignment_to_readonly_global_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected assignment to read-only global variable `{name}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoImplicitGlobals {
+    lexical_bindings: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow declarations in the global scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In browsers, top-level `var` and `function` declarations of scripts create properties of
+    /// the global object, and so do assignments to undeclared variables in non-strict code.
+    /// These globals are shared by all scripts on the page and can easily clash with each other.
+    ///
+    /// Top-level `let`, `const` and `class` declarations don't create properties of the global
+    /// object, but they are still shared by all scripts and can clash as well. They are only
+    /// reported with the `lexicalBindings` option.
+    ///
+    /// This rule only checks scripts, as declarations in modules are local to the module.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = 1;
+    ///
+    /// function bar() {}
+    ///
+    /// baz = 1;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// window.foo = 1;
+    ///
+    /// (function () {
+    ///   var foo = 1;
+    ///
+    ///   function bar() {}
+    /// })();
+    ///
+    /// const baz = 1;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### lexicalBindings
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also report top-level `let`, `const` and `class` declarations.
+    ///
+    /// Example:
+    /// ```json
+    /// "eslint/no-implicit-globals": [
+    ///   "error",
+    ///   { "lexicalBindings": true }
+    /// ]
+    /// ```
+    NoImplicitGlobals,
+    eslint,
+    restriction
+);
+
+impl Rule for NoImplicitGlobals {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let lexical_bindings = value
+            .get(0)
+            .and_then(|config| config.get("lexicalBindings"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { lexical_bindings }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let scoping = ctx.scoping();
+
+        let mut symbol_ids = scoping.iter_bindings_in(scoping.root_scope_id()).collect::<Vec<_>>();
+        symbol_ids.sort_unstable_by_key(|&symbol_id| scoping.symbol_span(symbol_id).start);
+
+        for symbol_id in symbol_ids {
+            let flags = scoping.symbol_flags(symbol_id);
+            let span = scoping.symbol_span(symbol_id);
+            if flags.is_function() {
+                ctx.diagnostic(global_non_lexical_binding_diagnostic("function", span));
+            } else if flags.contains(SymbolFlags::FunctionScopedVariable) {
+                ctx.diagnostic(global_non_lexical_binding_diagnostic("`var`", span));
+            } else if self.lexical_bindings && flags.is_class() {
+                ctx.diagnostic(global_lexical_binding_diagnostic("class", span));
+            } else if self.lexical_bindings && flags.contains(SymbolFlags::BlockScopedVariable) {
+                let kind = match ctx.nodes().parent_kind(scoping.symbol_declaration(symbol_id)) {
+                    AstKind::VariableDeclaration(decl) => decl.kind.as_str(),
+                    _ => "let",
+                };
+                ctx.diagnostic(global_lexical_binding_diagnostic(&format!("`{kind}`"), span));
+            }
+        }
+
+        for (name, reference_ids) in scoping.root_unresolved_references() {
+            for &reference_id in reference_ids {
+                let reference = scoping.get_reference(reference_id);
+                if !reference.is_write() {
+                    continue;
+                }
+                // Assigning to an undeclared variable throws in strict mode code.
+                let scope_id = ctx.nodes().get_node(reference.node_id()).scope_id();
+                if scoping.scope_flags(scope_id).is_strict_mode() {
+                    continue;
+                }
+                let span = ctx.semantic().reference_span(reference);
+                match ctx.get_global_variable_value(name) {
+                    Some(GlobalValue::Writeable) => {}
+                    Some(GlobalValue::Readonly) => {
+                        ctx.diagnostic(assignment_to_readonly_global_diagnostic(name, span));
+                    }
+                    _ => ctx.diagnostic(global_variable_leak_diagnostic(name, span)),
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_script()
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let module = || Some(PathBuf::from("foo.js"));
+
+    let pass = vec![
+        ("const foo = 1;", None, None, None),
+        ("let foo = 1;", None, None, None),
+        ("class Foo {}", None, None, None),
+        ("window.foo = 1;", None, None, None),
+        ("globalThis.foo = function () {};", None, None, None),
+        ("(function () { var foo = 1; function bar() {} })();", None, None, None),
+        ("{ let foo = 1; }", None, None, None),
+        ("var foo = function () {};", None, None, module()),
+        ("function foo() {}", None, None, module()),
+        ("var foo = 1; export {};", None, None, module()),
+        ("foo = 1;", None, None, module()),
+        ("const foo = 1;", Some(json!([{ "lexicalBindings": true }])), None, module()),
+        ("class Foo {}", Some(json!([{ "lexicalBindings": true }])), None, module()),
+        ("'use strict'; foo = 1;", None, None, None),
+        ("(function () { 'use strict'; foo = 1; })();", None, None, None),
+        ("foo;", None, None, None),
+        ("foo = 1;", None, Some(json!({ "globals": { "foo": "writable" } })), None),
+    ];
+
+    let fail = vec![
+        ("var foo = 1;", None, None, None),
+        ("var foo;", None, None, None),
+        ("var foo = 1, bar = 2;", None, None, None),
+        ("var { foo, bar } = baz;", None, None, None),
+        ("function foo() {}", None, None, None),
+        ("if (x) { var foo = 1; }", None, None, None),
+        ("'use strict'; var foo = 1;", None, None, None),
+        ("foo = 1;", None, None, None),
+        ("function f() { foo = 1; }", None, None, None),
+        ("for (foo in bar) {}", None, None, None),
+        ("foo++;", None, None, None),
+        ("foo = 1;", None, Some(json!({ "globals": { "foo": "readonly" } })), None),
+        ("const foo = 1;", Some(json!([{ "lexicalBindings": true }])), None, None),
+        ("let foo = 1;", Some(json!([{ "lexicalBindings": true }])), None, None),
+        ("class Foo {}", Some(json!([{ "lexicalBindings": true }])), None, None),
+    ];
+
+    Tester::new(NoImplicitGlobals::NAME, NoImplicitGlobals::PLUGIN, pass, fail)
+        .change_rule_path_extension("cjs")
+        .test_and_snapshot();
+}
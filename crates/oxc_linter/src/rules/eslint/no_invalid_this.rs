@@ -0,0 +1,224 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, ast_util, context::LintContext, rule::Rule};
+
+fn no_invalid_this_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `this`.")
+        .with_help("`this` is `undefined` here. Use it only in methods, constructors and class fields, or bind the function explicitly.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoInvalidThis {
+    cap_is_constructor: bool,
+}
+
+impl Default for NoInvalidThis {
+    fn default() -> Self {
+        NoInvalidThis { cap_is_constructor: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `this` keywords outside of classes or class-like objects.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In strict mode, `this` is `undefined` inside plain functions and at the top level of
+    /// modules. Using it there is almost always a mistake, e.g. a function that was meant to be
+    /// a method or a callback that was meant to be bound.
+    ///
+    /// This rule only checks code in strict mode, which includes modules and classes.
+    /// `this` is considered valid in:
+    /// - class methods, constructors, field initializers and static blocks,
+    /// - object methods and functions assigned to properties,
+    /// - constructors, i.e. functions whose name starts with an uppercase letter,
+    /// - functions bound with `.bind()`, `.call()` or `.apply()`, or passed along with a
+    ///   `thisArg` to methods like `Array.prototype.forEach`,
+    /// - functions with a `@this` JSDoc tag or a TypeScript `this` parameter.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// this.a = 0;
+    ///
+    /// function foo() {
+    ///   this.a = 0;
+    /// }
+    ///
+    /// foo(function () {
+    ///   this.a = 0;
+    /// });
+    ///
+    /// const bar = () => {
+    ///   this.a = 0;
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function Foo() {
+    ///   this.a = 0;
+    /// }
+    ///
+    /// const obj = {
+    ///   foo() {
+    ///     this.a = 0;
+    ///   },
+    /// };
+    ///
+    /// class Bar {
+    ///   a = this;
+    ///
+    ///   foo() {
+    ///     this.a = 0;
+    ///   }
+    /// }
+    ///
+    /// foo(function () {
+    ///   this.a = 0;
+    /// }.bind(obj));
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### capIsConstructor
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Treat functions whose name starts with an uppercase letter as constructors, including
+    /// anonymous functions assigned to such a name, e.g. `const Foo = function () {}`.
+    ///
+    /// Example:
+    /// ```json
+    /// "eslint/no-invalid-this": [
+    ///   "error",
+    ///   { "capIsConstructor": false }
+    /// ]
+    /// ```
+    NoInvalidThis,
+    eslint,
+    restriction
+);
+
+impl Rule for NoInvalidThis {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let cap_is_constructor = value
+            .get(0)
+            .and_then(|config| config.get("capIsConstructor").and_then(serde_json::Value::as_bool))
+            .unwrap_or(true);
+
+        Self { cap_is_constructor }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ThisExpression(this_expr) = node.kind() else {
+            return;
+        };
+
+        // Arrow functions don't bind `this`, so find the closest node which does.
+        for ancestor in ctx.nodes().ancestors(node.id()) {
+            match ancestor.kind() {
+                AstKind::Function(func) => {
+                    if func.this_param.is_some()
+                        || !ctx.scoping().scope_flags(func.scope_id()).is_strict_mode()
+                        || !ast_util::is_default_this_binding(
+                            ctx,
+                            ancestor,
+                            self.cap_is_constructor,
+                        )
+                    {
+                        return;
+                    }
+                    break;
+                }
+                AstKind::PropertyDefinition(prop)
+                    if prop
+                        .value
+                        .as_ref()
+                        .is_some_and(|value| value.span().contains_inclusive(this_expr.span)) =>
+                {
+                    return;
+                }
+                AstKind::AccessorProperty(prop)
+                    if prop
+                        .value
+                        .as_ref()
+                        .is_some_and(|value| value.span().contains_inclusive(this_expr.span)) =>
+                {
+                    return;
+                }
+                AstKind::StaticBlock(_) => return,
+                AstKind::Program(_) => {
+                    if !ctx.scoping().scope_flags(ctx.scoping().root_scope_id()).is_strict_mode() {
+                        return;
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        ctx.diagnostic(no_invalid_this_diagnostic(this_expr.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("class A { foo() { this.a; } }", None),
+        ("class A { constructor() { this.a = 0; } }", None),
+        ("class A { get foo() { return this.a; } }", None),
+        ("class A { a = this.b; }", None),
+        ("class A { a = () => this.b; }", None),
+        ("class A { accessor a = this.b; }", None),
+        ("class A { static { this.a; } }", None),
+        ("class A { foo() { return () => this.a; } }", None),
+        ("var obj = { foo() { this.a; } };", None),
+        ("var obj = { foo: function () { this.a; } };", None),
+        ("var obj = { get foo() { return this.a; } };", None),
+        ("var obj = { foo: function () { return () => this.a; } };", None),
+        ("obj.foo = function () { this.a; };", None),
+        ("function Foo() { this.a = 0; }", None),
+        ("var Foo = function () { this.a = 0; };", None),
+        ("Foo = function () { this.a = 0; };", None),
+        ("foo(function () { this.a; }.bind(obj));", None),
+        ("foo(function () { this.a; }.call(obj));", None),
+        ("foo.forEach(function () { this.a; }, obj);", None),
+        ("Array.from(foo, function () { this.a; }, obj);", None),
+        ("Reflect.apply(function () { this.a; }, obj, []);", None),
+        ("/** @this Obj */ function foo() { this.a; }", None),
+        ("function foo(this: Foo) { this.a; }", None),
+        ("var obj = { foo() { function Bar() { this.a; } } };", None),
+    ];
+
+    let fail = vec![
+        ("this.a;", None),
+        ("() => this.a;", None),
+        ("function foo() { this.a; }", None),
+        ("function foo() { this.a; this.b; }", None),
+        ("var foo = function () { this.a; };", None),
+        ("var Foo = function bar() { this.a; };", None),
+        ("const foo = () => { this.a; };", None),
+        ("function foo() { return () => this.a; }", None),
+        ("class A { foo() { function bar() { this.a; } } }", None),
+        ("class A { a = function () { return function () { this.b; }; }; }", None),
+        ("var obj = { foo() { return function () { this.a; }; } };", None),
+        ("foo(function () { this.a; });", None),
+        ("foo(function () { this.a; }.bind(null));", None),
+        ("foo.forEach(function () { this.a; });", None),
+        ("function Foo() { this.a = 0; }", Some(json!([{ "capIsConstructor": false }]))),
+        ("var Foo = function () { this.a = 0; };", Some(json!([{ "capIsConstructor": false }]))),
+    ];
+
+    Tester::new(NoInvalidThis::NAME, NoInvalidThis::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -1,9 +1,9 @@
 use crate::{AstNode, context::LintContext, rule::Rule};
 use oxc_ast::AstKind;
-use oxc_ast::ast::{IfStatement, Statement};
+use oxc_ast::ast::{BlockStatement, IfStatement, Statement};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 fn no_lonely_if_diagnostic(lonely_if: &IfStatement) -> OxcDiagnostic {
     let span = Span::sized(lonely_if.span.start, 2);
@@ -82,7 +82,7 @@ declare_oxc_lint!(
     NoLonelyIf,
     eslint,
     pedantic,
-    pending
+    fix
 );
 
 impl Rule for NoLonelyIf {
@@ -99,17 +99,30 @@ impl Rule for NoLonelyIf {
             return;
         };
 
-        if let AstKind::IfStatement(_) = ctx.nodes().parent_kind(node.id()) {
-            return;
-        }
-
         match only_stmt {
             Statement::IfStatement(lonely_if) => {
-                ctx.diagnostic(no_lonely_if_diagnostic(lonely_if));
+                if are_braces_necessary(lonely_if, node, ctx) {
+                    return;
+                }
+                let diagnostic = no_lonely_if_diagnostic(lonely_if);
+                if can_fix(alternate_block, lonely_if, ctx) {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        let source_text = ctx.source_text();
+                        let after_else = source_text[..alternate_block.span.start as usize]
+                            .ends_with(|c: char| c.is_ascii_whitespace());
+                        let space = if after_else { "" } else { " " };
+                        let lonely_if_text = ctx.source_range(lonely_if.span);
+                        fixer.replace(alternate_block.span, format!("{space}{lonely_if_text}"))
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
             }
             Statement::BlockStatement(inner_block) => {
                 if let [Statement::IfStatement(lonely_if)] = inner_block.body.as_slice() {
-                    ctx.diagnostic(no_lonely_if_diagnostic(lonely_if));
+                    if !are_braces_necessary(lonely_if, node, ctx) {
+                        ctx.diagnostic(no_lonely_if_diagnostic(lonely_if));
+                    }
                 }
             }
             _ => {}
@@ -117,6 +130,87 @@ impl Rule for NoLonelyIf {
     }
 }
 
+/// Whether removing the braces around `lonely_if` would attach a following `else` to it, e.g.
+/// `if (a) if (b) {} else { if (c) {} } else {}`.
+fn are_braces_necessary(lonely_if: &IfStatement, node: &AstNode, ctx: &LintContext) -> bool {
+    if !ends_with_open_if(lonely_if) {
+        return false;
+    }
+
+    let mut span = node.kind().span();
+    for kind in ctx.nodes().ancestor_kinds(node.id()) {
+        match kind {
+            AstKind::IfStatement(if_stmt) => {
+                if if_stmt.alternate.as_ref().is_some_and(|alternate| alternate.span() == span) {
+                    span = if_stmt.span;
+                } else {
+                    return if_stmt.alternate.is_some();
+                }
+            }
+            // Statements ending with their body.
+            AstKind::LabeledStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::WithStatement(_) => span = kind.span(),
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Whether the last `if` of `if_stmt`'s `else if` chain has no `else`.
+fn ends_with_open_if(if_stmt: &IfStatement) -> bool {
+    match &if_stmt.alternate {
+        Some(Statement::IfStatement(alternate)) => ends_with_open_if(alternate),
+        Some(Statement::BlockStatement(_)) => false,
+        // No `else`, or conservatively assume that other statements, e.g. loops, may end with
+        // an `if`.
+        _ => true,
+    }
+}
+
+/// The statement `if_stmt` ends with, following its `else if` chain.
+fn last_statement<'a, 'b>(if_stmt: &'b IfStatement<'a>) -> &'b Statement<'a> {
+    match &if_stmt.alternate {
+        None => &if_stmt.consequent,
+        Some(Statement::IfStatement(alternate)) => last_statement(alternate),
+        Some(alternate) => alternate,
+    }
+}
+
+/// Whether the braces around `lonely_if` can be removed without losing comments or changing
+/// the meaning of the code following the `else` block.
+fn can_fix(block: &BlockStatement, lonely_if: &IfStatement, ctx: &LintContext) -> bool {
+    let source_text = ctx.source_text();
+    let before_if = &source_text[block.span.start as usize + 1..lonely_if.span.start as usize];
+    let after_if = &source_text[lonely_if.span.end as usize..block.span.end as usize - 1];
+    if !before_if.trim().is_empty() || !after_if.trim().is_empty() {
+        return false;
+    }
+
+    let lonely_if_text = ctx.source_range(lonely_if.span);
+    if lonely_if_text.ends_with(';')
+        || matches!(last_statement(lonely_if), Statement::BlockStatement(_))
+    {
+        return true;
+    }
+
+    // Without the closing brace, the statement ending the `if` may continue with the
+    // following code due to ASI.
+    let after_block = &source_text[block.span.end as usize..];
+    let next_code = after_block.trim_start();
+    if next_code.is_empty() {
+        return true;
+    }
+    let on_same_line = !after_block[..after_block.len() - next_code.len()].contains('\n');
+    !(on_same_line
+        || next_code.starts_with(['(', '[', '/', '+', '`', '-'])
+        || lonely_if_text.ends_with("++")
+        || lonely_if_text.ends_with("--"))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -131,6 +225,13 @@ fn test() {
            if (c) {  }
            if (d) {  }
           }",
+        "if (a) {} else { if (b) {} foo(); }",
+        "if (a) {} else { foo(); if (b) {} }",
+        "if (a) {} else { const b = foo(); if (b) {} }",
+        "if (a) {} else { if (b) {} else {} bar(); }",
+        "if (a) if (b) {} else if (c) {} else { if (d) {} } else {}",
+        "if (a) if (b) {} else { if (c) {} else if (d) {} } else {}",
+        "if (a) for (;;) if (b) {} else { if (c) {} } else {}",
     ];
 
     let fail = vec![
@@ -152,134 +253,89 @@ fn test() {
              bar();
            } /* this comment will prevent this test case from being autofixed. */
          }",
-        // No fix; removing the braces would cause a SyntaxError.
         "if (foo) {
          } else {
-           if (bar) baz();
+           if (bar) baz()
          }
          qux();",
         // Not fixed; removing the braces would change the semantics due to ASI.
         "if (foo) {
          } else {
-           if (bar) baz();
+           if (bar) baz()
          }
          [1, 2, 3].forEach(foo);",
         // Not fixed; removing the braces would change the semantics due to ASI.
         "if (foo) { } else {
-           if (bar) baz++;
+           if (bar) baz++
          }
          foo;",
         // Not fixed; bar() would be interpreted as a template literal tag
         "if (a) {
            foo();
          } else {
-           if (b) bar();
+           if (b) bar()
          }
          `template literal`;",
+        "if (a) {} else { if (b) {} else { if (c) {} } }",
+        "if (a) {} else if (b) {} else { if (c) {} }",
+        "if (a) if (b) {} else { if (c) {} else {} } else {}",
+        "if (a) {} else { { if (b) {} } }",
     ];
 
-    /* Pending
     let fix = vec![
+        ("if (a) {;} else { if (b) {;} }", "if (a) {;} else if (b) {;}"),
+        ("if (foo) {} else { if (bar) baz(); }", "if (foo) {} else if (bar) baz();"),
+        ("if (foo) {} else { if (bar) baz(); } qux();", "if (foo) {} else if (bar) baz(); qux();"),
+        ("if (foo) {} else{ if (bar) baz(); }", "if (foo) {} else if (bar) baz();"),
+        // Not fixed; removing the braces would cause a SyntaxError.
         (
-            "if (a) {
-               foo();
-             } else {
-               if (b) {
-                 bar();
-               }
-             }",
-            "if (a) {
-               foo();
-             } else if (b) {
-               bar();
-             }",
-            None,
+            "if (foo) {} else { if (bar) baz() } qux();",
+            "if (foo) {} else { if (bar) baz() } qux();",
         ),
         (
-        "if (a) {
-           foo();
-         } /* comment */
- else {
-           if (b) {
-             bar();
-           }
-         }",
-        "if (a) {
-           foo();
-    } /* comment */
- else {
-           if (b) {
-             bar();
-           }
-         }",
-            None,
+            "if (foo) {} else {\n  if (bar) baz()\n}\nqux();",
+            "if (foo) {} else if (bar) baz()\nqux();",
         ),
+        ("if (foo) {} else {\n  if (bar) baz()\n}", "if (foo) {} else if (bar) baz()"),
         (
-        "if (a) {
-           foo();
-         } else {
-    if ( /* this comment is ok */
- b) {
-             bar();
-           }
-         }",
-        "if (a) {
-           foo();
-    } else if ( /* this comment is ok */
- b) {
-           bar();
-         }",
-            None,
+            "if (foo) {} else {\n  if (bar) baz()\n}\n[1, 2, 3].forEach(foo);",
+            "if (foo) {} else {\n  if (bar) baz()\n}\n[1, 2, 3].forEach(foo);",
         ),
         (
-        "if (foo) {} else { if (bar) baz(); }",
-        "if (foo) {} else if (bar) baz();",
-            None,
+            "if (foo) {} else {\n  if (bar) baz++\n}\nfoo;",
+            "if (foo) {} else {\n  if (bar) baz++\n}\nfoo;",
         ),
         (
-        "if (foo) { } else { if (bar) baz(); } qux();",
-        "if (foo) { } else if (bar) baz(); qux();",
-            None,
+            "if (a) {} else {\n  if (b) bar()\n}\n`template literal`;",
+            "if (a) {} else {\n  if (b) bar()\n}\n`template literal`;",
         ),
         (
-            "if (foo) { } else { if (bar) baz++; } foo;",
-            "if (foo) { } else if (bar) baz++; foo;",
-            None,
+            "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  } else if (c) {\n    baz();\n  } else {\n    qux();\n  }\n}",
+            "if (a) {\n  foo();\n} else if (b) {\n    bar();\n  } else if (c) {\n    baz();\n  } else {\n    qux();\n  }",
         ),
+        // Comments inside of the `if` are kept.
+        ("if (a) {} else { if (/* comment */ b) {} }", "if (a) {} else if (/* comment */ b) {}"),
+        ("if (a) {} else { if (b) { /* comment */ } }", "if (a) {} else if (b) { /* comment */ }"),
+        // Not fixed; the comments would be removed.
         (
-        "if (a) {
-           foo();
-         } else {
-           if (b) {
-             bar();
-           } else if (c) {
-             baz();
-           } else {
-             qux();
-           }
-         }",
-        "if (a) {
-           foo();
-         } else if (b) {
-           bar();
-         } else if (c) {
-           baz();
-         } else {
-           qux();
-         }",
-            None,
+            "if (a) {} else { /* comment */ if (b) {} }",
+            "if (a) {} else { /* comment */ if (b) {} }",
+        ),
+        ("if (a) {} else { if (b) {} // comment\n}", "if (a) {} else { if (b) {} // comment\n}"),
+        // Nested lonely `if`s are fixed one at a time.
+        (
+            "if (a) {} else { if (b) {} else { if (c) {} } }",
+            "if (a) {} else if (b) {} else { if (c) {} }",
         ),
-        ("if (a) {;} else { if (b) {;} }", "if (a) {;} else if (b) {;}", None),
-        ("if (foo) {} else { if (bar) baz(); }", "if (foo) {} else if (bar) baz();", None),
+        ("if (a) {} else if (b) {} else { if (c) {} }", "if (a) {} else if (b) {} else if (c) {}"),
         (
-            "if (foo) {} else { if (bar) baz(); } qux();",
-            "if (foo) {} else if (bar) baz(); qux();",
-            None,
+            "if (a) if (b) {} else { if (c) {} else {} } else {}",
+            "if (a) if (b) {} else if (c) {} else {} else {}",
         ),
+        ("if (a) {} else { { if (b) {} } }", "if (a) {} else { { if (b) {} } }"),
     ];
-    */
 
     Tester::new(NoLonelyIf::NAME, NoLonelyIf::PLUGIN, pass, fail)
-        //  .expect_fix(fix)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
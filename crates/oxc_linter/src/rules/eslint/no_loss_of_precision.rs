@@ -1,10 +1,11 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Write};
 
 use cow_utils::CowUtils;
 use oxc_ast::{AstKind, ast::NumericLiteral};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use oxc_syntax::number::NumberBase;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -56,7 +57,7 @@ declare_oxc_lint!(
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
-    /// var x = 12345";
+    /// var x = 12345;
     /// ```
     ///
     /// ```javascript
@@ -64,7 +65,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// ```javascript
-    /// var x = 123.0000000000000000000000";
+    /// var x = 123.0000000000000000000000;
     /// ```
     ///
     /// ```javascript
@@ -206,19 +207,31 @@ impl<'a> RawNum<'a> {
 
 impl NoLossOfPrecision {
     fn not_base_ten_loses_precision(node: &'_ NumericLiteral) -> bool {
-        let raw = node.raw.as_ref().unwrap().as_str().cow_replace('_', "");
-        let raw = raw.cow_to_ascii_uppercase();
-        #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        // AST always store number as f64, need a cast to format in bin/oct/hex
-        let value = node.value as u64;
-        let suffix = if raw.starts_with("0B") {
-            format!("{value:b}")
-        } else if raw.starts_with("0X") {
-            format!("{value:x}")
-        } else {
-            format!("{value:o}")
+        // too large for a f64, e.g. a hex literal with more than 256 digits
+        if node.value.is_infinite() {
+            return true;
+        }
+        let radix: u32 = match node.base {
+            NumberBase::Binary => 2,
+            NumberBase::Octal => 8,
+            _ => 16,
         };
-        !raw.ends_with(&suffix.cow_to_ascii_uppercase().as_ref())
+        let raw = node.raw.as_ref().unwrap().as_str().cow_replace('_', "");
+        let digits = raw.trim_start_matches('0');
+        let digits = digits.strip_prefix(['b', 'B', 'o', 'O', 'x', 'X']).unwrap_or(digits);
+
+        // Every binary, octal and hex digit maps to a fixed number of bits, so the literal
+        // fits if its significant bits, from the first to the last `1`, fit in the
+        // 53 bit mantissa of a f64. This also works for literals larger than `u64::MAX`.
+        let bits_per_digit = radix.trailing_zeros() as usize;
+        let bits = digits.chars().filter_map(|ch| ch.to_digit(radix)).fold(
+            String::new(),
+            |mut bits, digit| {
+                let _ = write!(bits, "{digit:0bits_per_digit$b}");
+                bits
+            },
+        );
+        bits.trim_matches('0').len() > 53
     }
 
     fn base_ten_loses_precision(node: &'_ NumericLiteral) -> bool {
@@ -384,8 +397,11 @@ pub fn to_precision(mut num: f64, precision: usize) -> String {
         suffix = "0".repeat(precision);
         exponent = 0;
     } else {
-        // Format with maximum precision to get all digits
-        suffix = format!("{num:.100}");
+        // Format with maximum precision to get all digits, numbers below 1 need enough
+        // decimals to reach their significant digits, e.g. `1e-200`
+        let decimals =
+            if num < 1.0 { (-num.log10()).ceil() as usize + precision + 1 } else { 0 }.max(100);
+        suffix = format!("{num:.decimals$}");
 
         // Calculate exponent
         exponent = flt_str_to_exp(&suffix);
@@ -510,6 +526,15 @@ fn test() {
         ("var a = 480.00", None),
         ("var a = -30.00", None),
         ("(1000000000000000128).toFixed(0)", None),
+        ("var x = 1e-200", None),
+        ("var x = 5e-324", None),
+        ("var x = 2.2250738585072014e-308", None),
+        ("var x = 1.7976931348623157e308", None),
+        ("var x = 0x10000000000000000", None),
+        ("var x = 0x1FFFFFFFFFFFFF0000000000", None),
+        ("var x = 0b1_0000000000000000000000000000000000000000000000000000_0", None),
+        ("var x = 9007199254740993n", None),
+        ("var x = 0x20000000000001n", None),
     ];
 
     let fail = vec![
@@ -559,6 +584,10 @@ fn test() {
         ("var x = 0x2_0000000000001", None),
         ("var x = 0X200000_0000000_1", None),
         ("var x = 1e18_446_744_073_709_551_615", None),
+        ("var x = 0.1e-1000", None),
+        ("var x = 1e-400", None),
+        ("var x = 0x1_0000_0000_0000_0001", None),
+        ("var x = 0x20000000000001000000000000", None),
     ];
 
     Tester::new(NoLossOfPrecision::NAME, NoLossOfPrecision::PLUGIN, pass, fail).test_and_snapshot();
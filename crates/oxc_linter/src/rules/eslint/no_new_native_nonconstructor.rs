@@ -53,16 +53,19 @@ impl Rule for NoNewNativeNonconstructor {
         let Expression::Identifier(ident) = &expr.callee else {
             return;
         };
-        if matches!(ident.name.as_str(), "Symbol" | "BigInt")
-            && ctx.scoping().root_unresolved_references().contains_key(ident.name.as_str())
-        {
-            let start = expr.span.start;
-            let end = start + 3;
-            ctx.diagnostic(no_new_native_nonconstructor_diagnostic(
-                ident.name.as_str(),
-                Span::new(start, end),
-            ));
+        if !matches!(ident.name.as_str(), "Symbol" | "BigInt") {
+            return;
+        }
+        // Only the global `Symbol` and `BigInt`, not a variable shadowing them in this scope.
+        if ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some() {
+            return;
         }
+        let start = expr.span.start;
+        let end = start + 3;
+        ctx.diagnostic(no_new_native_nonconstructor_diagnostic(
+            ident.name.as_str(),
+            Span::new(start, end),
+        ));
     }
 }
 
@@ -81,6 +84,11 @@ fn test() {
         "function BigInt() {} new BigInt();",
         "new foo(BigInt);",
         "new foo(bar, BigInt);",
+        "const Symbol = class {}; new Symbol();",
+        "class BigInt {} new BigInt(1);",
+        "import { Symbol } from 'foo'; new Symbol();",
+        "BigInt(1); function f(BigInt) { return new BigInt(1); }",
+        "Symbol(); { let Symbol = function () {}; new Symbol(); }",
     ];
 
     let fail = vec![
@@ -88,6 +96,8 @@ fn test() {
         "function bar() { return function Symbol() {}; } var baz = new Symbol('baz');",
         "var foo = new BigInt(9007199254740991);",
         "function bar() { return function BigInt() {}; } var baz = new BigInt(9007199254740991);",
+        "function f(Symbol) { return new Symbol(); } var foo = new Symbol();",
+        "{ let BigInt = function () {}; } new BigInt(1);",
     ];
 
     Tester::new(NoNewNativeNonconstructor::NAME, NoNewNativeNonconstructor::PLUGIN, pass, fail)
@@ -1,7 +1,10 @@
-use oxc_ast::{AstKind, ast::Expression};
+use oxc_ast::{
+    AstKind,
+    ast::{Directive, Expression, Statement},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -41,7 +44,7 @@ declare_oxc_lint!(
     NoObjectConstructor,
     eslint,
     pedantic,
-    pending
+    fix
 );
 
 impl Rule for NoObjectConstructor {
@@ -65,11 +68,70 @@ impl Rule for NoObjectConstructor {
             && arguments.is_empty()
             && type_parameters.is_none()
         {
-            ctx.diagnostic(no_object_constructor_diagnostic(span));
+            let diagnostic = no_object_constructor_diagnostic(span);
+            if ctx.has_comments_between(span) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                // `{}` at the start of a statement would be parsed as a block
+                let replacement = match statement_starting_with(node, span, ctx) {
+                    Some(statement) if needs_semicolon(statement, ctx) => ";({})",
+                    Some(_) => "({})",
+                    None => "{}",
+                };
+                fixer.replace(span, replacement)
+            });
         }
     }
 }
 
+/// Returns the expression statement that starts with the expression at `span`, if any.
+fn statement_starting_with<'a, 'b>(
+    node: &AstNode<'a>,
+    span: Span,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        if ancestor.kind().span().start != span.start {
+            return None;
+        }
+        if matches!(ancestor.kind(), AstKind::ExpressionStatement(_)) {
+            return Some(ancestor);
+        }
+    }
+    None
+}
+
+/// Whether `statement` would continue the statement before it if it started with `(`,
+/// e.g. `foo()\n({})` is parsed as `foo()({})`.
+fn needs_semicolon(statement: &AstNode, ctx: &LintContext) -> bool {
+    let span = statement.kind().span();
+    let (directives, statements): (&[Directive], &[Statement]) =
+        match ctx.nodes().parent_kind(statement.id()) {
+            AstKind::Program(program) => (&program.directives, &program.body),
+            AstKind::FunctionBody(body) => (&body.directives, &body.statements),
+            AstKind::BlockStatement(block) => (&[], &block.body),
+            AstKind::StaticBlock(block) => (&[], &block.body),
+            AstKind::SwitchCase(case) => (&[], &case.consequent),
+            // e.g. `if (a) Object();`, the statement follows a keyword or `)`
+            _ => return false,
+        };
+
+    if let Some(previous) =
+        statements.iter().rev().find(|previous| previous.span().end <= span.start)
+    {
+        return match previous {
+            Statement::BlockStatement(_)
+            | Statement::FunctionDeclaration(_)
+            | Statement::ClassDeclaration(_)
+            | Statement::EmptyStatement(_) => false,
+            _ => !ctx.source_range(previous.span()).ends_with(';'),
+        };
+    }
+    directives.last().is_some_and(|directive| !ctx.source_range(directive.span).ends_with(';'))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -81,6 +143,9 @@ fn test() {
         ("const createObject = Object => new Object()", None, None),
         ("var Object; new Object;", None, None),
         ("new Object()", None, Some(serde_json::json!({"globals": {"Object": "off"} }))),
+        ("function foo(Object) { return Object(); }", None, None),
+        ("import Object from 'foo'; new Object();", None, None),
+        ("new Object<Foo>()", None, None),
     ];
 
     let fail = vec![
@@ -260,6 +325,35 @@ fn test() {
         ),
     ];
 
+    let fix = vec![
+        ("new Object", "({})"),
+        ("Object()", "({})"),
+        ("const obj = Object();", "const obj = {};"),
+        ("const obj = new Object();", "const obj = {};"),
+        ("const obj = Object?.();", "const obj = {};"),
+        ("const fn = () => Object();", "const fn = () => ({});"),
+        ("Object() instanceof Object;", "({}) instanceof Object;"),
+        ("(new Object() instanceof Object);", "({} instanceof Object);"),
+        ("foo(Object());", "foo({});"),
+        ("export default Object();", "export default {};"),
+        ("foo()\nObject()", "foo()\n;({})"),
+        ("var foo = { bar: baz }\nObject()", "var foo = { bar: baz }\n;({})"),
+        ("<foo />\nObject()", "<foo />\n;({})"),
+        ("'use strict'\nObject()", "'use strict'\n;({})"),
+        ("foo();\nObject()", "foo();\n({})"),
+        ("{}\nObject()", "{}\n({})"),
+        ("function foo() {}\nObject()", "function foo() {}\n({})"),
+        ("class Foo {}\nObject()", "class Foo {}\n({})"),
+        ("foo: Object();", "foo: ({});"),
+        ("{ Object(); }", "{ ({}); }"),
+        ("if (a) Object();", "if (a) ({});"),
+        ("if (a); else Object();", "if (a); else ({});"),
+        ("do Object(); while (a);", "do ({}); while (a);"),
+        ("switch (a) { case 1: Object(); }", "switch (a) { case 1: ({}); }"),
+        ("Object(/* comment */);", "Object(/* comment */);"),
+    ];
+
     Tester::new(NoObjectConstructor::NAME, NoObjectConstructor::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -0,0 +1,236 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, ast_util::get_enclosing_function, context::LintContext, rule::Rule};
+
+fn no_promise_executor_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Return values from promise executor functions cannot be read.")
+        .with_help("Remove the returned value, or call `resolve` or `reject` instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPromiseExecutorReturn {
+    allow_void: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow returning values from Promise executor functions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `new Promise` constructor accepts a single argument, called an executor.
+    /// The return value of the executor is ignored. Returning a value from an
+    /// executor function is a possible error because the returned value cannot
+    /// be used and it doesn't affect the promise in any way.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// new Promise((resolve, reject) => {
+    ///     if (someCondition) {
+    ///         return defaultResult;
+    ///     }
+    ///     getSomething((err, result) => {
+    ///         if (err) {
+    ///             reject(err);
+    ///         } else {
+    ///             resolve(result);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// new Promise((resolve, reject) => getSomething((err, data) => {
+    ///     if (err) {
+    ///         reject(err);
+    ///     } else {
+    ///         resolve(data);
+    ///     }
+    /// }));
+    ///
+    /// new Promise(() => {
+    ///     return 1;
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// new Promise((resolve, reject) => {
+    ///     if (someCondition) {
+    ///         resolve(defaultResult);
+    ///         return;
+    ///     }
+    ///     getSomething((err, result) => {
+    ///         if (err) {
+    ///             reject(err);
+    ///         } else {
+    ///             resolve(result);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// new Promise((resolve, reject) => {
+    ///     getSomething((err, data) => {
+    ///         if (err) {
+    ///             reject(err);
+    ///         } else {
+    ///             resolve(data);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// Promise.resolve(1);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowVoid
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When set to `true`, returning a `void` expression (e.g. `return void resolve(1)`
+    /// or `resolve => void resolve(1)`) is allowed.
+    NoPromiseExecutorReturn,
+    eslint,
+    correctness
+);
+
+impl Rule for NoPromiseExecutorReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_void: value
+                .get(0)
+                .and_then(|config| config.get("allowVoid"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ReturnStatement(return_stmt) => {
+                let Some(argument) = &return_stmt.argument else {
+                    return;
+                };
+                if self.allow_void && argument.is_void() {
+                    return;
+                }
+                let Some(function_node) = get_enclosing_function(node, ctx) else {
+                    return;
+                };
+                if is_promise_executor(function_node, ctx) {
+                    ctx.diagnostic(no_promise_executor_return_diagnostic(return_stmt.span));
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) if arrow.expression => {
+                let Some(expr) = arrow.get_expression() else {
+                    return;
+                };
+                if self.allow_void && expr.is_void() {
+                    return;
+                }
+                if is_promise_executor(node, ctx) {
+                    ctx.diagnostic(no_promise_executor_return_diagnostic(expr.span()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks if `node` is a function passed as the first argument to `new Promise(...)`,
+/// where `Promise` refers to the global.
+fn is_promise_executor<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let Some(new_expr_node) = ctx.nodes().ancestors(node.id()).find(|parent| {
+        !matches!(parent.kind(), AstKind::ParenthesizedExpression(_) | AstKind::Argument(_))
+    }) else {
+        return false;
+    };
+    let AstKind::NewExpression(new_expr) = new_expr_node.kind() else {
+        return false;
+    };
+    let Expression::Identifier(callee) = &new_expr.callee else {
+        return false;
+    };
+    if callee.name != "Promise" || !ctx.is_reference_to_global_variable(callee) {
+        return false;
+    }
+    new_expr
+        .arguments
+        .first()
+        .and_then(Argument::as_expression)
+        .is_some_and(|executor| executor.get_inner_expression().span() == node.kind().span())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(resolve, reject) { return 1; }", None),
+        ("function Promise(f) { return f(); }", None),
+        ("const foo = (resolve, reject) => { return 1; }", None),
+        ("const foo = (resolve, reject) => 1", None),
+        ("new Promise((resolve, reject) => {})", None),
+        ("new Promise(function (resolve, reject) {})", None),
+        ("new Promise((resolve, reject) => { return; })", None),
+        ("new Promise(function (resolve, reject) { return; })", None),
+        ("new Promise((resolve, reject) => { if (foo) { return; } resolve(1); })", None),
+        ("new Promise((resolve, reject) => { resolve(1); })", None),
+        ("new Promise((resolve, reject) => { setTimeout(() => { return 1; }); })", None),
+        ("new Promise((resolve, reject) => { setTimeout(function () { return 1; }); })", None),
+        ("new Promise((resolve, reject) => { function foo() { return 1; } })", None),
+        ("new Promise((resolve, reject) => { const foo = () => 1; })", None),
+        ("new Promise((resolve, reject) => { class Foo { bar() { return 1; } } })", None),
+        ("new Promise(foo, () => 1)", None),
+        ("new Promise(foo, function () { return 1; })", None),
+        ("new Foo((resolve, reject) => 1)", None),
+        ("new Foo(function (resolve, reject) { return 1; })", None),
+        ("Promise((resolve, reject) => 1)", None),
+        ("new Promise.foo((resolve, reject) => 1)", None),
+        ("const Promise = Foo; new Promise((resolve, reject) => 1)", None),
+        ("function f(Promise) { new Promise((resolve) => 1) }", None),
+        (
+            "new Promise((resolve) => void resolve(1))",
+            Some(serde_json::json!([{ "allowVoid": true }])),
+        ),
+        (
+            "new Promise(function (resolve) { return void resolve(1); })",
+            Some(serde_json::json!([{ "allowVoid": true }])),
+        ),
+        (
+            "new Promise((resolve) => { if (foo) { return void resolve(1); } })",
+            Some(serde_json::json!([{ "allowVoid": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("new Promise(function (resolve, reject) { return 1; })", None),
+        ("new Promise((resolve, reject) => { return 1; })", None),
+        ("new Promise((resolve, reject) => 1)", None),
+        ("new Promise((resolve) => resolve(1))", None),
+        ("new Promise(((resolve) => resolve(1)))", None),
+        ("new Promise(async (resolve) => { return 1; })", None),
+        ("new Promise(function foo(resolve) { if (foo) { return bar; } })", None),
+        ("new Promise((resolve) => { try { return 1; } finally {} })", None),
+        ("new Promise((resolve) => { setTimeout(() => 1); return 2; })", None),
+        ("new Promise((resolve) => void resolve(1))", None),
+        ("new Promise(function (resolve) { return void resolve(1); })", None),
+        (
+            "new Promise((resolve) => { return resolve(1); })",
+            Some(serde_json::json!([{ "allowVoid": true }])),
+        ),
+        ("new Promise((resolve) => resolve(1))", Some(serde_json::json!([{ "allowVoid": true }]))),
+    ];
+
+    Tester::new(NoPromiseExecutorReturn::NAME, NoPromiseExecutorReturn::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,337 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ExportSpecifier, ModuleExportName},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn restricted_named_export_diagnostic(name: &str, message: &str, span: Span) -> OxcDiagnostic {
+    let text = if message.is_empty() {
+        format!("'{name}' is restricted from being used as an exported name.")
+    } else {
+        format!("'{name}' is restricted from being used as an exported name. {message}")
+    };
+    OxcDiagnostic::warn(text).with_label(span)
+}
+
+fn restricted_default_export_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Exporting 'default' is restricted.").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedExports(Box<NoRestrictedExportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedExportsConfig {
+    /// Restricted names, mapped to the message to report for them.
+    restricted_named_exports: FxHashMap<CompactStr, CompactStr>,
+    restrict_default_exports: RestrictDefaultExports,
+}
+
+#[derive(Debug, Default, Clone)]
+struct RestrictDefaultExports {
+    /// `export default foo`
+    direct: bool,
+    /// `export { foo as default }`
+    named: bool,
+    /// `export { default } from "mod"`
+    default_from: bool,
+    /// `export { foo as default } from "mod"`
+    named_from: bool,
+    /// `export * as default from "mod"`
+    namespace_from: bool,
+}
+
+impl std::ops::Deref for NoRestrictedExports {
+    type Target = NoRestrictedExportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified names in exports.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In a project, certain names may be disallowed from being used as exported names for
+    /// various reasons, e.g. because they would collide with names used by the consumers of the
+    /// module, or because default exports are discouraged.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the
+    /// `{ "restrictedNamedExports": ["foo", "bar"], "restrictDefaultExports": { "direct": true } }`
+    /// options:
+    /// ```js
+    /// export const foo = 1;
+    /// export function bar() {}
+    /// export { baz as foo };
+    /// export { foo } from "some_module";
+    /// export * as bar from "some_module";
+    /// export default function () {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same options:
+    /// ```js
+    /// export const qux = 1;
+    /// export { foo as baz };
+    /// export { bar as qux } from "some_module";
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### restrictedNamedExports
+    ///
+    /// `{ type: (string | { name: string, message?: string })[], default: [] }`
+    ///
+    /// Names which can't be exported. An entry can also be an object with a custom `message`,
+    /// which is added to the reported diagnostic.
+    ///
+    /// `"default"` can be restricted too, which applies to `export { foo as default }` and
+    /// re-exports, but not to `export default` declarations.
+    ///
+    /// #### restrictDefaultExports
+    ///
+    /// `{ type: object, default: {} }`
+    ///
+    /// Restricts the different forms of default exports:
+    /// - `direct`: `export default foo`
+    /// - `named`: `export { foo as default }`
+    /// - `defaultFrom`: `export { default } from "mod"`
+    /// - `namedFrom`: `export { foo as default } from "mod"`
+    /// - `namespaceFrom`: `export * as default from "mod"`
+    ///
+    /// Example:
+    /// ```json
+    /// "eslint/no-restricted-exports": [
+    ///   "error",
+    ///   {
+    ///     "restrictedNamedExports": ["foo", { "name": "bar", "message": "Use `baz` instead." }],
+    ///     "restrictDefaultExports": { "direct": true, "named": true }
+    ///   }
+    /// ]
+    /// ```
+    NoRestrictedExports,
+    eslint,
+    restriction
+);
+
+impl Rule for NoRestrictedExports {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+
+        let restricted_named_exports = config
+            .get("restrictedNamedExports")
+            .and_then(Value::as_array)
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|entry| match entry {
+                        // "restrictedNamedExports": ["foo"]
+                        Value::String(name) => Some((name.as_str().into(), CompactStr::new(""))),
+                        // "restrictedNamedExports": [{ "name": "foo", "message": "..." }]
+                        Value::Object(obj) => {
+                            let name = obj.get("name").and_then(Value::as_str)?;
+                            let message =
+                                obj.get("message").and_then(Value::as_str).unwrap_or_default();
+                            Some((name.into(), message.into()))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let default_exports = config.get("restrictDefaultExports");
+        let get_bool = |name: &str| {
+            default_exports
+                .and_then(|default_exports| default_exports.get(name))
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+        };
+
+        Self(Box::new(NoRestrictedExportsConfig {
+            restricted_named_exports,
+            restrict_default_exports: RestrictDefaultExports {
+                direct: get_bool("direct"),
+                named: get_bool("named"),
+                default_from: get_bool("defaultFrom"),
+                named_from: get_bool("namedFrom"),
+                namespace_from: get_bool("namespaceFrom"),
+            },
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    declaration.bound_names(&mut |ident| {
+                        self.check_named_export(&ident.name, ident.span, ctx);
+                    });
+                }
+                for specifier in &decl.specifiers {
+                    self.check_export_specifier(specifier, decl.source.is_some(), ctx);
+                }
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                let Some(exported) = &decl.exported else {
+                    return;
+                };
+                if self.check_named_export(&exported.name(), exported.span(), ctx) {
+                    return;
+                }
+                if is_default(exported) && self.restrict_default_exports.namespace_from {
+                    ctx.diagnostic(restricted_default_export_diagnostic(exported.span()));
+                }
+            }
+            AstKind::ExportDefaultDeclaration(decl) if self.restrict_default_exports.direct => {
+                ctx.diagnostic(restricted_default_export_diagnostic(decl.exported.span()));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoRestrictedExports {
+    /// Reports `name` if it is in `restrictedNamedExports`, and returns whether it was reported.
+    fn check_named_export(&self, name: &str, span: Span, ctx: &LintContext) -> bool {
+        let Some(message) = self.restricted_named_exports.get(name) else {
+            return false;
+        };
+        ctx.diagnostic(restricted_named_export_diagnostic(name, message, span));
+        true
+    }
+
+    fn check_export_specifier(
+        &self,
+        specifier: &ExportSpecifier,
+        has_source: bool,
+        ctx: &LintContext,
+    ) {
+        let exported = &specifier.exported;
+        if self.check_named_export(&exported.name(), exported.span(), ctx) || !is_default(exported)
+        {
+            return;
+        }
+
+        let restricted = if !has_source {
+            self.restrict_default_exports.named
+        } else if is_default(&specifier.local) {
+            self.restrict_default_exports.default_from
+        } else {
+            self.restrict_default_exports.named_from
+        };
+        if restricted {
+            ctx.diagnostic(restricted_default_export_diagnostic(exported.span()));
+        }
+    }
+}
+
+fn is_default(name: &ModuleExportName) -> bool {
+    name.name() == "default"
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("export var a;", None),
+        ("export function a() {}", None),
+        ("var a; export { a };", None),
+        ("export default foo;", None),
+        ("export default foo;", Some(json!([{ "restrictedNamedExports": ["default"] }]))),
+        ("export var b;", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export let { b, c: d } = obj;", Some(json!([{ "restrictedNamedExports": ["a", "c"] }]))),
+        ("export class A {}", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("var b; export { b };", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("var a; export { a as b };", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export { b } from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export { a as b } from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export * from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export * as b from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("import a from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("var a; export { a as 'b' };", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        (
+            "var foo; export { foo as default };",
+            Some(json!([{ "restrictDefaultExports": { "direct": true } }])),
+        ),
+        (
+            "export default foo;",
+            Some(json!([{ "restrictDefaultExports": { "named": true, "defaultFrom": true } }])),
+        ),
+        (
+            "export { default } from 'mod';",
+            Some(json!([{ "restrictDefaultExports": { "namedFrom": true } }])),
+        ),
+        (
+            "export { foo as default } from 'mod';",
+            Some(json!([{ "restrictDefaultExports": { "defaultFrom": true } }])),
+        ),
+        (
+            "export * as default from 'mod';",
+            Some(json!([{ "restrictDefaultExports": { "named": true } }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("export var a;", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export const a = 1, b = 2;", Some(json!([{ "restrictedNamedExports": ["a", "b"] }]))),
+        ("export let { a, b: [c] } = obj;", Some(json!([{ "restrictedNamedExports": ["c"] }]))),
+        ("export function a() {}", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export class a {}", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("var a; export { a };", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("var b; export { b as a };", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("var b; export { b as 'a' };", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export { a } from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        ("export * as a from 'mod';", Some(json!([{ "restrictedNamedExports": ["a"] }]))),
+        (
+            "var foo; export { foo as default };",
+            Some(json!([{ "restrictedNamedExports": ["default"] }])),
+        ),
+        (
+            "export var a;",
+            Some(
+                json!([{ "restrictedNamedExports": [{ "name": "a", "message": "Use `b` instead." }] }]),
+            ),
+        ),
+        ("export default foo;", Some(json!([{ "restrictDefaultExports": { "direct": true } }]))),
+        (
+            "export default function () {}",
+            Some(json!([{ "restrictDefaultExports": { "direct": true } }])),
+        ),
+        (
+            "var foo; export { foo as default };",
+            Some(json!([{ "restrictDefaultExports": { "named": true } }])),
+        ),
+        (
+            "export { default } from 'mod';",
+            Some(json!([{ "restrictDefaultExports": { "defaultFrom": true } }])),
+        ),
+        (
+            "export { foo as default } from 'mod';",
+            Some(json!([{ "restrictDefaultExports": { "namedFrom": true } }])),
+        ),
+        (
+            "export * as default from 'mod';",
+            Some(json!([{ "restrictDefaultExports": { "namespaceFrom": true } }])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedExports::NAME, NoRestrictedExports::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -28,7 +28,7 @@ declare_oxc_lint!(
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
     /// class URL {
-    ///   set origin() {
+    ///   set origin(value) {
     ///     return true;
     ///   }
     /// }
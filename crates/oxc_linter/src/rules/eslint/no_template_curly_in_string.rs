@@ -56,11 +56,11 @@ impl Rule for NoTemplateCurlyInString {
             return;
         };
 
-        let text = literal.value.as_str();
-        let Some(start) = text.find("${") else { return };
+        let raw = ctx.source_range(literal.span);
+        let Some(start) = find_unescaped_placeholder(raw) else { return };
 
-        if text[start + 2..].contains('}') {
-            let template = format!("`{text}`");
+        if raw[start + 2..].contains('}') {
+            let template = format!("`{}`", literal.value);
             ctx.diagnostic_with_dangerous_fix(
                 no_template_curly_in_string_diagnostic(literal.span),
                 |fixer| fixer.replace(literal.span, template),
@@ -69,6 +69,20 @@ impl Rule for NoTemplateCurlyInString {
     }
 }
 
+/// Returns the offset of the first `${` in `raw` that is not escaped, e.g. as `\${` or `$\{`.
+fn find_unescaped_placeholder(raw: &str) -> Option<usize> {
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'$' if bytes[i + 1] == b'{' => return Some(i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -89,6 +103,10 @@ fn test() {
         "'{foo}'",
         r#"'{foo: "bar"}'"#,
         "const number = 3",
+        r"'\${name}'",
+        r"'$\{name}'",
+        r#""\${greeting}, \${name}""#,
+        r"'\\\${name}'",
     ];
 
     let fail = vec![
@@ -100,6 +118,8 @@ fn test() {
         r#"'Hello, ${name + " foo"}'"#,
         r#"'Hello, ${name || "foo"}'"#,
         r#"'Hello, ${{foo: "bar"}.foo}'"#,
+        r"'\\${name}'",
+        r"'\${greeting}, ${name}'",
     ];
 
     let fix = vec![
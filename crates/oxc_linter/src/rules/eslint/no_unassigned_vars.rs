@@ -0,0 +1,169 @@
+use oxc_ast::{AstKind, ast::BindingPatternKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, ast_util::binding_identifier_span, context::LintContext, rule::Rule};
+
+fn no_unassigned_vars_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is always 'undefined' because it's never assigned."))
+        .with_help("Assign a value to this variable, or remove it and use `undefined` directly.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnassignedVars;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `let` or `var` variables that are read but never assigned.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A variable that is declared without an initializer and never assigned
+    /// anywhere will always be `undefined`. Reading it is almost always a
+    /// mistake, such as a forgotten assignment or a typo in the assignment
+    /// target.
+    ///
+    /// Variables that are never read are not reported, as they are already
+    /// covered by `no-unused-vars`. Destructuring patterns, exported bindings,
+    /// `for-in`/`for-of` loop variables and ambient (`declare`) declarations
+    /// are ignored.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// let status;
+    /// if (status === 'ready') {
+    ///   console.log('Ready!');
+    /// }
+    ///
+    /// let user;
+    /// greet(user);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// let message = 'hello';
+    /// console.log(message);
+    ///
+    /// let user;
+    /// user = getUser();
+    /// console.log(user.name);
+    ///
+    /// for (let item of items) {
+    ///   process(item);
+    /// }
+    /// ```
+    NoUnassignedVars,
+    eslint,
+    correctness
+);
+
+impl Rule for NoUnassignedVars {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclarator(declarator) = node.kind() else {
+            return;
+        };
+        if declarator.init.is_some() || declarator.kind.is_const() {
+            return;
+        }
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return;
+        };
+
+        let declaration_node = ctx.nodes().parent_node(node.id());
+        let AstKind::VariableDeclaration(declaration) = declaration_node.kind() else {
+            return;
+        };
+        if declaration.declare {
+            return;
+        }
+        if matches!(
+            ctx.nodes().parent_kind(declaration_node.id()),
+            AstKind::ForInStatement(_)
+                | AstKind::ForOfStatement(_)
+                | AstKind::ExportNamedDeclaration(_)
+        ) {
+            return;
+        }
+        if ctx
+            .nodes()
+            .ancestor_kinds(declaration_node.id())
+            .any(|kind| matches!(kind, AstKind::TSModuleDeclaration(module) if module.declare))
+        {
+            return;
+        }
+
+        let mut has_read = false;
+        for reference in ctx.scoping().get_resolved_references(ident.symbol_id()) {
+            if reference.is_write() {
+                return;
+            }
+            if reference.is_read() {
+                has_read = true;
+            }
+        }
+
+        // Variables that are never read are reported by `no-unused-vars`.
+        if has_read {
+            ctx.diagnostic(no_unassigned_vars_diagnostic(
+                &ident.name,
+                binding_identifier_span(&declarator.id, ident),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("let x;", None),
+        ("var x;", None),
+        ("const x = undefined; log(x);", None),
+        ("let y = undefined; log(y);", None),
+        ("var y = undefined; log(y);", None),
+        ("let a = x, b = y; log(a, b);", None),
+        ("var a = x, b = y; log(a, b);", None),
+        ("let x; x = 5; log(x);", None),
+        ("var x; x = 5; log(x);", None),
+        ("let x; x++; log(x);", None),
+        ("let x; x += 1; log(x);", None),
+        ("let x; [x] = [1]; log(x);", None),
+        ("let x; ({ x } = obj); log(x);", None),
+        ("let x; for (x of items) log(x);", None),
+        ("let x; for (x in obj) log(x);", None),
+        ("let x; function foo() { x = 1; } foo(); log(x);", None),
+        ("let x; log(x); x = 1;", None),
+        ("let { a } = obj; log(a);", None),
+        ("let [a] = arr; log(a);", None),
+        ("let { a } = {}; log(a);", None),
+        ("export let x; log(x);", None),
+        ("export var x; log(x);", None),
+        ("for (let x of items) { log(x); }", None),
+        ("for (let x in obj) { log(x); }", None),
+        ("for (var x of items) { log(x); }", None),
+        ("for (let i = 0; i < 10; i++) { log(i); }", None),
+        ("declare let x: number; log(x);", None),
+        ("declare module 'foo' { let x: number; export const y = x; }", None),
+        ("declare global { var x: number; } log(x);", None),
+    ];
+
+    let fail = vec![
+        ("let x; let a = x;", None),
+        ("var x; log(x);", None),
+        ("let x; if (x) { log('truthy'); }", None),
+        ("let x, y = 1; log(x, y);", None),
+        ("let x; function foo() { return x; }", None),
+        ("for (let i; i < 10; ) { log(i); }", None),
+        ("for (let x of items) { let y; log(x, y); }", None),
+        ("let x: number; log(x);", None),
+        ("let x; x.foo = 1;", None),
+    ];
+
+    Tester::new(NoUnassignedVars::NAME, NoUnassignedVars::PLUGIN, pass, fail).test_and_snapshot();
+}
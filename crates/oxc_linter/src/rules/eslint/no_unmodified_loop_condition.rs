@@ -0,0 +1,303 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BindingIdentifier, CallExpression, Class, Expression, Function,
+        IdentifierReference, NewExpression, TaggedTemplateExpression, YieldExpression,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ScopeFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unmodified_loop_condition_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is not modified in this loop."))
+        .with_help(format!("Modify `{name}` in the loop, or the loop may never terminate."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnmodifiedLoopCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unmodified loop conditions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables in a loop condition often are modified in the loop. If they aren't, the
+    /// condition never changes, which is likely a mistake resulting in an infinite loop.
+    ///
+    /// Only variables are checked, not the objects of member accesses like `items` of
+    /// `items.length`. Comparisons which contain function calls, `new` or `yield` expressions
+    /// are ignored, as their result may change anyway. Variables compared with each other, e.g.
+    /// `a < b`, are reported only if none of them is modified.
+    ///
+    /// A variable is also considered modified if a function declaration modifying it is
+    /// referenced in the loop.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// let node = something;
+    ///
+    /// while (node) {
+    ///   doSomething(node);
+    /// }
+    ///
+    /// for (let j = 0; j < items.length; ++i) {
+    ///   doSomething(items[j]);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// let node = something;
+    ///
+    /// while (node) {
+    ///   doSomething(node);
+    ///   node = node.parent;
+    /// }
+    ///
+    /// for (let j = 0; j < items.length; ++j) {
+    ///   doSomething(items[j]);
+    /// }
+    ///
+    /// // OK, the function call can change the result.
+    /// while (node !== getRoot()) {
+    ///   doSomething(node);
+    /// }
+    /// ```
+    NoUnmodifiedLoopCondition,
+    eslint,
+    suspicious
+);
+
+impl Rule for NoUnmodifiedLoopCondition {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (test, loop_span, init_span) = match node.kind() {
+            AstKind::WhileStatement(stmt) => (&stmt.test, stmt.span, None),
+            AstKind::DoWhileStatement(stmt) => (&stmt.test, stmt.span, None),
+            AstKind::ForStatement(stmt) => {
+                let Some(test) = &stmt.test else {
+                    return;
+                };
+                (test, stmt.span, stmt.init.as_ref().map(GetSpan::span))
+            }
+            _ => return,
+        };
+        // Modifications in the `init` of a `for` loop happen before the loop starts.
+        let is_in_loop = |span: Span| {
+            loop_span.contains_inclusive(span)
+                && init_span.is_none_or(|init| !init.contains_inclusive(span))
+        };
+
+        let mut conditions = vec![];
+        collect_conditions(test, None, &mut conditions);
+
+        let conditions = conditions
+            .into_iter()
+            .filter_map(|(ident, group)| {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+                Some((ident, group, is_modified(symbol_id, &is_in_loop, ctx)))
+            })
+            .collect::<Vec<_>>();
+
+        for (ident, group, modified) in &conditions {
+            if *modified {
+                continue;
+            }
+            // All variables of a group, e.g. `a` and `b` of `a < b`, must be unmodified.
+            if group.is_some_and(|group| {
+                conditions.iter().any(|(_, other, modified)| *other == Some(group) && *modified)
+            }) {
+                continue;
+            }
+            ctx.diagnostic(no_unmodified_loop_condition_diagnostic(&ident.name, ident.span));
+        }
+    }
+}
+
+/// Collects the variables of a loop condition, together with the span of the outermost binary
+/// or conditional expression they are part of.
+fn collect_conditions<'a, 'b>(
+    expr: &'b Expression<'a>,
+    group: Option<Span>,
+    conditions: &mut Vec<(&'b IdentifierReference<'a>, Option<Span>)>,
+) {
+    match expr {
+        Expression::Identifier(ident) => conditions.push((ident, group)),
+        Expression::BinaryExpression(binary) => {
+            if has_dynamic_expressions(expr) {
+                return;
+            }
+            let group = group.or(Some(binary.span));
+            collect_conditions(&binary.left, group, conditions);
+            collect_conditions(&binary.right, group, conditions);
+        }
+        Expression::ConditionalExpression(conditional) => {
+            if has_dynamic_expressions(expr) {
+                return;
+            }
+            let group = group.or(Some(conditional.span));
+            collect_conditions(&conditional.test, group, conditions);
+            collect_conditions(&conditional.consequent, group, conditions);
+            collect_conditions(&conditional.alternate, group, conditions);
+        }
+        Expression::LogicalExpression(logical) => {
+            collect_conditions(&logical.left, group, conditions);
+            collect_conditions(&logical.right, group, conditions);
+        }
+        Expression::UnaryExpression(unary) => {
+            collect_conditions(&unary.argument, group, conditions);
+        }
+        Expression::AssignmentExpression(assignment) => {
+            collect_conditions(&assignment.right, group, conditions);
+        }
+        Expression::SequenceExpression(sequence) => {
+            for expr in &sequence.expressions {
+                collect_conditions(expr, group, conditions);
+            }
+        }
+        Expression::ParenthesizedExpression(paren) => {
+            collect_conditions(&paren.expression, group, conditions);
+        }
+        Expression::TSAsExpression(expr) => {
+            collect_conditions(&expr.expression, group, conditions);
+        }
+        Expression::TSSatisfiesExpression(expr) => {
+            collect_conditions(&expr.expression, group, conditions);
+        }
+        Expression::TSNonNullExpression(expr) => {
+            collect_conditions(&expr.expression, group, conditions);
+        }
+        _ => {}
+    }
+}
+
+/// Whether `symbol_id` is written to in the loop, either directly or by calling a function
+/// declaration which writes to it.
+fn is_modified(symbol_id: SymbolId, is_in_loop: &impl Fn(Span) -> bool, ctx: &LintContext) -> bool {
+    let semantic = ctx.semantic();
+    ctx.scoping().get_resolved_references(symbol_id).filter(|reference| reference.is_write()).any(
+        |reference| {
+            if is_in_loop(semantic.reference_span(reference)) {
+                return true;
+            }
+            let Some(function_id) = get_enclosing_function_declaration(reference.node_id(), ctx)
+            else {
+                return false;
+            };
+            ctx.scoping()
+                .get_resolved_references(function_id)
+                .any(|reference| is_in_loop(semantic.reference_span(reference)))
+        },
+    )
+}
+
+/// The symbol of the function declaration `node_id` is in, skipping function expressions and
+/// arrow functions nested in it.
+fn get_enclosing_function_declaration(node_id: NodeId, ctx: &LintContext) -> Option<SymbolId> {
+    ctx.nodes().ancestor_kinds(node_id).find_map(|kind| match kind {
+        AstKind::Function(func) if func.is_declaration() => {
+            Some(func.id.as_ref().map(BindingIdentifier::symbol_id))
+        }
+        _ => None,
+    })?
+}
+
+/// Whether `expr` contains an expression whose value may change without modifying a variable,
+/// such as a function call.
+fn has_dynamic_expressions(expr: &Expression) -> bool {
+    let mut finder = DynamicExpressionFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+#[derive(Default)]
+struct DynamicExpressionFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicExpressionFinder {
+    fn visit_call_expression(&mut self, _it: &CallExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_new_expression(&mut self, _it: &NewExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _it: &TaggedTemplateExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = 0; while (foo) { ++foo; }",
+        "let foo = 0; while (foo) { foo += 1; }",
+        "var foo = 0; while (foo++) { }",
+        "var foo = 0; while (foo = next()) { }",
+        "var foo = 0; while (ok(foo)) { }",
+        "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? 1 : bar) { bar = baz; }",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; ++bar; }",
+        "var foo = 0; while (foo < bar()) { }",
+        "var foo = 0; while (foo < new Bar()) { }",
+        "var foo = 0; while (foo < tag`bar`) { }",
+        "function* foo() { var foo = 0; while (yield foo) { } }",
+        "function* foo() { var foo = 0; while (foo > (yield)) { } }",
+        "var foo = 0; while (foo.ok) { }",
+        "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+        "var foo = 0; while (foo) { [1, 2].forEach(() => { foo = false; }); }",
+        "var foo = 0; while (foo) { (function () { foo = false; })(); }",
+        "var foo = 0; do { ++foo; } while (foo);",
+        "for (var foo = 0; foo < 10; ++foo) { }",
+        "for (var foo = 0; foo < 10; ) { foo += 1; }",
+        "for (var foo = 0, bar = 10; foo < bar; ++foo) { }",
+        "for (let i = 0; ; ) { }",
+        "while (foo) { }",
+        "var a = 0; while (a) { for (;;) { a = 1; } }",
+        "var foo = 0; while (foo < (() => foo)()) { }",
+    ];
+
+    let fail = vec![
+        "var foo = 0; while (foo) { } foo = 1;",
+        "var foo = 0; while (!foo) { } foo = 1;",
+        "var foo = 0; while (foo != null) { } foo = 1;",
+        "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+        "var a, b, c; while (a < c && b < c) { ++a; } foo = 1;",
+        "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+        "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+        "var foo; do { } while (foo);",
+        "for (var foo = 0; foo < 10; ) { }",
+        "for (var foo = 0; foo < 10; ++bar) { }",
+        "const done = false; while (!done) { doSomething(); }",
+        "let items = []; for (let j = 0; j < items.length; ++i) { }",
+        "var foo = 0; while (foo) { } function f() { foo = 2; }",
+    ];
+
+    Tester::new(NoUnmodifiedLoopCondition::NAME, NoUnmodifiedLoopCondition::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,363 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, Statement, SwitchCase, SwitchStatement, TryStatement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unreachable_loop_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Invalid loop. Its body allows only one iteration.")
+        .with_help(
+            "Use an `if` statement instead, or make sure the loop can reach its next iteration.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachableLoop(Box<NoUnreachableLoopConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachableLoopConfig {
+    /// Types of loops which aren't checked, e.g. `WhileStatement`.
+    ignore: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoUnreachableLoop {
+    type Target = NoUnreachableLoopConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow loops with a body that allows only one iteration.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A loop whose body always exits, with `break`, `return` or `throw`, can never reach
+    /// its second iteration. Such a loop is most likely a mistake, e.g. a `break` which was
+    /// meant to be in a condition. If running the body once is intended, an `if` statement
+    /// states that more clearly.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// for (const item of items) {
+    ///   if (item.isValid()) {
+    ///     process(item);
+    ///   }
+    ///   break;
+    /// }
+    ///
+    /// while (queue.length) {
+    ///   consume(queue.pop());
+    ///   throw new Error("Unexpected item");
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// for (const item of items) {
+    ///   if (item.isValid()) {
+    ///     process(item);
+    ///     break;
+    ///   }
+    /// }
+    ///
+    /// while (queue.length) {
+    ///   if (!consume(queue.pop())) {
+    ///     continue;
+    ///   }
+    ///   throw new Error("Unexpected item");
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignore
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// The types of loops to ignore: `"WhileStatement"`, `"DoWhileStatement"`,
+    /// `"ForStatement"`, `"ForInStatement"` and `"ForOfStatement"`.
+    ///
+    /// Example:
+    /// ```json
+    /// "eslint/no-unreachable-loop": ["error", { "ignore": ["ForInStatement", "ForOfStatement"] }]
+    /// ```
+    NoUnreachableLoop,
+    eslint,
+    suspicious
+);
+
+impl Rule for NoUnreachableLoop {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore = value
+            .get(0)
+            .and_then(|config| config.get("ignore"))
+            .and_then(serde_json::Value::as_array)
+            .map(|types| {
+                types.iter().filter_map(serde_json::Value::as_str).map(CompactStr::from).collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(NoUnreachableLoopConfig { ignore }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (loop_type, body) = match node.kind() {
+            AstKind::WhileStatement(stmt) => ("WhileStatement", &stmt.body),
+            AstKind::DoWhileStatement(stmt) => ("DoWhileStatement", &stmt.body),
+            AstKind::ForStatement(stmt) => ("ForStatement", &stmt.body),
+            AstKind::ForInStatement(stmt) => ("ForInStatement", &stmt.body),
+            AstKind::ForOfStatement(stmt) => ("ForOfStatement", &stmt.body),
+            _ => return,
+        };
+        if self.ignore.iter().any(|ignored| ignored == loop_type) {
+            return;
+        }
+
+        let labels = ctx
+            .nodes()
+            .ancestor_kinds(node.id())
+            .map_while(|kind| match kind {
+                AstKind::LabeledStatement(labeled) => Some(labeled.label.name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let completion = Completion::of_statement(body);
+        if !completion.reaches_next_iteration(&labels) {
+            ctx.diagnostic(no_unreachable_loop_diagnostic(node.span()));
+        }
+    }
+}
+
+/// How the execution of a statement can end, apart from `return` and `throw`.
+#[derive(Debug, Default)]
+struct Completion<'a> {
+    /// Whether the execution can continue after the statement.
+    normal: bool,
+    /// The labels of the `break` statements jumping out of the statement, `None` for `break`
+    /// statements without a label.
+    breaks: Vec<Option<&'a str>>,
+    /// The labels of the `continue` statements jumping out of the statement, `None` for
+    /// `continue` statements without a label.
+    continues: Vec<Option<&'a str>>,
+}
+
+impl<'a> Completion<'a> {
+    fn normal() -> Self {
+        Self { normal: true, ..Self::default() }
+    }
+
+    fn of_statement(stmt: &Statement<'a>) -> Self {
+        match stmt {
+            Statement::ReturnStatement(_) | Statement::ThrowStatement(_) => Self::default(),
+            Statement::BreakStatement(stmt) => Self {
+                breaks: vec![stmt.label.as_ref().map(|label| label.name.as_str())],
+                ..Self::default()
+            },
+            Statement::ContinueStatement(stmt) => Self {
+                continues: vec![stmt.label.as_ref().map(|label| label.name.as_str())],
+                ..Self::default()
+            },
+            Statement::BlockStatement(block) => Self::of_statements(&block.body),
+            Statement::IfStatement(stmt) => {
+                let mut completion = Self::of_statement(&stmt.consequent);
+                let alternate =
+                    stmt.alternate.as_ref().map_or_else(Self::normal, Self::of_statement);
+                completion.merge(alternate);
+                completion
+            }
+            Statement::LabeledStatement(stmt) => {
+                let label = stmt.label.name.as_str();
+                let mut completion = Self::of_statement(&stmt.body);
+                let breaks_out = completion.breaks.len();
+                completion.breaks.retain(|target| *target != Some(label));
+                completion.normal |= completion.breaks.len() != breaks_out;
+                // A `continue` with the label of a loop stays within the loop.
+                completion.continues.retain(|target| *target != Some(label));
+                completion
+            }
+            Statement::WithStatement(stmt) => Self::of_statement(&stmt.body),
+            Statement::SwitchStatement(stmt) => Self::of_switch(stmt),
+            Statement::TryStatement(stmt) => Self::of_try(stmt),
+            Statement::WhileStatement(stmt) => {
+                Self::of_loop(&stmt.body, is_true_literal(&stmt.test))
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let body = Self::of_statement(&stmt.body);
+                let infinite = is_true_literal(&stmt.test)
+                    || (!body.normal && !body.continues.contains(&None));
+                Self::of_loop_body(body, infinite)
+            }
+            Statement::ForStatement(stmt) => {
+                Self::of_loop(&stmt.body, stmt.test.as_ref().is_none_or(is_true_literal))
+            }
+            Statement::ForInStatement(stmt) => Self::of_loop(&stmt.body, false),
+            Statement::ForOfStatement(stmt) => Self::of_loop(&stmt.body, false),
+            _ => Self::normal(),
+        }
+    }
+
+    fn of_statements(stmts: &[Statement<'a>]) -> Self {
+        let mut completion = Self::normal();
+        for stmt in stmts {
+            // The remaining statements are unreachable.
+            if !completion.normal {
+                break;
+            }
+            let next = Self::of_statement(stmt);
+            completion.normal = next.normal;
+            completion.breaks.extend(next.breaks);
+            completion.continues.extend(next.continues);
+        }
+        completion
+    }
+
+    /// The completion of a loop with `body`. An `infinite` loop only ends with a `break`.
+    fn of_loop(body: &Statement<'a>, infinite: bool) -> Self {
+        Self::of_loop_body(Self::of_statement(body), infinite)
+    }
+
+    fn of_loop_body(mut body: Self, infinite: bool) -> Self {
+        // Unlabeled `break` and `continue` statements target the loop itself.
+        let breaks_out = body.breaks.contains(&None);
+        body.breaks.retain(Option::is_some);
+        body.continues.retain(Option::is_some);
+        body.normal = !infinite || breaks_out;
+        body
+    }
+
+    fn of_switch(stmt: &SwitchStatement<'a>) -> Self {
+        let mut completion = Self::default();
+        // Without a `default` case, the execution continues if no case matches.
+        let mut normal = !stmt.cases.iter().any(SwitchCase::is_default_case);
+        for (index, case) in stmt.cases.iter().enumerate() {
+            let case_completion = Self::of_statements(&case.consequent);
+            // The last case is the only one which can't fall through to another case.
+            if index == stmt.cases.len() - 1 {
+                normal |= case_completion.normal;
+            }
+            completion.breaks.extend(case_completion.breaks);
+            completion.continues.extend(case_completion.continues);
+        }
+        // Unlabeled `break` statements target the `switch` statement itself.
+        normal |= completion.breaks.contains(&None);
+        completion.breaks.retain(Option::is_some);
+        completion.normal = normal;
+        completion
+    }
+
+    fn of_try(stmt: &TryStatement<'a>) -> Self {
+        let mut completion = Self::of_statements(&stmt.block.body);
+        if let Some(handler) = &stmt.handler {
+            // Any statement of the `try` block can throw into the `catch` block.
+            completion.merge(Self::of_statements(&handler.body.body));
+        }
+        if let Some(finalizer) = &stmt.finalizer {
+            let finalizer = Self::of_statements(&finalizer.body);
+            // Jumping out of the `finally` block overrides how the `try` statement ends.
+            if !finalizer.normal {
+                return finalizer;
+            }
+            completion.breaks.extend(finalizer.breaks);
+            completion.continues.extend(finalizer.continues);
+        }
+        completion
+    }
+
+    /// Merges the completion of an alternative branch.
+    fn merge(&mut self, other: Self) {
+        self.normal |= other.normal;
+        self.breaks.extend(other.breaks);
+        self.continues.extend(other.continues);
+    }
+
+    /// Whether the body of a loop with `labels` can continue with the next iteration.
+    fn reaches_next_iteration(&self, labels: &[&str]) -> bool {
+        self.normal
+            || self
+                .continues
+                .iter()
+                .any(|target| target.is_none_or(|label| labels.contains(&label)))
+    }
+}
+
+/// Whether `expr` is the literal `true`, as the test of an infinite loop.
+fn is_true_literal(expr: &Expression) -> bool {
+    matches!(expr.without_parentheses(), Expression::BooleanLiteral(lit) if lit.value)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("while (foo) { bar(); }", None),
+        ("while (foo) { if (bar) { break; } }", None),
+        ("while (foo) { if (bar) { break; } baz(); }", None),
+        ("for (const a of b) { if (a) break; }", None),
+        ("for (const a in b) { if (a) { continue; } break; }", None),
+        ("for (;;) { if (foo) continue; break; }", None),
+        ("do { if (x) continue; throw x; } while (foo)", None),
+        ("function f() { while (foo) { switch (bar) { case 1: break; default: return; } } }", None),
+        ("function f() { while (foo) { switch (bar) { case 1: return; } } }", None),
+        (
+            "function f() { while (foo) { switch (bar) { case 1: return; default: continue; } } }",
+            None,
+        ),
+        ("function f() { while (foo) { try { return; } catch (e) {} } }", None),
+        ("while (foo) { try { bar(); } finally { baz(); } }", None),
+        ("outer: for (a of b) { for (c of d) { if (c) continue outer; } }", None),
+        ("for (x of y) { if (x) { continue; } else { break; } break; }", None),
+        ("inner: while (a) { if (b) continue inner; break; }", None),
+        ("while (foo) { function f() { return; } }", None),
+        ("for (const x of y) { (() => { return; })(); }", None),
+        ("function f() { while (a) { label: { if (b) break label; return; } } }", None),
+        ("while (a) { while (b) {} }", None),
+        ("while (a) { for (;;) { if (b) break; } }", None),
+        ("while (foo) { break; }", Some(json!([{ "ignore": ["WhileStatement"] }]))),
+        (
+            "for (a in b) { break; } for (a of b) { break; }",
+            Some(json!([{ "ignore": ["ForInStatement", "ForOfStatement"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("while (foo) { break; }", None),
+        ("do { throw e; } while (foo)", None),
+        ("for (;;) { break; }", None),
+        ("function f() { for (x in y) { return x; } }", None),
+        ("for (x of y) { if (x) { break; } else { throw x; } }", None),
+        ("function f() { for (x of y) { if (x) { break; } return; } }", None),
+        ("while (foo) { try { break; } finally {} }", None),
+        (
+            "function f() { while (foo) { try { bar(); } catch { break; } finally { return; } } }",
+            None,
+        ),
+        (
+            "function f() { while (foo) { switch (bar) { case 1: return; default: throw e; } } }",
+            None,
+        ),
+        ("function f() { while (foo) { switch (bar) { case 1: break; } return; } }", None),
+        ("outer: while (a) { while (b) { continue outer; } }", None),
+        ("label: while (a) { while (b) { break label; } }", None),
+        ("outer: for (a of b) { for (c of d) { continue outer; } break; }", None),
+        ("while (a) { while (b) {} break; }", None),
+        ("while (a) { label: { break label; } break; }", None),
+        ("while (a) { for (;;) {} }", None),
+        ("for (;;) { break; }", Some(json!([{ "ignore": ["WhileStatement"] }]))),
+    ];
+
+    Tester::new(NoUnreachableLoop::NAME, NoUnreachableLoop::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -534,6 +534,10 @@ fn test() {
             None,
         ),
         ("const _func = (value: number) => { value + 1; }", None),
+        ("x === y;", None),
+        ("1 + 1;", None),
+        ("a && b;", None),
+        ("a && b;", Some(serde_json::json!([{ "allowShortCircuit": true }]))),
     ];
 
     Tester::new(NoUnusedExpressions::NAME, NoUnusedExpressions::PLUGIN, pass, fail)
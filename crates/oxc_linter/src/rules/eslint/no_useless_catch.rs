@@ -69,7 +69,7 @@ impl Rule for NoUselessCatch {
         let Some(Statement::ThrowStatement(throw_stmt)) = catch_clause.body.body.first() else {
             return;
         };
-        let Expression::Identifier(throw_ident) = &throw_stmt.argument else {
+        let Expression::Identifier(throw_ident) = throw_stmt.argument.without_parentheses() else {
             return;
         };
         if binding_ident.name == throw_ident.name {
@@ -217,6 +217,13 @@ fn test() {
           }
         }
       ",
+        "
+        try {
+          foo();
+        } catch (err) {
+          throw (err);
+        }
+      ",
     ];
 
     Tester::new(NoUselessCatch::NAME, NoUselessCatch::PLUGIN, pass, fail).test_and_snapshot();
@@ -0,0 +1,274 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, PropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_useless_computed_key_diagnostic(key: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessarily computed property [{key}] found."))
+        .with_help(format!("Replace the computed key with `{key}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUselessComputedKey {
+    enforce_for_class_members: bool,
+}
+
+impl Default for NoUselessComputedKey {
+    fn default() -> Self {
+        Self { enforce_for_class_members: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary computed property keys in objects and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It's unnecessary to use computed properties with literals such as:
+    /// ```js
+    /// const foo = { ["a"]: "b" };
+    /// ```
+    /// The code can be rewritten as:
+    /// ```js
+    /// const foo = { "a": "b" };
+    /// ```
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const a = { ['0']: 0 };
+    /// const b = { ['0+1,234']: 0 };
+    /// const c = { [0]: 0 };
+    /// const d = { ['x']: 0 };
+    /// const e = { ['x']() {} };
+    ///
+    /// class Foo {
+    ///     ["foo"] = "bar";
+    ///     [0]() {}
+    ///     static ["foo"] = "bar";
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const a = { 'a': 0 };
+    /// const b = { 0: 0 };
+    /// const c = { x() {} };
+    /// const d = { a: 0 };
+    /// const e = { [x]: 0 };
+    ///
+    /// // `{ ['__proto__']: x }` defines an own property, while `{ __proto__: x }` sets the prototype.
+    /// const f = { ['__proto__']: 0 };
+    ///
+    /// class Foo {
+    ///     "foo" = "bar";
+    ///     0() {}
+    ///     // a method named "constructor" would be the class constructor
+    ///     ["constructor"]() {}
+    ///     // a static method named "prototype" is a syntax error
+    ///     static ["prototype"]() {}
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### enforceForClassMembers
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// When set to `false`, computed keys in class members are not checked.
+    NoUselessComputedKey,
+    eslint,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoUselessComputedKey {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            enforce_for_class_members: value
+                .get(0)
+                .and_then(|config| config.get("enforceForClassMembers"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // `allowed_keys` must stay computed, as they would change meaning or be syntax errors otherwise.
+        let (key, allowed_keys): (_, &[&str]) = match node.kind() {
+            // Only a `__proto__: value` property sets the prototype, methods and accessors don't.
+            AstKind::ObjectProperty(prop) if prop.computed => (
+                &prop.key,
+                if prop.method || prop.kind != PropertyKind::Init { &[] } else { &["__proto__"] },
+            ),
+            AstKind::BindingProperty(prop) if prop.computed => (&prop.key, &[]),
+            AstKind::AssignmentTargetPropertyProperty(prop) if prop.computed => (&prop.name, &[]),
+            AstKind::MethodDefinition(method)
+                if method.computed && self.enforce_for_class_members =>
+            {
+                (&method.key, if method.r#static { &["prototype"] } else { &["constructor"] })
+            }
+            AstKind::PropertyDefinition(prop)
+                if prop.computed && self.enforce_for_class_members =>
+            {
+                (
+                    &prop.key,
+                    if prop.r#static { &["constructor", "prototype"] } else { &["constructor"] },
+                )
+            }
+            _ => return,
+        };
+
+        let Some(key) = key.as_expression().map(Expression::get_inner_expression) else {
+            return;
+        };
+        match key {
+            Expression::StringLiteral(lit) if !allowed_keys.contains(&lit.value.as_str()) => {}
+            Expression::NumericLiteral(_) => {}
+            _ => return,
+        }
+
+        let key_span = key.span();
+        let key_text = ctx.source_range(key_span);
+        let diagnostic = no_useless_computed_key_diagnostic(key_text, key_span);
+
+        let Some(brackets_span) = find_brackets_span(key_span, ctx) else {
+            // comments or parentheses inside the brackets would be lost
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            // `get[1]() {}` -> `get 1() {}`
+            let needs_space = ctx.source_text()[..brackets_span.start as usize]
+                .ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+                && key_text.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '.');
+            if needs_space {
+                fixer.replace(brackets_span, format!(" {key_text}"))
+            } else {
+                fixer.replace(brackets_span, key_text)
+            }
+        });
+    }
+}
+
+/// Returns the span of `[key]`, or `None` if there is anything other than whitespace
+/// between the brackets and the key.
+fn find_brackets_span(key_span: Span, ctx: &LintContext) -> Option<Span> {
+    let source_text = ctx.source_text();
+    let before = source_text[..key_span.start as usize].trim_end();
+    let after = source_text[key_span.end as usize..].trim_start();
+    if !before.ends_with('[') || !after.starts_with(']') {
+        return None;
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    let start = (before.len() - 1) as u32;
+    #[expect(clippy::cast_possible_truncation)]
+    let end = (source_text.len() - after.len() + 1) as u32;
+    Some(Span::new(start, end))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("({ 'a': 0, b(){} })", None),
+        ("({ [x]: 0 });", None),
+        ("({ a: 0, [b](){} })", None),
+        ("({ ['__proto__']: [] })", None),
+        ("({ [`a`]: 0 })", None),
+        ("({ ['a' + 'b']: 0 })", None),
+        ("class Foo { a() {} }", None),
+        ("class Foo { [x]() {} }", None),
+        ("class Foo { ['constructor']() {} }", None),
+        ("class Foo { static ['prototype']() {} }", None),
+        ("(class { ['constructor']() {} })", None),
+        ("(class { static ['prototype']() {} })", None),
+        ("class Foo { 'x'() {} }", None),
+        ("class Foo { [x] = 0 }", None),
+        ("class Foo { static [x] = 0 }", None),
+        ("class Foo { 'prototype' = 0 }", None),
+        ("class Foo { static ['constructor'] = 0 }", None),
+        (
+            "class Foo { ['a']() {} }",
+            Some(serde_json::json!([{ "enforceForClassMembers": false }])),
+        ),
+        ("class Foo { ['a'] = 0 }", Some(serde_json::json!([{ "enforceForClassMembers": false }]))),
+        (
+            "class Foo { static ['a']() {} }",
+            Some(serde_json::json!([{ "enforceForClassMembers": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("({ ['0']: 0 })", None),
+        ("var { ['0']: a } = obj", None),
+        ("({ ['0+1,234']: 0 })", None),
+        ("({ [0]: 0 })", None),
+        ("var { [0]: a } = obj", None),
+        ("({ ['0']: a } = obj)", None),
+        ("var { ['__proto__']: a } = obj", None),
+        ("({ ['x']: 0 })", None),
+        ("({ ['x']() {} })", None),
+        ("({ [/* this comment prevents a fix */ 'x']: 0 })", None),
+        ("({ ['x' /* this comment also prevents a fix */]: 0 })", None),
+        ("({ [('x')]: 0 })", None),
+        ("({ get[.2]() {} })", None),
+        ("({ set[.2](value) {} })", None),
+        ("({ async[.2]() {} })", None),
+        ("({ [2]() {} })", None),
+        ("({ get [2]() {} })", None),
+        ("({ ['__proto__']() {} })", None),
+        ("class Foo { ['0']() {} }", None),
+        ("class Foo { ['0+1,234']() {} }", None),
+        ("class Foo { ['x']() {} }", None),
+        ("class Foo { static ['constructor']() {} }", None),
+        ("class Foo { ['prototype']() {} }", None),
+        ("class Foo { get[.2]() {} }", None),
+        ("class Foo { static[.2]() {} }", None),
+        ("class Foo { ['x'] = 0 }", None),
+        ("class Foo { static ['x'] = 0 }", None),
+        ("class Foo { ['prototype'] = 0 }", None),
+        ("class Foo { [2] = 0 }", None),
+        ("(class { ['x']() {} })", Some(serde_json::json!([{ "enforceForClassMembers": true }]))),
+    ];
+
+    let fix = vec![
+        ("({ ['0']: 0 })", "({ '0': 0 })"),
+        ("var { ['0']: a } = obj", "var { '0': a } = obj"),
+        ("({ [0]: 0 })", "({ 0: 0 })"),
+        ("({ ['0']: a } = obj)", "({ '0': a } = obj)"),
+        ("({ [('x')]: 0 })", "({ [('x')]: 0 })"),
+        ("({ ['x']() {} })", "({ 'x'() {} })"),
+        ("({ [ 'x' ]: 0 })", "({ 'x': 0 })"),
+        (
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+        ),
+        ("({ get[.2]() {} })", "({ get .2() {} })"),
+        ("({ async[.2]() {} })", "({ async .2() {} })"),
+        ("({ get ['x']() {} })", "({ get 'x'() {} })"),
+        ("({ *['x']() {} })", "({ *'x'() {} })"),
+        ("({ ['__proto__']() {} })", "({ '__proto__'() {} })"),
+        ("class Foo { ['x']() {} }", "class Foo { 'x'() {} }"),
+        ("class Foo { static[.2]() {} }", "class Foo { static .2() {} }"),
+        ("class Foo { static['x'] = 0 }", "class Foo { static'x' = 0 }"),
+        ("class Foo { ['x'] = 0 }", "class Foo { 'x' = 0 }"),
+    ];
+
+    Tester::new(NoUselessComputedKey::NAME, NoUselessComputedKey::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,606 @@
+use std::borrow::Cow;
+
+use lazy_regex::{Captures, Lazy, Regex, RegexBuilder, lazy_regex};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{AllowWarnDeny, context::LintContext, rule::Rule};
+
+fn no_warning_comments_diagnostic(
+    term: &str,
+    comment: &str,
+    severity: Option<AllowWarnDeny>,
+    span: Span,
+) -> OxcDiagnostic {
+    let message = format!("Unexpected '{term}' comment: '{comment}'.");
+    let diagnostic = if severity == Some(AllowWarnDeny::Deny) {
+        OxcDiagnostic::error(message)
+    } else {
+        OxcDiagnostic::warn(message)
+    };
+    diagnostic
+        .with_help("Resolve the issue and remove the comment, or track it in an issue tracker.")
+        .with_label(span)
+}
+
+/// Maximum number of characters of the comment shown in the diagnostic message.
+const CHAR_LIMIT: usize = 40;
+
+static URL_REGEX: Lazy<Regex> = lazy_regex!(r"https?://\S+");
+
+#[derive(Debug, Default, Clone)]
+pub struct NoWarningComments(Box<NoWarningCommentsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoWarningCommentsConfig {
+    terms: Vec<WarningTerm>,
+    /// Whether to ignore warning terms inside URLs.
+    ignore_urls: bool,
+}
+
+#[derive(Debug, Clone)]
+struct WarningTerm {
+    term: CompactStr,
+    /// Pattern used to find the term in a comment.
+    regex: Regex,
+    /// Severity configured for this term. `None` reports with the rule's severity.
+    severity: Option<AllowWarnDeny>,
+}
+
+impl std::ops::Deref for NoWarningComments {
+    type Target = NoWarningCommentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoWarningCommentsConfig {
+    fn default() -> Self {
+        Self::new(default_terms(), Location::Start, "", false, false)
+    }
+}
+
+impl NoWarningCommentsConfig {
+    fn new(
+        terms: Vec<(CompactStr, Option<AllowWarnDeny>)>,
+        location: Location,
+        decoration: &str,
+        case_sensitive: bool,
+        ignore_urls: bool,
+    ) -> Self {
+        let terms = terms
+            .into_iter()
+            .filter(|(_, severity)| !severity.is_some_and(AllowWarnDeny::is_allow))
+            .map(|(term, severity)| {
+                let regex = convert_to_regexp(&term, location, decoration, case_sensitive);
+                WarningTerm { term, regex, severity }
+            })
+            .collect();
+        Self { terms, ignore_urls }
+    }
+}
+
+fn default_terms() -> Vec<(CompactStr, Option<AllowWarnDeny>)> {
+    ["todo", "fixme", "xxx"].into_iter().map(|term| (CompactStr::from(term), None)).collect()
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    /// Only match terms at the start of a comment.
+    #[default]
+    Start,
+    /// Match terms anywhere in a comment.
+    Anywhere,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified warning terms in comments.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Developers often add comments to code which is not complete or needs
+    /// review. Most likely you want to fix or review the code, and then remove
+    /// the comment, before you consider the code to be production ready.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function callback(err, results) {
+    ///   if (err) {
+    ///     console.error(err);
+    ///     return;
+    ///   }
+    ///   // TODO: handle the results
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function callback(err, results) {
+    ///   if (err) {
+    ///     console.error(err);
+    ///     return;
+    ///   }
+    ///   // NOT READY FOR PRIME TIME
+    ///   // but too bad, it is not a predefined warning term
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### terms
+    ///
+    /// `{ type: (string | { term: string, severity: string })[], default: ["todo", "fixme", "xxx"] }`
+    ///
+    /// The warning terms to look for. Terms are matched as whole words, and
+    /// case-insensitively unless `caseSensitive` is set.
+    ///
+    /// A term can also be an object with its own `severity` (`"error"`, `"warn"`
+    /// or `"off"`), which takes precedence over the severity the rule is
+    /// configured with. For example, this reports `FIXME` comments as errors,
+    /// `TODO` comments with the rule's severity, and ignores `XXX` comments:
+    ///
+    /// ```json
+    /// {
+    ///   "no-warning-comments": [
+    ///     "warn",
+    ///     {
+    ///       "terms": [
+    ///         { "term": "fixme", "severity": "error" },
+    ///         "todo",
+    ///         { "term": "xxx", "severity": "off" }
+    ///       ]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// #### location
+    ///
+    /// `{ type: "start" | "anywhere", default: "start" }`
+    ///
+    /// Whether to only check the start of each comment, or anywhere in it.
+    ///
+    /// #### decoration
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Characters to ignore at the start of a comment when `location` is
+    /// `"start"`, e.g. `["*", "/"]`.
+    ///
+    /// #### caseSensitive
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Whether terms must match with the same case. For example, with
+    /// `{ "terms": ["TODO"], "caseSensitive": true }`, `// TODO` is reported but
+    /// `// todo` is not.
+    ///
+    /// #### ignoreUrls
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Whether to ignore terms inside URLs starting with `http://` or `https://`.
+    /// For example, with `{ "location": "anywhere", "ignoreUrls": true }`,
+    /// `// see https://example.com/todo` is not reported.
+    NoWarningComments,
+    eslint,
+    pedantic
+);
+
+impl Rule for NoWarningComments {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+
+        let terms = config
+            .get("terms")
+            .and_then(serde_json::Value::as_array)
+            .map_or_else(default_terms, |terms| terms.iter().filter_map(parse_term).collect());
+        let location = match config.get("location").and_then(serde_json::Value::as_str) {
+            Some("anywhere") => Location::Anywhere,
+            _ => Location::Start,
+        };
+        let decoration = config
+            .get("decoration")
+            .and_then(serde_json::Value::as_array)
+            .map(|decoration| {
+                decoration.iter().filter_map(serde_json::Value::as_str).collect::<String>()
+            })
+            .unwrap_or_default();
+        let case_sensitive =
+            config.get("caseSensitive").and_then(serde_json::Value::as_bool).unwrap_or(false);
+        let ignore_urls =
+            config.get("ignoreUrls").and_then(serde_json::Value::as_bool).unwrap_or(false);
+
+        Self(Box::new(NoWarningCommentsConfig::new(
+            terms,
+            location,
+            &decoration,
+            case_sensitive,
+            ignore_urls,
+        )))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        for comment in ctx.comments() {
+            self.check_comment(ctx.comment_text(comment), comment.content_span().start, ctx);
+        }
+    }
+}
+
+/// Parses a term given either as a plain string, or as `{ "term": string, "severity": string }`.
+fn parse_term(value: &serde_json::Value) -> Option<(CompactStr, Option<AllowWarnDeny>)> {
+    if let Some(term) = value.as_str() {
+        return Some((CompactStr::from(term), None));
+    }
+    let term = value.get("term").and_then(serde_json::Value::as_str)?;
+    let severity =
+        value.get("severity").and_then(|severity| AllowWarnDeny::try_from(severity).ok());
+    Some((CompactStr::from(term), severity))
+}
+
+impl NoWarningComments {
+    /// Reports the warning terms in `comment`, whose text starts at `offset` in the source.
+    fn check_comment(&self, comment: &str, offset: u32, ctx: &LintContext) {
+        if is_self_config_comment(comment) {
+            return;
+        }
+
+        let text = if self.ignore_urls { mask_urls(comment) } else { Cow::Borrowed(comment) };
+        let matches = self.comment_contains_warning_term(&text, offset);
+        if matches.is_empty() {
+            return;
+        }
+
+        let comment_to_display = truncate_comment(comment);
+        for (WarningTerm { term, severity, .. }, span) in matches {
+            let diagnostic =
+                no_warning_comments_diagnostic(term, &comment_to_display, *severity, span);
+            if severity.is_some() {
+                ctx.diagnostic_with_own_severity(diagnostic);
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+
+    /// Returns every configured term found in `comment`, along with the span of
+    /// the matched text. `offset` is the position of `comment` in the source.
+    #[expect(clippy::cast_possible_truncation)]
+    fn comment_contains_warning_term(
+        &self,
+        comment: &str,
+        offset: u32,
+    ) -> Vec<(&WarningTerm, Span)> {
+        self.terms
+            .iter()
+            .filter_map(|term| {
+                let matched = term.regex.captures(comment)?.get(1)?;
+                let span =
+                    Span::new(offset + matched.start() as u32, offset + matched.end() as u32);
+                Some((term, span))
+            })
+            .collect()
+    }
+}
+
+/// Builds the pattern used to find `term` in a comment, mirroring ESLint's `convertToRegExp`.
+///
+/// Terms that start or end with a word character get a word boundary on that
+/// side, so that e.g. `todo` does not match inside `TodoMVC`. When `location`
+/// is [`Location::Start`], the term may only be preceded by whitespace and
+/// `decoration` characters. The term itself is captured by the first group.
+fn convert_to_regexp(
+    term: &str,
+    location: Location,
+    decoration: &str,
+    case_sensitive: bool,
+) -> Regex {
+    let escaped = escape_regexp(term);
+    let prefix = match location {
+        Location::Start => format!(r"^[\s{}]*", escape_regexp(decoration)),
+        Location::Anywhere if term.starts_with(is_word_char) => r"\b".to_string(),
+        Location::Anywhere => String::new(),
+    };
+    let suffix = if term.ends_with(is_word_char) { r"\b" } else { "" };
+
+    RegexBuilder::new(&format!("{prefix}({escaped}){suffix}"))
+        .case_insensitive(!case_sensitive)
+        .build()
+        .expect("escaped warning term should always be a valid regex")
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn escape_regexp(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '.'
+                | '+'
+                | '*'
+                | '?'
+                | '('
+                | ')'
+                | '|'
+                | '['
+                | ']'
+                | '{'
+                | '}'
+                | '^'
+                | '$'
+                | '#'
+                | '&'
+                | '-'
+                | '~'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Replaces the URLs in `comment` with `\0` characters, so that terms inside them aren't
+/// matched, while keeping the byte offsets of the rest of the comment intact.
+fn mask_urls(comment: &str) -> Cow<'_, str> {
+    URL_REGEX.replace_all(comment, |captures: &Captures| "\0".repeat(captures[0].len()))
+}
+
+/// Comments configuring this rule, e.g. `/* eslint no-warning-comments: ["error", { "terms": ["todo"] }] */`,
+/// would otherwise always report themselves.
+fn is_self_config_comment(comment: &str) -> bool {
+    let comment = comment.trim_start();
+    (comment.starts_with("eslint") || comment.starts_with("oxlint"))
+        && comment.contains("no-warning-comments")
+}
+
+/// Shortens `comment` to at most [`CHAR_LIMIT`] characters on a word
+/// boundary, appending `...` if anything was cut off.
+fn truncate_comment(comment: &str) -> String {
+    let mut comment_to_display = String::new();
+    let mut truncated = false;
+
+    for word in comment.split_whitespace() {
+        let added_len = if comment_to_display.is_empty() { word.len() } else { word.len() + 1 };
+        if comment_to_display.len() + added_len > CHAR_LIMIT {
+            truncated = true;
+            break;
+        }
+        if !comment_to_display.is_empty() {
+            comment_to_display.push(' ');
+        }
+        comment_to_display.push_str(word);
+    }
+
+    if truncated {
+        comment_to_display.push_str("...");
+    }
+    comment_to_display
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// any comment", Some(serde_json::json!([{ "terms": ["fixme"] }]))),
+        ("// any comment", Some(serde_json::json!([{ "terms": ["fixme", "todo"] }]))),
+        ("// any comment", None),
+        ("// any comment", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        (
+            "// any comment with TODO, FIXME or XXX",
+            Some(serde_json::json!([{ "location": "start" }])),
+        ),
+        ("// any comment with TODO, FIXME or XXX", None),
+        ("/* any block comment */", Some(serde_json::json!([{ "terms": ["fixme"] }]))),
+        ("/* any block comment */", Some(serde_json::json!([{ "terms": ["fixme", "todo"] }]))),
+        ("/* any block comment */", None),
+        ("/* any block comment */", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        (
+            "/* any block comment with TODO, FIXME or XXX */",
+            Some(serde_json::json!([{ "location": "start" }])),
+        ),
+        ("/* any block comment with TODO, FIXME or XXX */", None),
+        ("/* any block comment with (TODO, FIXME's or XXX!) */", None),
+        ("/**\n *any block comment \n*with (TODO, FIXME's or XXX!) **/", None),
+        (
+            "// comments containing terms as substrings like TodoMVC",
+            Some(serde_json::json!([{ "terms": ["todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// TodoMVC and Fixmeister are not warning terms",
+            Some(serde_json::json!([{ "location": "anywhere" }])),
+        ),
+        (
+            "// terms as suffixes like myTodo or prefixes like TodoList",
+            Some(serde_json::json!([{ "terms": ["todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// special regex characters don't cause problems",
+            Some(serde_json::json!([{ "terms": ["[aeiou]"], "location": "anywhere" }])),
+        ),
+        (
+            "/*eslint no-warning-comments: [2, { \"terms\": [\"todo\", \"fixme\", \"any other term\"], \"location\": \"anywhere\" }]*/\n\nvar x = 10;\n",
+            Some(serde_json::json!([{ "location": "anywhere" }])),
+        ),
+        (
+            "/* eslint no-warning-comments: [2, { \"terms\": [\"todo\", \"fixme\", \"any other term\"], \"location\": \"anywhere\" }] */\n\nvar x = 10;\n",
+            Some(serde_json::json!([{ "location": "anywhere" }])),
+        ),
+        ("// foo", Some(serde_json::json!([{ "terms": ["foo-bar"] }]))),
+        ("/** multi-line block comment with lines starting with\nTODO\nFIXME or\nXXX\n*/", None),
+        ("//!TODO ", Some(serde_json::json!([{ "decoration": ["*"] }]))),
+        (
+            "// todo",
+            Some(serde_json::json!([{ "terms": [{ "term": "todo", "severity": "off" }] }])),
+        ),
+        (
+            "// xxx",
+            Some(
+                serde_json::json!([{ "terms": [{ "term": "fixme", "severity": "error" }, "todo"] }]),
+            ),
+        ),
+        ("foo(); // any comment", None),
+        ("foo();\n//", None),
+        ("foo(); /**/", None),
+        ("// todo", Some(serde_json::json!([{ "terms": ["TODO"], "caseSensitive": true }]))),
+        (
+            "// any Fixme",
+            Some(
+                serde_json::json!([{ "terms": ["FIXME"], "location": "anywhere", "caseSensitive": true }]),
+            ),
+        ),
+        (
+            "// https://github.com/eslint/eslint/issues?q=fixme see",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": true }])),
+        ),
+        (
+            "// see https://example.com/todo/fixme.html",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": true }])),
+        ),
+        (
+            "/* http://example.com/#todo and https://example.com/?q=xxx */",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": true }])),
+        ),
+        ("// https://example.com TODO", Some(serde_json::json!([{ "ignoreUrls": true }]))),
+    ];
+
+    let fail = vec![
+        ("// fixme", None),
+        ("// any fixme", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        ("// any fixme", Some(serde_json::json!([{ "terms": ["fixme"], "location": "anywhere" }]))),
+        ("// any FIXME", Some(serde_json::json!([{ "terms": ["fixme"], "location": "anywhere" }]))),
+        ("// any fIxMe", Some(serde_json::json!([{ "terms": ["fixme"], "location": "anywhere" }]))),
+        (
+            "/* any fixme */",
+            Some(serde_json::json!([{ "terms": ["FIXME"], "location": "anywhere" }])),
+        ),
+        ("/* fixme and todo */", None),
+        ("/* fixme! */", Some(serde_json::json!([{ "terms": ["fixme"] }]))),
+        (
+            "// regex [litera|$]",
+            Some(serde_json::json!([{ "terms": ["[litera|$]"], "location": "anywhere" }])),
+        ),
+        ("/* eslint one-var: 2 */", Some(serde_json::json!([{ "terms": ["eslint"] }]))),
+        (
+            "/* eslint one-var: 2 */",
+            Some(serde_json::json!([{ "terms": ["one"], "location": "anywhere" }])),
+        ),
+        ("// TODO: something small", None),
+        ("// TODO: something really longer than 40 characters", None),
+        (
+            "// https://github.com/eslint/eslint/pull/13522#discussion_r470293411 TODO",
+            Some(serde_json::json!([{ "location": "anywhere" }])),
+        ),
+        (
+            "// Comment ending with term followed by punctuation TODO!",
+            Some(serde_json::json!([{ "terms": ["todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// Comment ending with term including punctuation TODO!",
+            Some(serde_json::json!([{ "terms": ["todo!"], "location": "anywhere" }])),
+        ),
+        (
+            "// Comment ending with term including punctuation followed by more TODO!!!",
+            Some(serde_json::json!([{ "terms": ["todo!"], "location": "anywhere" }])),
+        ),
+        (
+            "// !TODO comment starting with term preceded by punctuation",
+            Some(serde_json::json!([{ "terms": ["todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// !TODO comment starting with term including punctuation",
+            Some(serde_json::json!([{ "terms": ["!todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// !!!TODO comment starting with term including punctuation preceded by more",
+            Some(serde_json::json!([{ "terms": ["!todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// FIX!term ending with punctuation followed word character",
+            Some(serde_json::json!([{ "terms": ["FIX!"], "location": "anywhere" }])),
+        ),
+        (
+            "// Term starting with punctuation preceded word character!FIX",
+            Some(serde_json::json!([{ "terms": ["!FIX"], "location": "anywhere" }])),
+        ),
+        (
+            "//!XXX comment starting with no spaces (anywhere)",
+            Some(serde_json::json!([{ "terms": ["!xxx"], "location": "anywhere" }])),
+        ),
+        (
+            "//!XXX comment starting with no spaces (start)",
+            Some(serde_json::json!([{ "terms": ["!xxx"], "location": "start" }])),
+        ),
+        ("//!TODO comment with decoration", Some(serde_json::json!([{ "decoration": ["!"] }]))),
+        ("/** TODO comment with decoration */", Some(serde_json::json!([{ "decoration": ["*"] }]))),
+        (
+            "// todo inside the Todo list",
+            Some(serde_json::json!([{ "terms": ["todo"], "location": "anywhere" }])),
+        ),
+        (
+            "// FIXME: this is an error",
+            Some(
+                serde_json::json!([{ "terms": [{ "term": "fixme", "severity": "error" }, "todo"] }]),
+            ),
+        ),
+        (
+            "// TODO: this is a warning",
+            Some(
+                serde_json::json!([{ "terms": [{ "term": "fixme", "severity": "error" }, "todo"] }]),
+            ),
+        ),
+        (
+            "// TODO: this is also a warning",
+            Some(serde_json::json!([{ "terms": [{ "term": "todo", "severity": "warn" }] }])),
+        ),
+        ("foo();\n// todo", None),
+        ("foo(); /* fixme */", None),
+        ("foo(); // xxx: ünïcödé", None),
+        ("// ünïcödé then todo", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        ("/* fixme, todo and xxx */", Some(serde_json::json!([{ "location": "anywhere" }]))),
+        (
+            "/*\n * TODO: on the second line\n */",
+            Some(serde_json::json!([{ "decoration": ["*"] }])),
+        ),
+        ("// todo", Some(serde_json::json!([{ "terms": ["TODO"] }]))),
+        ("// todo", Some(serde_json::json!([{ "terms": ["TODO"], "caseSensitive": false }]))),
+        ("// TODO", Some(serde_json::json!([{ "terms": ["TODO"], "caseSensitive": true }]))),
+        (
+            "// any FIXME",
+            Some(
+                serde_json::json!([{ "terms": ["FIXME"], "location": "anywhere", "caseSensitive": true }]),
+            ),
+        ),
+        (
+            "// https://github.com/eslint/eslint/pull/13522#discussion_r470293411 TODO",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": true }])),
+        ),
+        (
+            "// see https://example.com/todo/fixme.html",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": false }])),
+        ),
+        (
+            "// fixme: https://example.com/todo",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": true }])),
+        ),
+        ("// TODO: https://example.com/fixme", Some(serde_json::json!([{ "ignoreUrls": true }]))),
+        (
+            "// todo://example.com",
+            Some(serde_json::json!([{ "location": "anywhere", "ignoreUrls": true }])),
+        ),
+    ];
+
+    Tester::new(NoWarningComments::NAME, NoWarningComments::PLUGIN, pass, fail).test_and_snapshot();
+}
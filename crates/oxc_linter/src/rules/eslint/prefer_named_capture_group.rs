@@ -0,0 +1,160 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{CapturingGroup, Pattern},
+    visit::{Visit, walk},
+};
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+
+fn prefer_named_capture_group_diagnostic(group: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Capture group `{group}` should be converted to a named or non-capturing group."
+    ))
+    .with_help(
+        "Name the group with `(?<name>...)`, or use `(?:...)` if it doesn't need to be captured.",
+    )
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNamedCaptureGroup;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using named capture groups in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Numbered capture groups are referred to by their position in the pattern, which is hard
+    /// to follow in complex regular expressions and easy to break when groups are added or
+    /// removed. Named capture groups like `(?<year>\d{4})` document what they match and can be
+    /// accessed by name, e.g. `match.groups.year`.
+    ///
+    /// Groups that don't need to be captured can use a non-capturing group `(?:...)` instead.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const foo = /(ba[rz])/;
+    /// const bar = new RegExp("(ba[rz])");
+    /// const baz = RegExp("(ba[rz])");
+    ///
+    /// foo.exec("bar")[1];
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const foo = /(?<id>ba[rz])/;
+    /// const bar = new RegExp("(?<id>ba[rz])");
+    /// const baz = RegExp("(?<id>ba[rz])");
+    /// const xyz = /xyz(?:zy|abc)/;
+    ///
+    /// foo.exec("bar").groups.id;
+    /// ```
+    PreferNamedCaptureGroup,
+    eslint,
+    style,
+    suggestion
+);
+
+impl Rule for PreferNamedCaptureGroup {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            check_pattern(pattern, ctx);
+        });
+    }
+}
+
+fn check_pattern(pattern: &Pattern, ctx: &LintContext) {
+    let mut finder = CapturingGroupFinder::default();
+    finder.visit_pattern(pattern);
+
+    let mut next_index = 0;
+    for span in finder.unnamed_groups {
+        // Suggest the first `tempN` name which isn't used by a group of this pattern yet.
+        let name = loop {
+            next_index += 1;
+            let name = format!("temp{next_index}");
+            if !finder.names.contains(&name.as_str()) {
+                break name;
+            }
+        };
+
+        ctx.diagnostic_with_suggestion(
+            prefer_named_capture_group_diagnostic(ctx.source_range(span), span),
+            |fixer| {
+                fixer
+                    .insert_text_after_range(Span::sized(span.start, 1), format!("?<{name}>"))
+                    .with_message(format!("Name the group `{name}`"))
+            },
+        );
+    }
+}
+
+#[derive(Default)]
+struct CapturingGroupFinder<'a> {
+    unnamed_groups: Vec<Span>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Visit<'a> for CapturingGroupFinder<'a> {
+    fn visit_capturing_group(&mut self, group: &CapturingGroup<'a>) {
+        match &group.name {
+            Some(name) => self.names.push(name.as_str()),
+            None => self.unnamed_groups.push(group.span),
+        }
+        walk::walk_capturing_group(self, group);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/normal_regex/",
+        "/(?:[0-9]{4})/",
+        "/(?<year>[0-9]{4})/",
+        "/(?<year>[0-9]{4})-(?<month>[0-9]{2})/",
+        "/(?=a)(?!b)(?<=c)(?<!d)/",
+        r"/\(a\)/",
+        r"/[(a)]/",
+        r"/\\\(a\)/",
+        "/(?<a>(?:b))/",
+        "new RegExp('(?<year>[0-9]{4})')",
+        "RegExp('(?:[0-9]{4})')",
+        r"new RegExp('\\(a\\)')",
+        "new RegExp(foo)",
+        "new RegExp()",
+        "new Foo('(a)')",
+        "'(a)'",
+    ];
+
+    let fail = vec![
+        "/([0-9]{4})/",
+        "/(?:a(b))/",
+        "/(a)(?<b>c)(d)/",
+        r"/\((a)\)/",
+        r"/\\(a)/",
+        "/(?<temp1>a)(b)/",
+        "new RegExp('([0-9]{4})')",
+        "new RegExp('([0-9]{4})', 'u')",
+        "RegExp('(a)|(b)')",
+    ];
+
+    let fix = vec![
+        ("/([0-9]{4})/", "/(?<temp1>[0-9]{4})/"),
+        ("/(?:a(b))/", "/(?:a(?<temp1>b))/"),
+        ("/(a)(?<b>c)(d)/", "/(?<temp1>a)(?<b>c)(?<temp2>d)/"),
+        ("/(?<temp1>a)(b)/", "/(?<temp1>a)(?<temp2>b)/"),
+        ("new RegExp('([0-9]{4})')", "new RegExp('(?<temp1>[0-9]{4})')"),
+    ];
+
+    Tester::new(PreferNamedCaptureGroup::NAME, PreferNamedCaptureGroup::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
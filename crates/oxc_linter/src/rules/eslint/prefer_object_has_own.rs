@@ -192,6 +192,13 @@ fn test() {
 			        Object.hasOwn(obj,"");
 			        "#,
         "const hasProperty = Object.hasOwn(object, property);",
+        "Object.prototype.hasOwnProperty.call()",
+        "Object.prototype.hasOwnProperty.call(obj)",
+        "Object.prototype.hasOwnProperty.call(obj, prop, extra)",
+        "Object.hasOwnProperty.call(obj)",
+        "({}).hasOwnProperty.call(obj)",
+        "({}).hasOwnProperty.call(obj, prop, extra)",
+        "Object.prototype.hasOwnProperty.call(...args)",
         // "/* global Object: off */
         // 	        ({}).hasOwnProperty.call(a, b);",
     ];
@@ -6,12 +6,16 @@ use std::{
 
 use cow_utils::CowUtils;
 use itertools::Itertools;
-use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement};
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, ImportSpecifier, Statement};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+};
 
 fn unexpected_syntax_order_diagnostic(
     curr_kind: &ImportKind,
@@ -59,11 +63,11 @@ declare_oxc_lint!(
     /// This rule checks all import declarations and verifies that all imports are first sorted
     /// by the used member syntax and then alphabetically by the first member or alias name.
     ///
+    /// ### Why is this bad?
+    ///
     /// When declaring multiple imports, a sorted list of import declarations make it easier for developers to read
     /// the code and find necessary imports later.
     ///
-    /// ### Why is this bad?
-    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -73,6 +77,56 @@ declare_oxc_lint!(
     /// import d from 'foo.js';
     /// import e from 'bar.js';
     /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import 'module-without-export.js';
+    /// import * as bar from 'bar.js';
+    /// import {a, b, c} from 'foo.js';
+    /// import d from 'd.js';
+    /// ```
+    ///
+    /// Misordered members and declarations are fixed automatically, unless there are comments
+    /// or blank lines between them. Declarations are not reordered if the group contains a side-effect import
+    /// (`import 'foo.js'`), as moving those could change the program's behavior.
+    ///
+    /// ### Options
+    ///
+    /// #### ignoreCase
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, the rule ignores the case-sensitivity of the imports local name.
+    ///
+    /// #### ignoreDeclarationSort
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignores the sorting of import declaration statements.
+    ///
+    /// #### ignoreMemberSort
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignores the member sorting within a `multiple` member import declaration.
+    ///
+    /// #### memberSyntaxSortOrder
+    ///
+    /// `{ type: ("none" | "all" | "multiple" | "single")[], default: ["none", "all", "multiple", "single"] }`
+    ///
+    /// The order of the member syntax groups, all four must be specified:
+    /// - `none` = import module without exported bindings.
+    /// - `all` = import all members provided by exported bindings.
+    /// - `multiple` = import multiple members.
+    /// - `single` = import single member.
+    ///
+    /// #### allowSeparatedGroups
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, the rule checks the sorting of import declaration statements only for those
+    /// that appear on consecutive lines. Import declarations separated by blank lines or comments
+    /// start a new group.
     SortImports,
     eslint,
     style,
@@ -146,39 +200,112 @@ impl Rule for SortImports {
             }
         }
 
-        let mut previous: Option<&ImportDeclaration> = None;
-
+        // ```js
+        // import b from 'foo.js'
+        //
+        // import a from 'foo.js'
+        // ```
+        // with `allowSeparatedGroups`, line 1 and line 3 are in different groups
+        let mut groups: Vec<Vec<&ImportDeclaration>> = vec![];
         for current in import_declarations {
-            if !self.ignore_declaration_sort {
-                // ```js
-                // import b from 'foo.js'
-                //
-                // import a from 'foo.js'
-                // ```
-                // when visit line 3, line 2 will make `previous` to be `None`
-                if self.allow_separated_groups
-                    && previous.is_some_and(|previous| {
-                        get_number_of_lines_between(previous.span, current.span, ctx) > 0
-                    })
+            match groups.last_mut() {
+                Some(group)
+                    if !self.allow_separated_groups
+                        || group.last().is_some_and(|previous| {
+                            get_number_of_lines_between(previous.span, current.span, ctx) == 0
+                        }) =>
                 {
-                    previous = None;
+                    group.push(current);
                 }
+                _ => groups.push(vec![current]),
+            }
+        }
 
-                if let Some(previous) = previous {
-                    self.check_syntax_order_and_member_order(previous, current, ctx);
+        for group in &groups {
+            // Only the first misordered declaration gets a fix, which sorts the whole group.
+            let mut has_fix = false;
+
+            for (index, current) in group.iter().enumerate() {
+                if !self.ignore_declaration_sort && index > 0 {
+                    if let Some(diagnostic) =
+                        self.check_syntax_order_and_member_order(group[index - 1], current)
+                    {
+                        if !has_fix && is_declaration_sort_fixable(group, ctx) {
+                            has_fix = true;
+                            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                                self.sort_declarations(group, fixer)
+                            });
+                        } else {
+                            ctx.diagnostic(diagnostic);
+                        }
+                    }
                 }
 
-                previous = Some(current);
-            }
-
-            if !self.ignore_member_sort {
-                self.check_member_sort_with_fix(current, ctx);
+                if !self.ignore_member_sort {
+                    self.check_member_sort_with_fix(current, ctx);
+                }
             }
         }
     }
 }
 
 impl SortImports {
+    fn sort_declarations<'a>(
+        &self,
+        group: &[&ImportDeclaration],
+        fixer: RuleFixer<'_, 'a>,
+    ) -> RuleFix<'a> {
+        // import b from 'b';     import a from 'a';
+        //                   ^^^^^
+        let mut paddings: Vec<&str> = group
+            .windows(2)
+            .map(|window| fixer.source_range(Span::new(window[0].span.end, window[1].span.start)))
+            .collect();
+        paddings.push("");
+
+        let sorted = group.iter().sorted_by_cached_key(|decl| {
+            let name = get_first_local_member_name(decl).map(|name| {
+                if self.ignore_case {
+                    Cow::Owned(name.cow_to_ascii_lowercase().into_owned())
+                } else {
+                    name
+                }
+            });
+            (self.member_syntax_sort_order.get_group_index_by_import_decl(decl), name)
+        });
+
+        let sorted_text = sorted.zip(paddings).fold(String::new(), |mut acc, (decl, padding)| {
+            let _ = acc.write_str(&self.get_declaration_text(decl, &fixer));
+            let _ = acc.write_str(padding);
+            acc
+        });
+
+        fixer.replace(group[0].span.merge(group[group.len() - 1].span), sorted_text)
+    }
+
+    // Source text of `decl`, with its members sorted unless `ignoreMemberSort` is set.
+    fn get_declaration_text<'a>(
+        &self,
+        decl: &ImportDeclaration,
+        fixer: &RuleFixer<'_, 'a>,
+    ) -> Cow<'a, str> {
+        let text = fixer.source_range(decl.span);
+        let specifiers = get_import_specifiers(decl);
+        if self.ignore_member_sort || specifiers.len() < 2 {
+            return Cow::Borrowed(text);
+        }
+
+        let specifiers_span = specifiers[0].span.merge(specifiers[specifiers.len() - 1].span);
+        let start = (specifiers_span.start - decl.span.start) as usize;
+        let end = (specifiers_span.end - decl.span.start) as usize;
+        Cow::Owned(format!(
+            "{}{}{}",
+            &text[..start],
+            self.get_sorted_specifiers_text(&specifiers, |span| fixer.source_range(span)),
+            &text[end..]
+        ))
+    }
+
     // Check between two import declarations.
     //
     // 1. syntax order
@@ -197,8 +324,7 @@ impl SortImports {
         &self,
         previous: &ImportDeclaration,
         current: &ImportDeclaration,
-        ctx: &LintContext,
-    ) {
+    ) -> Option<OxcDiagnostic> {
         let current_member_syntax_group_index =
             self.member_syntax_sort_order.get_group_index_by_import_decl(current);
         let previous_member_syntax_group_index =
@@ -225,28 +351,20 @@ impl SortImports {
                     self.member_syntax_sort_order.get(current_member_syntax_group_index);
                 let previous_kind =
                     self.member_syntax_sort_order.get(previous_member_syntax_group_index);
-                if let Some((current_kind, previous_kind)) = current_kind.zip(previous_kind) {
-                    ctx.diagnostic(unexpected_syntax_order_diagnostic(
-                        current_kind,
-                        previous_kind,
-                        current.span,
-                    ));
-                }
+                let (current_kind, previous_kind) = current_kind.zip(previous_kind)?;
+                Some(unexpected_syntax_order_diagnostic(current_kind, previous_kind, current.span))
             }
             std::cmp::Ordering::Equal => {
                 // ```js
                 // import { b } from 'foo.js'
                 // import { a } from 'foo.js' // <-- incorrect, 'a' should come before 'b'
                 // ```
-                if let Some((current_name, previous_name)) =
-                    current_local_member_name.zip(previous_local_member_name)
-                {
-                    if current_name < previous_name {
-                        ctx.diagnostic(sort_imports_alphabetically_diagnostic(current.span));
-                    }
-                }
+                let (current_name, previous_name) =
+                    current_local_member_name.zip(previous_local_member_name)?;
+                (current_name < previous_name)
+                    .then(|| sort_imports_alphabetically_diagnostic(current.span))
             }
-            std::cmp::Ordering::Greater => {}
+            std::cmp::Ordering::Greater => None,
         }
     }
 
@@ -255,20 +373,7 @@ impl SortImports {
     // import { b, a } from 'foo.js'
     // ```
     fn check_member_sort_with_fix(&self, current: &ImportDeclaration, ctx: &LintContext) {
-        let Some(specifiers) = &current.specifiers else {
-            return;
-        };
-
-        let specifiers: Vec<_> = specifiers
-            .iter()
-            .filter_map(|specifier| {
-                if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
-                    Some(specifier)
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let specifiers = get_import_specifiers(current);
 
         if specifiers.len() < 2 {
             return;
@@ -307,49 +412,55 @@ impl SortImports {
             ctx.diagnostic_with_fix(
                 sort_members_alphabetically_diagnostic(unsorted_name, unsorted_span),
                 |fixer| {
-                    // import { a, b,      c, d } from 'foo.js'
-                    //            ^  ^^^^^^  ^
-                    let mut paddings: Vec<&str> = specifiers
-                        .windows(2)
-                        .map(|window| {
-                            let a = window[0].span;
-                            let b = window[1].span;
-
-                            let padding = Span::new(a.end, b.start);
-                            ctx.source_range(padding)
-                        })
-                        .collect();
-
-                    // add a empty string for zip with specifiers
-                    paddings.push("");
-
-                    let specifiers = specifiers.iter().sorted_by(|a, b| {
-                        let a = a.local.name.as_str();
-                        let b = b.local.name.as_str();
-
-                        if self.ignore_case {
-                            a.cow_to_ascii_lowercase().cmp(&b.cow_to_ascii_lowercase())
-                        } else {
-                            a.cmp(b)
-                        }
-                    });
-
-                    let sorted_text = specifiers.zip(paddings).fold(
-                        String::new(),
-                        |mut acc, (specifier, padding)| {
-                            let _ = acc.write_str(ctx.source_range(specifier.span));
-                            let _ = acc.write_str(padding);
-                            acc
-                        },
-                    );
-
-                    fixer.replace(specifiers_span, sorted_text)
+                    fixer.replace(
+                        specifiers_span,
+                        self.get_sorted_specifiers_text(&specifiers, |span| ctx.source_range(span)),
+                    )
                 },
             );
         } else {
             ctx.diagnostic(sort_members_alphabetically_diagnostic(unsorted_name, unsorted_span));
         }
     }
+
+    fn get_sorted_specifiers_text<'a>(
+        &self,
+        specifiers: &[&ImportSpecifier],
+        source_range: impl Fn(Span) -> &'a str,
+    ) -> String {
+        // import { a, b,      c, d } from 'foo.js'
+        //            ^  ^^^^^^  ^
+        let mut paddings: Vec<&str> = specifiers
+            .windows(2)
+            .map(|window| {
+                let a = window[0].span;
+                let b = window[1].span;
+
+                let padding = Span::new(a.end, b.start);
+                source_range(padding)
+            })
+            .collect();
+
+        // add a empty string for zip with specifiers
+        paddings.push("");
+
+        let specifiers = specifiers.iter().sorted_by(|a, b| {
+            let a = a.local.name.as_str();
+            let b = b.local.name.as_str();
+
+            if self.ignore_case {
+                a.cow_to_ascii_lowercase().cmp(&b.cow_to_ascii_lowercase())
+            } else {
+                a.cmp(b)
+            }
+        });
+
+        specifiers.zip(paddings).fold(String::new(), |mut acc, (specifier, padding)| {
+            let _ = acc.write_str(source_range(specifier.span));
+            let _ = acc.write_str(padding);
+            acc
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -443,6 +554,34 @@ impl Display for ImportKind {
     }
 }
 
+// Moving side-effect imports could change the program's behavior, and comments between the
+// declarations can not be reliably moved along with them. Declarations separated by blank lines
+// are not moved either, as that would mix up the blocks they are in.
+fn is_declaration_sort_fixable(group: &[&ImportDeclaration], ctx: &LintContext) -> bool {
+    group.iter().all(|decl| decl.specifiers.is_some())
+        && !ctx.has_comments_between(group[0].span.merge(group[group.len() - 1].span))
+        && group
+            .windows(2)
+            .all(|window| get_number_of_lines_between(window[0].span, window[1].span, ctx) == 0)
+}
+
+// `import { a, b } from 'foo.js'` -> `[a, b]`, ignoring default and namespace specifiers.
+fn get_import_specifiers<'b, 'a>(decl: &'b ImportDeclaration<'a>) -> Vec<&'b ImportSpecifier<'a>> {
+    let Some(specifiers) = &decl.specifiers else {
+        return vec![];
+    };
+    specifiers
+        .iter()
+        .filter_map(|specifier| {
+            if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                Some(&**specifier)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn get_first_local_member_name<'a>(decl: &ImportDeclaration<'a>) -> Option<Cow<'a, str>> {
     let specifiers = decl.specifiers.as_ref()?;
     specifiers.first().map(ImportDeclarationSpecifier::name)
@@ -804,6 +943,39 @@ fn test() {
               import { a, c } from 'c';",
             None,
         ),
+        ("import b from 'b';\nimport a from 'a';", "import a from 'a';\nimport b from 'b';", None),
+        (
+            "import a from 'a';\nimport * as b from 'b';\nimport {c, d} from 'c';",
+            "import * as b from 'b';\nimport {c, d} from 'c';\nimport a from 'a';",
+            None,
+        ),
+        (
+            "import {d, c} from 'c';\nimport {b, a} from 'a';",
+            "import {a, b} from 'a';\nimport {c, d} from 'c';",
+            None,
+        ),
+        (
+            "import B from 'B';\nimport a from 'a';",
+            "import a from 'a';\nimport B from 'B';",
+            Some(serde_json::json!([{ "ignoreCase": true }])),
+        ),
+        (
+            "import b from 'b';\nimport a from 'a';\n\nimport d from 'd';\nimport c from 'c';",
+            "import a from 'a';\nimport b from 'b';\n\nimport c from 'c';\nimport d from 'd';",
+            Some(serde_json::json!([{ "allowSeparatedGroups": true }])),
+        ),
+        // Not fixed due to side-effect import
+        (
+            "import b from 'b';\nimport 'foo';\nimport a from 'a';",
+            "import b from 'b';\nimport 'foo';\nimport a from 'a';",
+            None,
+        ),
+        // Not fixed due to comment
+        (
+            "import b from 'b';\n// comment\nimport a from 'a';",
+            "import b from 'b';\n// comment\nimport a from 'a';",
+            None,
+        ),
         // Not fixed due to comment
         (
             "import {zzzzz, /* comment */ aaaaa} from 'foo.js';",
@@ -3,13 +3,18 @@ use std::{borrow::Cow, cmp::Ordering};
 use cow_utils::CowUtils;
 use oxc_ast::{
     AstKind,
-    ast::{BindingPatternKind, VariableDeclarator},
+    ast::{BindingPatternKind, Expression, VariableDeclarator},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+};
 
 fn sort_vars_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Variable declarations should be sorted").with_label(span)
@@ -46,7 +51,7 @@ declare_oxc_lint!(
     SortVars,
     eslint,
     pedantic,
-    pending
+    fix
 );
 
 impl Rule for SortVars {
@@ -69,17 +74,28 @@ impl Rule for SortVars {
             return;
         }
 
-        let mut previous: Option<&VariableDeclarator> = None;
-        for current in var_decl
+        let id_declarations = var_decl
             .declarations
             .iter()
             .filter(|decl| matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_)))
-        {
+            .collect::<Vec<_>>();
+
+        let mut fixable = is_fixable(&id_declarations);
+        let mut previous: Option<&VariableDeclarator> = None;
+        for current in id_declarations.iter().copied() {
             if let Some(previous) = previous {
                 if self.get_sortable_name(previous).cmp(&self.get_sortable_name(current))
                     == Ordering::Greater
                 {
-                    ctx.diagnostic(sort_vars_diagnostic(current.span));
+                    if fixable {
+                        ctx.diagnostic_with_fix(sort_vars_diagnostic(current.span), |fixer| {
+                            self.sort_declarations(&id_declarations, fixer)
+                        });
+                        // the fix sorts all declarators at once
+                        fixable = false;
+                    } else {
+                        ctx.diagnostic(sort_vars_diagnostic(current.span));
+                    }
                 }
             }
 
@@ -88,7 +104,51 @@ impl Rule for SortVars {
     }
 }
 
+/// Reordering declarators changes the order their initializers are evaluated in, so only
+/// declarations without initializers or with literal initializers are fixed. Anything else
+/// could reference another variable of the declaration or have side effects.
+fn is_fixable(declarations: &[&VariableDeclarator]) -> bool {
+    declarations.iter().all(|decl| {
+        decl.init.as_ref().is_none_or(|init| {
+            matches!(
+                init,
+                Expression::BooleanLiteral(_)
+                    | Expression::NullLiteral(_)
+                    | Expression::NumericLiteral(_)
+                    | Expression::BigIntLiteral(_)
+                    | Expression::RegExpLiteral(_)
+                    | Expression::StringLiteral(_)
+            )
+        })
+    })
+}
+
 impl SortVars {
+    /// Sorts the declarators in place, keeping the text between them (e.g. `, ` or
+    /// destructuring declarators) where it is.
+    fn sort_declarations<'a>(
+        &self,
+        declarations: &[&VariableDeclarator<'a>],
+        fixer: RuleFixer<'_, 'a>,
+    ) -> RuleFix<'a> {
+        let mut sorted = declarations.to_vec();
+        sorted.sort_by(|a, b| self.get_sortable_name(a).cmp(&self.get_sortable_name(b)));
+
+        let mut text = String::new();
+        for (i, decl) in sorted.iter().enumerate() {
+            text.push_str(fixer.source_range(decl.span));
+            if let Some(next) = declarations.get(i + 1) {
+                text.push_str(
+                    fixer.source_range(Span::new(declarations[i].span.end, next.span.start)),
+                );
+            }
+        }
+
+        let span =
+            Span::new(declarations[0].span.start, declarations[declarations.len() - 1].span.end);
+        fixer.replace(span, text).with_message("Sort the variable declarations")
+    }
+
     fn get_sortable_name<'a>(&self, decl: &VariableDeclarator<'a>) -> Cow<'a, str> {
         let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else {
             unreachable!();
@@ -197,7 +257,7 @@ fn test() {
         ("var c, a = b = 0", None),
     ];
 
-    let _fix = vec![
+    let fix = vec![
         ("var b, a", "var a, b", None),
         ("var b , a", "var a , b", None),
         ("var b=10, a=20;", "var a=20, b=10;", None),
@@ -221,7 +281,14 @@ fn test() {
             Some(serde_json::json!([{ "ignoreCase": true }])),
         ),
         ("var {} = 1, b, a", "var {} = 1, a, b", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("var b, [x] = y, a", "var a, [x] = y, b", None),
+        ("var b = 'b', a = /a/, c = null;", "var a = /a/, b = 'b', c = null;", None),
+        ("var b=10, a=f();", "var b=10, a=f();", None),
+        ("var b=10, a=b;", "var b=10, a=b;", None),
+        ("var b = 0, a = `${b}`;", "var b = 0, a = `${b}`;", None),
+        ("var b = 0, c = b, a;", "var b = 0, c = b, a;", None),
+        ("var c, a = b = 0", "var c, a = b = 0", None),
     ];
 
-    Tester::new(SortVars::NAME, SortVars::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(SortVars::NAME, SortVars::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
 }
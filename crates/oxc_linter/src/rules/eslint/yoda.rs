@@ -37,7 +37,7 @@ declare_oxc_lint!(
     /// Yoda conditions are so named because the literal value of the condition comes first while the variable comes second. For example, the following is a Yoda condition:
     /// ```js
     /// if ("red" === color) {
-    //     // ...
+    ///     // ...
     /// }
     /// ```
     /// This is called a Yoda condition because it reads as, "if red equals the color", similar to the way the Star Wars character Yoda speaks. Compare to the other way of arranging the operands:
@@ -76,6 +76,41 @@ declare_oxc_lint!(
     /// test('should assert something', () => {});
     /// ```
     ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// it('should be a test', () => {
+    ///     expect(foo()).toBe(true);
+    /// });
+    /// test.todo('should assert something');
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "jest/expect-expect": [
+    ///     "error",
+    ///     {
+    ///       "assertFunctionNames": ["expect", "request.**.expect"],
+    ///       "additionalTestBlockFunctions": ["checkForMe"]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// #### assertFunctionNames
+    ///
+    /// An array of function names that count as assertions. Names may contain
+    /// `*` (matches a single member segment) and `**` (matches any number of
+    /// segments), e.g. `request.*.expect` or `request.**.expect`. Defaults to
+    /// `["expect"]` (or `["expect", "expectTypeOf", "assert", "assertType"]`
+    /// for Vitest).
+    ///
+    /// #### additionalTestBlockFunctions
+    ///
+    /// An array of additional function names that should be treated as test
+    /// blocks, e.g. custom `it` wrappers. Defaults to `[]`.
+    ///
     /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/expect-expect.md),
     /// to use it, add the following configuration to your `.eslintrc.json`:
     ///
@@ -1,15 +1,14 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::ScopeId;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        JestFnKind, JestGeneralFnKind, PossibleJestNode, collect_possible_jest_call_node,
-        is_type_of_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, collect_possible_jest_call_node, is_type_of_jest_fn_call,
     },
 };
 
@@ -133,45 +132,38 @@ impl Rule for MaxNestedDescribe {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let mut describes_hooks_depth: Vec<ScopeId> = vec![];
-        let mut possibles_jest_nodes = collect_possible_jest_call_node(ctx);
-        possibles_jest_nodes.sort_by_key(|n| n.node.id());
+        let mut describe_calls = collect_possible_jest_call_node(ctx)
+            .iter()
+            .filter(|possible_jest_node| {
+                let AstKind::CallExpression(call_expr) = possible_jest_node.node.kind() else {
+                    return false;
+                };
+                is_type_of_jest_fn_call(
+                    call_expr,
+                    possible_jest_node,
+                    ctx,
+                    &[JestFnKind::General(JestGeneralFnKind::Describe)],
+                )
+            })
+            .map(|possible_jest_node| possible_jest_node.node.id())
+            .collect::<Vec<_>>();
+        describe_calls.sort_unstable();
+        let describe_call_set = describe_calls.iter().copied().collect::<FxHashSet<_>>();
 
-        for possible_jest_node in &possibles_jest_nodes {
-            self.run(possible_jest_node, &mut describes_hooks_depth, ctx);
-        }
-    }
-}
-
-impl MaxNestedDescribe {
-    fn run<'a>(
-        &self,
-        possible_jest_node: &PossibleJestNode<'a, '_>,
-        describes_hooks_depth: &mut Vec<ScopeId>,
-        ctx: &LintContext<'a>,
-    ) {
-        let node = possible_jest_node.node;
-        let scope_id = node.scope_id();
-        let AstKind::CallExpression(call_expr) = node.kind() else {
-            return;
-        };
-        let is_describe_call = is_type_of_jest_fn_call(
-            call_expr,
-            possible_jest_node,
-            ctx,
-            &[JestFnKind::General(JestGeneralFnKind::Describe)],
-        );
-
-        if is_describe_call && !describes_hooks_depth.contains(&scope_id) {
-            describes_hooks_depth.push(scope_id);
-        }
-
-        if is_describe_call && describes_hooks_depth.len() > self.max {
-            ctx.diagnostic(exceeded_max_depth(
-                describes_hooks_depth.len(),
-                self.max,
-                call_expr.span,
-            ));
+        for node_id in describe_calls {
+            // The `describe` call itself, and every `describe` call it is nested in.
+            let depth = 1 + ctx
+                .nodes()
+                .ancestor_ids(node_id)
+                .filter(|ancestor_id| describe_call_set.contains(ancestor_id))
+                .count();
+            if depth > self.max {
+                ctx.diagnostic(exceeded_max_depth(
+                    depth,
+                    self.max,
+                    ctx.nodes().get_node(node_id).span(),
+                ));
+            }
         }
     }
 }
@@ -284,6 +276,22 @@ fn test() {
             ",
             None,
         ),
+        (
+            "
+                describe('foo', () => {
+                    describe('bar', () => {});
+                });
+
+                describe('baz', () => {
+                    describe('qux', () => {});
+                });
+            ",
+            Some(serde_json::json!([{ "max": 2 }])),
+        ),
+        (
+            "describe.each([1])('%s', () => { describe.each([2])('%s', () => {}); });",
+            Some(serde_json::json!([{ "max": 2 }])),
+        ),
     ];
 
     let mut fail = vec![
@@ -394,6 +402,10 @@ fn test() {
             ",
             Some(serde_json::json!([{ "max": 1 }])),
         ),
+        (
+            "describe.each([1])('%s', () => { describe.each([2])('%s', () => { describe('c', () => {}); }); });",
+            Some(serde_json::json!([{ "max": 2 }])),
+        ),
     ];
 
     let pass_vitest = vec![
@@ -2,6 +2,7 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use phf::{Map, phf_map};
 
 use crate::{
     context::LintContext,
@@ -100,6 +101,20 @@ declare_oxc_lint!(
     fix
 );
 
+const ALIAS_METHODS_MAP: Map<&'static str, &'static str> = phf_map! {
+    "toBeCalled" => "toHaveBeenCalled",
+    "toBeCalledTimes" => "toHaveBeenCalledTimes",
+    "toBeCalledWith" => "toHaveBeenCalledWith",
+    "lastCalledWith" => "toHaveBeenLastCalledWith",
+    "nthCalledWith" => "toHaveBeenNthCalledWith",
+    "toReturn" => "toHaveReturned",
+    "toReturnTimes" => "toHaveReturnedTimes",
+    "toReturnWith" => "toHaveReturnedWith",
+    "lastReturnedWith" => "toHaveLastReturnedWith",
+    "nthReturnedWith" => "toHaveNthReturnedWith",
+    "toThrowError" => "toThrow",
+};
+
 impl Rule for NoAliasMethods {
     fn run_on_jest_node<'a, 'c>(
         &self,
@@ -120,10 +135,9 @@ impl Rule for NoAliasMethods {
             return;
         };
 
-        let Some(method_name) = BadAliasMethodName::from_str(alias.as_ref()) else {
+        let Some(canonical_name) = ALIAS_METHODS_MAP.get(alias.as_ref()) else {
             return;
         };
-        let (name, canonical_name) = method_name.name_with_canonical();
 
         let mut span = matcher.span;
         // expect(a).not['toThrowError']()
@@ -134,62 +148,12 @@ impl Rule for NoAliasMethods {
         }
 
         ctx.diagnostic_with_fix(
-            no_alias_methods_diagnostic(name, canonical_name, matcher.span),
-            // || Fix::new(canonical_name, Span::new(start, end)),
-            |fixer| fixer.replace(span, canonical_name),
+            no_alias_methods_diagnostic(&alias, canonical_name, matcher.span),
+            |fixer| fixer.replace(span, *canonical_name),
         );
     }
 }
 
-enum BadAliasMethodName {
-    ToBeCalled,
-    ToBeCalledTimes,
-    ToBeCalledWith,
-    LastCalledWith,
-    NthCalledWith,
-    ToReturn,
-    ToReturnTimes,
-    ToReturnWith,
-    LastReturnedWith,
-    NthReturnedWith,
-    ToThrowError,
-}
-
-impl BadAliasMethodName {
-    fn from_str(s: &str) -> Option<Self> {
-        match s {
-            "toBeCalled" => Some(Self::ToBeCalled),
-            "toBeCalledTimes" => Some(Self::ToBeCalledTimes),
-            "toBeCalledWith" => Some(Self::ToBeCalledWith),
-            "lastCalledWith" => Some(Self::LastCalledWith),
-            "nthCalledWith" => Some(Self::NthCalledWith),
-            "toReturn" => Some(Self::ToReturn),
-            "toReturnTimes" => Some(Self::ToReturnTimes),
-            "toReturnWith" => Some(Self::ToReturnWith),
-            "lastReturnedWith" => Some(Self::LastReturnedWith),
-            "nthReturnedWith" => Some(Self::NthReturnedWith),
-            "toThrowError" => Some(Self::ToThrowError),
-            _ => None,
-        }
-    }
-
-    fn name_with_canonical(&self) -> (&'static str, &'static str) {
-        match self {
-            Self::ToBeCalled => ("toBeCalled", "toHaveBeenCalled"),
-            Self::ToBeCalledTimes => ("toBeCalledTimes", "toHaveBeenCalledTimes"),
-            Self::ToBeCalledWith => ("toBeCalledWith", "toHaveBeenCalledWith"),
-            Self::LastCalledWith => ("lastCalledWith", "toHaveBeenLastCalledWith"),
-            Self::NthCalledWith => ("nthCalledWith", "toHaveBeenNthCalledWith"),
-            Self::ToReturn => ("toReturn", "toHaveReturned"),
-            Self::ToReturnTimes => ("toReturnTimes", "toHaveReturnedTimes"),
-            Self::ToReturnWith => ("toReturnWith", "toHaveReturnedWith"),
-            Self::LastReturnedWith => ("lastReturnedWith", "toHaveLastReturnedWith"),
-            Self::NthReturnedWith => ("nthReturnedWith", "toHaveNthReturnedWith"),
-            Self::ToThrowError => ("toThrowError", "toThrow"),
-        }
-    }
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -226,10 +190,34 @@ fn test() {
         ("expect(a).rejects.toThrowError()", None),
         ("expect(a).not.toThrowError()", None),
         ("expect(a).not['toThrowError']()", None),
+        ("expect(a).not.toBeCalled()", None),
+        ("expect(a).not.toBeCalledTimes()", None),
+        ("expect(a).not.toBeCalledWith()", None),
+        ("expect(a).not.lastCalledWith()", None),
+        ("expect(a).not.nthCalledWith()", None),
+        ("expect(a).not.toReturn()", None),
+        ("expect(a).not.toReturnTimes()", None),
+        ("expect(a).not.toReturnWith()", None),
+        ("expect(a).not.lastReturnedWith()", None),
+        ("expect(a).not.nthReturnedWith()", None),
+        ("expect(a).resolves.not.toThrowError()", None),
     ];
 
     let mut fix = vec![
         ("expect(a).toBeCalled()", "expect(a).toHaveBeenCalled()", None),
+        ("expect(a).toBeCalledTimes(1)", "expect(a).toHaveBeenCalledTimes(1)", None),
+        ("expect(a).toBeCalledWith(b)", "expect(a).toHaveBeenCalledWith(b)", None),
+        ("expect(a).lastCalledWith(b)", "expect(a).toHaveBeenLastCalledWith(b)", None),
+        ("expect(a).nthCalledWith(1, b)", "expect(a).toHaveBeenNthCalledWith(1, b)", None),
+        ("expect(a).toReturn()", "expect(a).toHaveReturned()", None),
+        ("expect(a).toReturnTimes(1)", "expect(a).toHaveReturnedTimes(1)", None),
+        ("expect(a).toReturnWith(b)", "expect(a).toHaveReturnedWith(b)", None),
+        ("expect(a).lastReturnedWith(b)", "expect(a).toHaveLastReturnedWith(b)", None),
+        ("expect(a).nthReturnedWith(1, b)", "expect(a).toHaveNthReturnedWith(1, b)", None),
+        ("expect(a).toThrowError()", "expect(a).toThrow()", None),
+        ("expect(a).not.toBeCalled()", "expect(a).not.toHaveBeenCalled()", None),
+        ("expect(a).not.toReturnWith(b)", "expect(a).not.toHaveReturnedWith(b)", None),
+        ("expect(a).rejects.not.toThrowError()", "expect(a).rejects.not.toThrow()", None),
         ("expect(a).not['toThrowError']()", "expect(a).not['toThrow']()", None),
         ("expect(a).not[`toThrowError`]()", "expect(a).not[`toThrow`]()", None),
     ];
@@ -167,7 +167,8 @@ fn check_parents<'a>(
         | AstKind::LogicalExpression(_) => {
             return check_parents(parent_node, visited, InConditional(true), ctx);
         }
-        AstKind::Function(function) => {
+        // Function expressions are checked like arrow functions, by where they are defined.
+        AstKind::Function(function) if function.is_declaration() => {
             let Some(ident) = &function.id else {
                 return InConditional(false);
             };
@@ -471,6 +472,9 @@ fn test() {
             });",
             None,
         ),
+        ("it('foo', function () { expect(1).toBe(1); });", None),
+        ("it('foo', function () { if (a) { foo(); } expect(1).toBe(1); });", None),
+        ("it('foo', () => { [1].forEach(function (x) { expect(x).toBe(1); }); });", None),
     ];
 
     let mut fail = vec![
@@ -926,6 +930,17 @@ fn test() {
             ",
             None,
         ),
+        ("it('foo', function () { if (a) { expect(1).toBe(1); } });", None),
+        (
+            "it('foo', function () { try { foo(); } catch (err) { expect(err).toBeDefined(); } });",
+            None,
+        ),
+        ("it('foo', function () { a && expect(1).toBe(1); });", None),
+        ("it('foo', function () { const b = a ? expect(1).toBe(1) : null; });", None),
+        (
+            "it('foo', () => { if (a) { [1].forEach(function (x) { expect(x).toBe(1); }); } });",
+            None,
+        ),
     ];
 
     let pass_vitest = vec![
@@ -5,8 +5,13 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{Map, phf_map};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    config::{BuiltinLintPlugins, JestVersion},
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
 
 fn deprecated_function(deprecated: &str, new: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("{deprecated:?} has been deprecated in favor of {new:?}"))
@@ -15,7 +20,7 @@ fn deprecated_function(deprecated: &str, new: &str, span: Span) -> OxcDiagnostic
 
 #[derive(Debug, Default, Clone)]
 pub struct JestConfig {
-    version: String,
+    version: Option<JestVersion>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -63,6 +68,9 @@ declare_oxc_lint!(
     /// #### `jest.genMockFromModule`
     /// This function was renamed to `createMockFromModule` in Jest 26, and is scheduled for removal in Jest 30.
     ///
+    /// The Jest version is read from the `jest.version` rule option, or else from
+    /// `settings.jest.version`. If neither is set, the latest version is assumed.
+    ///
     /// ### Why is this bad?
     ///
     /// While typically these deprecated functions are kept in the codebase for a number
@@ -81,6 +89,8 @@ declare_oxc_lint!(
     fix
 );
 
+const DEFAULT_JEST_VERSION: usize = 29;
+
 const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_map! {
     "jest.resetModuleRegistry" => (15, "jest.resetModules"),
     "jest.addMatchers" => (17, "expect.extend"),
@@ -96,18 +106,9 @@ impl Rule for NoDeprecatedFunctions {
             .get(0)
             .and_then(|v| v.get("jest"))
             .and_then(|v| v.get("version"))
-            .and_then(|v| serde_json::Value::as_str(v))
-            // Todo: Fixed Me
-            // Currently set the default version to the (maybe) latest, to help to find more problems in
-            // the codebase. In the future, the version should come from the cli option or the config files,
-            // such as `package.json` or `eslint.config.js`.
-            .unwrap_or("29");
-
-        let major: Vec<&str> = version.split('.').collect();
-
-        Self(Box::new(NoDeprecatedFunctionsConfig {
-            jest: JestConfig { version: major[0].to_string() },
-        }))
+            .and_then(|version| JestVersion::deserialize(version).ok());
+
+        Self(Box::new(NoDeprecatedFunctionsConfig { jest: JestConfig { version } }))
     }
 
     fn run<'a>(&self, node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
@@ -124,8 +125,15 @@ impl Rule for NoDeprecatedFunctions {
         }
 
         let node_name = chain.join(".");
-        // Todo: read from configuration
-        let jest_version_num: usize = self.jest.version.parse().unwrap_or(29);
+        // The version in the rule configuration takes precedence over `settings.jest.version`.
+        // Without either, assume the latest version to find as many deprecations as possible.
+        let jest_version_num = self
+            .jest
+            .version
+            .as_ref()
+            .and_then(JestVersion::major)
+            .or_else(|| ctx.settings().jest.get_version())
+            .unwrap_or(DEFAULT_JEST_VERSION);
 
         if let Some((base_version, replacement)) = DEPRECATED_FUNCTIONS_MAP.get(&node_name) {
             if jest_version_num >= *base_version {
@@ -136,6 +144,12 @@ impl Rule for NoDeprecatedFunctions {
             }
         }
     }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        // The deprecated functions are specific to Jest, so there is nothing to report when
+        // the rule is enabled without the Jest plugin.
+        ctx.plugins().builtin.contains(BuiltinLintPlugins::JEST)
+    }
 }
 
 #[test]
@@ -143,29 +157,76 @@ fn tests() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("jest", Some(serde_json::json!([{ "jest": { "version": "14" } }]))),
-        ("require('fs')", Some(serde_json::json!([{ "jest": { "version": "14" } }]))),
-        ("jest.resetModuleRegistry", Some(serde_json::json!([{ "jest": { "version": "14" } }]))),
-        ("require.requireActual", Some(serde_json::json!([{ "jest": { "version": "17" } }]))),
-        ("jest.genMockFromModule", Some(serde_json::json!([{ "jest": { "version": "25" } }]))),
-        ("jest.genMockFromModule", Some(serde_json::json!([{ "jest": { "version": "25.1.1" } }]))),
-        ("require.requireActual", Some(serde_json::json!([{ "jest": { "version": "17.2" } }]))),
+        ("jest", Some(serde_json::json!([{ "jest": { "version": "14" } }])), None),
+        ("require('fs')", Some(serde_json::json!([{ "jest": { "version": "14" } }])), None),
+        (
+            "jest.resetModuleRegistry",
+            Some(serde_json::json!([{ "jest": { "version": "14" } }])),
+            None,
+        ),
+        ("require.requireActual", Some(serde_json::json!([{ "jest": { "version": "17" } }])), None),
+        (
+            "jest.genMockFromModule",
+            Some(serde_json::json!([{ "jest": { "version": "25" } }])),
+            None,
+        ),
+        (
+            "jest.genMockFromModule",
+            Some(serde_json::json!([{ "jest": { "version": "25.1.1" } }])),
+            None,
+        ),
+        (
+            "require.requireActual",
+            Some(serde_json::json!([{ "jest": { "version": "17.2" } }])),
+            None,
+        ),
+        (
+            "jest.resetModuleRegistry",
+            Some(serde_json::json!([{ "jest": { "version": 14 } }])),
+            None,
+        ),
+        (
+            "jest.resetModuleRegistry",
+            None,
+            Some(serde_json::json!({ "settings": { "jest": { "version": 14 } } })),
+        ),
+        (
+            "jest.genMockFromModule",
+            None,
+            Some(serde_json::json!({ "settings": { "jest": { "version": "25.1.1" } } })),
+        ),
+        (
+            "jest.genMockFromModule",
+            Some(serde_json::json!([{ "jest": { "version": "25" } }])),
+            Some(serde_json::json!({ "settings": { "jest": { "version": 27 } } })),
+        ),
     ];
 
     let fail = vec![
-        ("jest.resetModuleRegistry", None),
+        ("jest.resetModuleRegistry", None, None),
         // replace with `jest.resetModules` in Jest 15
-        ("jest.resetModuleRegistry", Some(serde_json::json!([{ "jest": { "version": "16" }}]))),
+        (
+            "jest.resetModuleRegistry",
+            Some(serde_json::json!([{ "jest": { "version": "16" }}])),
+            None,
+        ),
         // replace with `jest.requireMock` in Jest 17.
-        ("jest.addMatchers", Some(serde_json::json!([{ "jest": { "version": "18" }}]))),
+        ("jest.addMatchers", Some(serde_json::json!([{ "jest": { "version": "18" }}])), None),
         // replace with `jest.requireMock` in Jest 21.
-        ("require.requireMock", Some(serde_json::json!([{ "jest": { "version": "22" }}]))),
+        ("require.requireMock", Some(serde_json::json!([{ "jest": { "version": "22" }}])), None),
         // replace with `jest.requireActual` in Jest 21.
-        ("require.requireActual", Some(serde_json::json!([{ "jest": { "version": "22" }}]))),
+        ("require.requireActual", Some(serde_json::json!([{ "jest": { "version": "22" }}])), None),
         // replace with `jest.advanceTimersByTime` in Jest 22
-        ("jest.runTimersToTime", Some(serde_json::json!([{ "jest": { "version": "23" }}]))),
+        ("jest.runTimersToTime", Some(serde_json::json!([{ "jest": { "version": "23" }}])), None),
         // replace with `jest.createMockFromModule` in Jest 26
-        ("jest.genMockFromModule", Some(serde_json::json!([{ "jest": { "version": "27" }}]))),
+        ("jest.genMockFromModule", Some(serde_json::json!([{ "jest": { "version": "27" }}])), None),
+        (
+            "jest.addMatchers",
+            None,
+            Some(serde_json::json!({ "settings": { "jest": { "version": 18 } } })),
+        ),
+        ("jest.genMockFromModule", None, Some(serde_json::json!({ "settings": { "jest": {} } }))),
+        ("jest.addMatchers", Some(serde_json::json!([{ "jest": { "version": 18 } }])), None),
     ];
 
     let fix = vec![
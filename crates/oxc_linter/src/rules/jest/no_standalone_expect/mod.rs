@@ -57,6 +57,31 @@ declare_oxc_lint!(
     ///     expect(1).toBe(1);
     /// });
     /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// describe('a test', () => {
+    ///     it('an it', () => {
+    ///         expect(1).toBe(1);
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### additionalTestBlockFunctions
+    ///
+    /// An array of function names that should also be treated as test blocks,
+    /// e.g. `each.test` or custom wrappers around `it`.
+    ///
+    /// ```json
+    /// {
+    ///   "jest/no-standalone-expect": [
+    ///     "error",
+    ///     { "additionalTestBlockFunctions": ["each.test"] }
+    ///   ]
+    /// }
+    /// ```
     NoStandaloneExpect,
     jest,
     correctness
@@ -44,6 +44,15 @@ declare_oxc_lint!(
     /// xdescribe('foo'); // invalid
     /// ```
     ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// it.only('foo');
+    /// describe.only('foo');
+    /// it.skip('foo');
+    /// test.skip('foo');
+    /// describe.skip('foo');
+    /// ```
+    ///
     /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/no-test-prefixes.md),
     /// to use it, add the following configuration to your `.eslintrc.json`:
     ///
@@ -217,6 +226,9 @@ fn test() {
         // ("ftest('foo', () => {})", "test.only('foo', () => {})"),
         ("xit('foo', () => {})", "it.skip('foo', () => {})"),
         ("fit('foo', () => {})", "it.only('foo', () => {})"),
+        ("xit.each([])('foo', () => {})", "it.skip.each([])('foo', () => {})"),
+        ("xtest.each``('foo', () => {})", "test.skip.each``('foo', () => {})"),
+        ("xdescribe.each([])('foo', () => {})", "describe.skip.each([])('foo', () => {})"),
     ];
 
     Tester::new(NoTestPrefixes::NAME, NoTestPrefixes::PLUGIN, pass, fail)
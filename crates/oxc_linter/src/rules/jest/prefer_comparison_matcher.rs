@@ -19,7 +19,7 @@ use crate::{
 
 fn use_to_be_comparison(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Suggest using the built-in comparison matchers")
-        .with_help(format!("Prefer using `{x0:?}` instead"))
+        .with_help(format!("Prefer using `{x0}` instead"))
         .with_label(span1)
 }
 
@@ -4,7 +4,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     context::LintContext,
@@ -91,30 +91,22 @@ impl Rule for PreferExpectResolves {
         let Some(Expression::CallExpression(call_expr)) = jest_expect_fn_call.head.parent else {
             return;
         };
-        let Some(argument) = call_expr.arguments.first() else {
-            return;
-        };
-        let Argument::AwaitExpression(await_expr) = argument else {
-            return;
-        };
-        let Some(ident) = call_expr.callee.get_identifier_reference() else {
+        let Some(Expression::AwaitExpression(await_expr)) = call_expr
+            .arguments
+            .first()
+            .and_then(Argument::as_expression)
+            .map(Expression::without_parentheses)
+        else {
             return;
         };
         ctx.diagnostic_with_fix(expect_resolves(await_expr.span), |fixer| {
-            let offset = match &await_expr.argument {
-                Expression::CallExpression(call_expr) => call_expr.span.start - ident.span.end,
-                Expression::Identifier(promise_ident) => promise_ident.span.start - ident.span.end,
-                _ => 0,
-            };
-            let arg_span = Span::new(
-                call_expr.span.start + (ident.span.end - ident.span.start) + offset,
-                await_expr.span.end,
-            );
-            let local = jest_expect_fn_call.local.as_ref();
-            let argument = fixer.source_range(arg_span);
-            let mut code = String::with_capacity(local.len() + argument.len() + 17);
+            // `expect(await promise)` -> `await expect(promise).resolves`, which keeps any
+            // modifiers and the matcher following the `expect` call, e.g. `.not.toBe(x)`.
+            let callee = fixer.source_range(call_expr.callee.span());
+            let argument = fixer.source_range(await_expr.argument.span());
+            let mut code = String::with_capacity(callee.len() + argument.len() + 18);
             code.push_str("await ");
-            code.push_str(local);
+            code.push_str(callee);
             code.push('(');
             code.push_str(argument);
             code.push_str(").resolves");
@@ -159,6 +151,9 @@ fn tests() {
             None,
         ),
         ("expect().nothing();", None),
+        ("it('is true', () => { expect(myPromise).toBe(true); });", None),
+        ("it('is true', () => { expect(someValue()).not.toBe(false); });", None),
+        ("it('is true', async () => { expect(compute(await someValue())).toBe(true); });", None),
     ];
 
     let fail = vec![
@@ -190,6 +185,13 @@ fn tests() {
             ",
             None,
         ),
+        ("it('is true', async () => { expect(await myPromise).not.toBe(false); });", None),
+        (
+            "it('is true', async () => { expect(await (await getClient()).fetch()).toBe(true); });",
+            None,
+        ),
+        ("it('is true', async () => { expect((await myPromise)).toBe(true); });", None),
+        ("it('is true', async () => { expect(await obj.promise).toEqual(1); });", None),
     ];
 
     let fix = vec![
@@ -240,6 +242,26 @@ fn tests() {
             ",
             None,
         ),
+        (
+            "it('is true', async () => { expect(await myPromise).not.toBe(false); });",
+            "it('is true', async () => { await expect(myPromise).resolves.not.toBe(false); });",
+            None,
+        ),
+        (
+            "it('is true', async () => { expect(await (await getClient()).fetch()).toBe(true); });",
+            "it('is true', async () => { await expect((await getClient()).fetch()).resolves.toBe(true); });",
+            None,
+        ),
+        (
+            "it('is true', async () => { expect((await myPromise)).toBe(true); });",
+            "it('is true', async () => { await expect(myPromise).resolves.toBe(true); });",
+            None,
+        ),
+        (
+            "it('is true', async () => { expect(await obj.promise).toEqual(1); });",
+            "it('is true', async () => { await expect(obj.promise).resolves.toEqual(1); });",
+            None,
+        ),
     ];
 
     Tester::new(PreferExpectResolves::NAME, PreferExpectResolves::PLUGIN, pass, fail)
@@ -180,9 +180,12 @@ fn tests() {
         ("test.skip('i need to write this test', function() {});", None),
         ("test[`skip`]('i need to write this test', function() {});", None),
         ("test[`skip`]('i need to write this test', function() {});", None),
+        ("it('i need to write this test');", None),
+        ("it.skip('i need to write this test');", None),
     ];
 
     let fix = vec![
+        ("it('i need to write this test');", "it.todo('i need to write this test');", None),
         (
             "test.skip('i need to write this test');",
             "test.todo('i need to write this test');",
@@ -1,16 +1,18 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::ScopeId;
+use oxc_semantic::NodeId;
 use oxc_span::Span;
 use rustc_hash::FxHashMap;
 
 use crate::{
+    AstNode,
     context::LintContext,
     rule::Rule,
     utils::{
         JestFnKind, JestGeneralFnKind, ParsedGeneralJestFnCall, ParsedJestFnCallNew,
-        PossibleJestNode, collect_possible_jest_call_node, parse_jest_fn_call,
+        PossibleJestNode, collect_possible_jest_call_node, is_type_of_jest_fn_call,
+        parse_jest_fn_call,
     },
 };
 
@@ -122,12 +124,17 @@ impl Rule for RequireTopLevelDescribe {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let mut describe_contexts: FxHashMap<ScopeId, usize> = FxHashMap::default();
+        let mut top_level_describes = 0;
         let mut possibles_jest_nodes = collect_possible_jest_call_node(ctx);
         possibles_jest_nodes.sort_by_key(|n| n.node.id());
+        let id_nodes_mapping =
+            possibles_jest_nodes.iter().fold(FxHashMap::default(), |mut acc, cur| {
+                acc.entry(cur.node.id()).or_insert(cur);
+                acc
+            });
 
         for possible_jest_node in &possibles_jest_nodes {
-            self.run(possible_jest_node, &mut describe_contexts, ctx);
+            self.run(possible_jest_node, &id_nodes_mapping, &mut top_level_describes, ctx);
         }
     }
 }
@@ -136,13 +143,11 @@ impl RequireTopLevelDescribe {
     fn run<'a>(
         &self,
         possible_jest_node: &PossibleJestNode<'a, '_>,
-        describe_contexts: &mut FxHashMap<ScopeId, usize>,
+        id_nodes_mapping: &FxHashMap<NodeId, &PossibleJestNode<'a, '_>>,
+        top_level_describes: &mut usize,
         ctx: &LintContext<'a>,
     ) {
         let node = possible_jest_node.node;
-        let scopes = ctx.scoping();
-        let is_top = scopes.scope_flags(node.scope_id()).is_top();
-
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
         };
@@ -153,6 +158,8 @@ impl RequireTopLevelDescribe {
             return;
         };
 
+        let is_top = !is_inside_describe(node, id_nodes_mapping, ctx);
+
         match kind {
             JestFnKind::General(JestGeneralFnKind::Test) => {
                 if is_top {
@@ -169,19 +176,15 @@ impl RequireTopLevelDescribe {
                     return;
                 }
 
-                let Some((_, count)) = describe_contexts.get_key_value(&node.scope_id()) else {
-                    describe_contexts.insert(node.scope_id(), 1);
-                    return;
-                };
-
-                if count >= &self.max_number_of_top_level_describes {
+                let count = *top_level_describes;
+                if count >= self.max_number_of_top_level_describes {
                     ctx.diagnostic(too_many_describes(
                         self.max_number_of_top_level_describes,
-                        if *count == 1 { "" } else { "s" },
+                        if count == 1 { "" } else { "s" },
                         call_expr.span,
                     ));
                 } else {
-                    describe_contexts.insert(node.scope_id(), count + 1);
+                    *top_level_describes += 1;
                 }
             }
             _ => (),
@@ -189,6 +192,28 @@ impl RequireTopLevelDescribe {
     }
 }
 
+/// Checks whether `node` has an enclosing `describe` call, no matter how deeply
+/// it is nested in blocks or helper functions.
+fn is_inside_describe<'a>(
+    node: &AstNode<'a>,
+    id_nodes_mapping: &FxHashMap<NodeId, &PossibleJestNode<'a, '_>>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    ctx.nodes().ancestors(node.id()).any(|ancestor| {
+        let AstKind::CallExpression(call_expr) = ancestor.kind() else {
+            return false;
+        };
+        id_nodes_mapping.get(&ancestor.id()).is_some_and(|jest_node| {
+            is_type_of_jest_fn_call(
+                call_expr,
+                jest_node,
+                ctx,
+                &[JestFnKind::General(JestGeneralFnKind::Describe)],
+            )
+        })
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -291,6 +316,37 @@ fn test() {
             ",
             Some(serde_json::json!({ "maxNumberOfTopLevelDescribes": 1 })),
         ),
+        (
+            "
+                describe('one', () => {
+                    describe('two', () => {});
+                    describe('three', () => {});
+                });
+            ",
+            Some(serde_json::json!([{ "maxNumberOfTopLevelDescribes": 1 }])),
+        ),
+        (
+            "
+                describe('test suite', () => {
+                    if (process.env.CI) {
+                        test('my test', () => {});
+                    }
+                });
+            ",
+            None,
+        ),
+        (
+            "
+                describe('test suite', () => {
+                    function defineTests() {
+                        beforeEach(() => {});
+                        it('my test', () => {});
+                    }
+                    defineTests();
+                });
+            ",
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -392,6 +448,8 @@ fn test() {
             ",
             Some(serde_json::json!([{ "maxNumberOfTopLevelDescribes": 1 }])),
         ),
+        ("if (process.env.CI) { test('my test', () => {}); }", None),
+        ("function defineTests() { beforeEach(() => {}); }", None),
     ];
 
     Tester::new(RequireTopLevelDescribe::NAME, RequireTopLevelDescribe::PLUGIN, pass, fail)
@@ -1,6 +1,6 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression, FunctionBody, Statement},
+    ast::{Argument, FunctionBody, Statement},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -135,7 +135,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
             let Some(body) = &fn_expr.body else {
                 return;
             };
-            if let Some(span) = find_first_return_stmt_span(body) {
+            for span in return_stmt_spans(body) {
                 diagnostic(ctx, span, Message::UnexpectedReturnInDescribe);
             }
         }
@@ -149,17 +149,12 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
                 diagnostic(ctx, arrow_expr.span, Message::UnexpectedDescribeArgument);
             }
 
-            if arrow_expr.expression && !arrow_expr.body.statements.is_empty() {
-                let stmt = &arrow_expr.body.statements[0];
-                let Statement::ExpressionStatement(expr_stmt) = stmt else {
-                    return;
-                };
-                if let Expression::CallExpression(call_expr) = &expr_stmt.expression {
-                    diagnostic(ctx, call_expr.span, Message::UnexpectedReturnInDescribe);
-                }
+            // `() => value` implicitly returns `value`
+            if let Some(expr) = arrow_expr.get_expression() {
+                diagnostic(ctx, expr.span(), Message::UnexpectedReturnInDescribe);
             }
 
-            if let Some(span) = find_first_return_stmt_span(&arrow_expr.body) {
+            for span in return_stmt_spans(&arrow_expr.body) {
                 diagnostic(ctx, span, Message::UnexpectedReturnInDescribe);
             }
         }
@@ -167,8 +162,8 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
     }
 }
 
-fn find_first_return_stmt_span(function_body: &FunctionBody) -> Option<Span> {
-    function_body.statements.iter().find_map(|stmt| {
+fn return_stmt_spans(function_body: &FunctionBody) -> impl Iterator<Item = Span> {
+    function_body.statements.iter().filter_map(|stmt| {
         if let Statement::ReturnStatement(return_stmt) = stmt {
             Some(return_stmt.span)
         } else {
@@ -357,6 +352,11 @@ fn test() {
         ("describe('foo', function (done) {})", None),
         ("describe('foo', function (one, two, three) {})", None),
         ("describe('foo', async function (done) {})", None),
+        ("describe('foo', () => 42)", None),
+        ("describe('foo', async () => await setup())", None),
+        ("describe('foo', function () { if (skip) {} return; return 42; })", None),
+        ("describe('foo', callback)", None),
+        ("describe.only('foo', 42)", None),
     ];
 
     let pass_vitest = vec![
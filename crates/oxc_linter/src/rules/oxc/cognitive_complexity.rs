@@ -0,0 +1,429 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BreakStatement, CatchClause, ConditionalExpression,
+        ContinueStatement, DoWhileStatement, Expression, ForInStatement, ForOfStatement,
+        ForStatement, Function, IfStatement, LogicalExpression, Statement, SwitchStatement,
+        WhileStatement,
+    },
+};
+use oxc_ast_visit::{
+    Visit,
+    walk::{walk_arrow_function_expression, walk_catch_clause, walk_function},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use oxc_syntax::operator::LogicalOperator;
+
+use crate::{
+    AstNode,
+    ast_util::{get_function_name_span, get_function_name_with_kind, is_function_node},
+    context::LintContext,
+    rule::Rule,
+};
+
+fn cognitive_complexity_diagnostic(
+    name: &str,
+    complexity: usize,
+    max: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The {name} has a cognitive complexity of {complexity}. Maximum allowed is {max}."
+    ))
+    .with_help("Reduce the nesting and branching of the function, e.g. by extracting parts of it into smaller functions.")
+    .with_label(span)
+}
+
+const DEFAULT_MAX_COGNITIVE_COMPLEXITY: usize = 15;
+
+#[derive(Debug, Clone)]
+pub struct CognitiveComplexity {
+    max: usize,
+}
+
+impl Default for CognitiveComplexity {
+    fn default() -> Self {
+        Self { max: DEFAULT_MAX_COGNITIVE_COMPLEXITY }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum cognitive complexity of functions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Cognitive complexity measures how hard the control flow of a function is to understand.
+    /// Unlike cyclomatic complexity, which counts the paths through a function, it penalizes
+    /// nesting: a condition inside a loop inside another condition is much harder to follow
+    /// than the same number of conditions one after another. Functions with a high cognitive
+    /// complexity are hard to read and to maintain.
+    ///
+    /// The complexity of a function is computed as in the
+    /// [SonarSource metric](https://www.sonarsource.com/docs/CognitiveComplexity.pdf):
+    ///
+    /// - `if`, `?:`, `switch`, `for`, `for...in`, `for...of`, `while`, `do...while` and `catch`
+    ///   add 1, plus 1 for each level they are nested in.
+    /// - `else if` and `else` add 1.
+    /// - Every sequence of `&&`, `||` or `??` operators adds 1, so `a && b && c` adds 1, while
+    ///   `a && b || c` adds 2.
+    /// - `break` and `continue` with a label add 1.
+    ///
+    /// Nested functions don't add to the complexity by themselves, but increase the nesting of
+    /// everything in them, which counts towards the complexity of the outermost function. Only
+    /// functions which aren't nested in another function are reported.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "max": 5 }`:
+    /// ```js
+    /// function process(items) {
+    ///   for (const item of items) { // +1
+    ///     if (item.enabled) { // +2 (nesting = 1)
+    ///       if (item.ready && item.valid) { // +3 (nesting = 2), +1 for `&&`
+    ///         run(item);
+    ///       }
+    ///     }
+    ///   }
+    /// } // Cognitive complexity 7
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "max": 5 }`:
+    /// ```js
+    /// function process(items) {
+    ///   for (const item of items) { // +1
+    ///     if (isRunnable(item)) { // +2 (nesting = 1)
+    ///       run(item);
+    ///     }
+    ///   }
+    /// } // Cognitive complexity 3
+    ///
+    /// function isRunnable(item) {
+    ///   return item.enabled && item.ready && item.valid; // +1
+    /// } // Cognitive complexity 1
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### max
+    ///
+    /// `{ type: number, default: 15 }`
+    ///
+    /// The maximum allowed cognitive complexity of a function.
+    ///
+    /// Example:
+    /// ```json
+    /// "oxc/cognitive-complexity": ["error", { "max": 10 }]
+    /// ```
+    CognitiveComplexity,
+    oxc,
+    pedantic
+);
+
+impl Rule for CognitiveComplexity {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let max = value
+            .get(0)
+            .and_then(|config| config.get("max"))
+            .and_then(serde_json::Value::as_u64)
+            .map_or(DEFAULT_MAX_COGNITIVE_COMPLEXITY, |n| n as usize);
+
+        Self { max }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let body = match node.kind() {
+            AstKind::Function(func) => {
+                let Some(body) = &func.body else {
+                    return;
+                };
+                &**body
+            }
+            AstKind::ArrowFunctionExpression(arrow) => &*arrow.body,
+            _ => return,
+        };
+        // Nested functions are part of the complexity of the function containing them.
+        if ctx.nodes().ancestors(node.id()).any(is_function_node) {
+            return;
+        }
+
+        let mut visitor = ComplexityVisitor::default();
+        visitor.visit_function_body(body);
+        if visitor.complexity <= self.max {
+            return;
+        }
+
+        let parent = ctx.nodes().parent_node(node.id());
+        let name = get_function_name_with_kind(node, parent);
+        ctx.diagnostic(cognitive_complexity_diagnostic(
+            &name,
+            visitor.complexity,
+            self.max,
+            get_function_name_span(node, parent),
+        ));
+    }
+}
+
+/// Computes the cognitive complexity of a function body.
+#[derive(Default)]
+struct ComplexityVisitor {
+    complexity: usize,
+    nesting: usize,
+}
+
+impl<'a> ComplexityVisitor {
+    /// Adds the increment of a structure which is weighted by how deeply it is nested.
+    fn increment_nested(&mut self) {
+        self.complexity += 1 + self.nesting;
+    }
+
+    fn visit_nested_statement(&mut self, stmt: &Statement<'a>) {
+        self.nesting += 1;
+        self.visit_statement(stmt);
+        self.nesting -= 1;
+    }
+}
+
+impl<'a> Visit<'a> for ComplexityVisitor {
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.increment_nested();
+        self.visit_expression(&it.test);
+        self.visit_nested_statement(&it.consequent);
+
+        let mut alternate = it.alternate.as_ref();
+        while let Some(stmt) = alternate {
+            // `else if` and `else` aren't weighted by nesting.
+            self.complexity += 1;
+            if let Statement::IfStatement(else_if) = stmt {
+                self.visit_expression(&else_if.test);
+                self.visit_nested_statement(&else_if.consequent);
+                alternate = else_if.alternate.as_ref();
+            } else {
+                self.visit_nested_statement(stmt);
+                alternate = None;
+            }
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.increment_nested();
+        self.visit_expression(&it.test);
+        self.nesting += 1;
+        self.visit_expression(&it.consequent);
+        self.visit_expression(&it.alternate);
+        self.nesting -= 1;
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        self.increment_nested();
+        self.visit_expression(&it.discriminant);
+        self.nesting += 1;
+        self.visit_switch_cases(&it.cases);
+        self.nesting -= 1;
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.increment_nested();
+        if let Some(init) = &it.init {
+            self.visit_for_statement_init(init);
+        }
+        if let Some(test) = &it.test {
+            self.visit_expression(test);
+        }
+        if let Some(update) = &it.update {
+            self.visit_expression(update);
+        }
+        self.visit_nested_statement(&it.body);
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.increment_nested();
+        self.visit_for_statement_left(&it.left);
+        self.visit_expression(&it.right);
+        self.visit_nested_statement(&it.body);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.increment_nested();
+        self.visit_for_statement_left(&it.left);
+        self.visit_expression(&it.right);
+        self.visit_nested_statement(&it.body);
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.increment_nested();
+        self.visit_expression(&it.test);
+        self.visit_nested_statement(&it.body);
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.increment_nested();
+        self.visit_nested_statement(&it.body);
+        self.visit_expression(&it.test);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.increment_nested();
+        self.nesting += 1;
+        walk_catch_clause(self, it);
+        self.nesting -= 1;
+    }
+
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_continue_statement(&mut self, it: &ContinueStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        let mut operators = vec![];
+        let mut operands = vec![];
+        flatten_logical_expression(it, &mut operators, &mut operands);
+
+        // Each sequence of the same operator adds 1.
+        self.complexity += 1 + operators.windows(2).filter(|pair| pair[0] != pair[1]).count();
+        for operand in operands {
+            self.visit_expression(operand);
+        }
+    }
+
+    // Nested functions don't add to the complexity, but increase the nesting.
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        self.nesting += 1;
+        walk_function(self, it, flags);
+        self.nesting -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.nesting += 1;
+        walk_arrow_function_expression(self, it);
+        self.nesting -= 1;
+    }
+}
+
+/// Collects the operators of `a && b || c`-like chains in source order, along with the
+/// operands which aren't logical expressions themselves.
+fn flatten_logical_expression<'b, 'a>(
+    expr: &'b LogicalExpression<'a>,
+    operators: &mut Vec<LogicalOperator>,
+    operands: &mut Vec<&'b Expression<'a>>,
+) {
+    flatten_logical_operand(&expr.left, operators, operands);
+    operators.push(expr.operator);
+    flatten_logical_operand(&expr.right, operators, operands);
+}
+
+fn flatten_logical_operand<'b, 'a>(
+    expr: &'b Expression<'a>,
+    operators: &mut Vec<LogicalOperator>,
+    operands: &mut Vec<&'b Expression<'a>>,
+) {
+    match expr.without_parentheses() {
+        Expression::LogicalExpression(logical) => {
+            flatten_logical_expression(logical, operators, operands);
+        }
+        expr => operands.push(expr),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { return 1; }", None),
+        (
+            "
+            function flat(a, b, c, d, e) {
+                if (a) { one(); }
+                if (b) { two(); }
+                if (c) { three(); }
+                if (d) { four(); }
+                if (e) { five(); }
+                for (const x of a) { six(x); }
+                while (b.next()) { seven(); }
+                switch (c) { case 1: eight(); }
+                return d ? 9 : 10;
+            }
+            ",
+            None,
+        ),
+        ("function foo(a) { if (a) {} else if (b) {} else {} }", Some(json!([{ "max": 3 }]))),
+        ("function foo() { return a && b && c; }", Some(json!([{ "max": 1 }]))),
+        ("function foo() { return a ? b ? 1 : 2 : 3; }", Some(json!([{ "max": 3 }]))),
+        ("function foo() { try { bar(); } finally { baz(); } }", Some(json!([{ "max": 0 }]))),
+        ("function foo() { for (;;) { break; } }", Some(json!([{ "max": 1 }]))),
+        (
+            "function foo() { return function () { if (a) { if (b) {} } }; }",
+            Some(json!([{ "max": 5 }])),
+        ),
+        ("const foo = () => a ? 1 : 2;", Some(json!([{ "max": 1 }]))),
+    ];
+
+    let fail = vec![
+        (
+            "
+            function process(items) {
+                for (const item of items) {
+                    if (item.enabled) {
+                        for (const child of item.children) {
+                            if (child.ready && child.valid) {
+                                while (child.next()) {
+                                    if (child.done) {
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            ",
+            None,
+        ),
+        ("function foo(a) { if (a) {} else if (b) {} else {} }", Some(json!([{ "max": 2 }]))),
+        ("function foo() { return a && b || c && d; }", Some(json!([{ "max": 2 }]))),
+        ("function foo() { return (a && (b || c) && d) ?? e; }", Some(json!([{ "max": 3 }]))),
+        ("function foo() { return a ? b ? 1 : 2 : 3; }", Some(json!([{ "max": 2 }]))),
+        ("function foo(x) { switch (x) { case 1: if (y) {} } }", Some(json!([{ "max": 2 }]))),
+        (
+            "function foo() { try { if (a) {} } catch (e) { if (b) {} } }",
+            Some(json!([{ "max": 3 }])),
+        ),
+        (
+            "function foo() { outer: for (;;) { for (;;) { continue outer; } } }",
+            Some(json!([{ "max": 3 }])),
+        ),
+        ("function foo() { do { if (a) {} } while (b && c); }", Some(json!([{ "max": 3 }]))),
+        (
+            "function foo() { return function bar() { if (a) { if (b) {} } }; }",
+            Some(json!([{ "max": 2 }])),
+        ),
+        ("const foo = () => { if (a) { if (b) { if (c) {} } } };", Some(json!([{ "max": 5 }]))),
+        ("class A { foo() { if (a) { for (x in y) {} } } }", Some(json!([{ "max": 2 }]))),
+        ("foo(function () { if (a) {} });", Some(json!([{ "max": 0 }]))),
+        (
+            "function foo() { return function () { if (a) { if (b) {} } }; }",
+            Some(json!([{ "max": 4 }])),
+        ),
+        (
+            "function foo(a) { if (a) { a.forEach((b) => b ? 1 : 2); } }",
+            Some(json!([{ "max": 3 }])),
+        ),
+    ];
+
+    Tester::new(CognitiveComplexity::NAME, CognitiveComplexity::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
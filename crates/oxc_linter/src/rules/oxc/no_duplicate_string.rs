@@ -0,0 +1,251 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_duplicate_string_diagnostic(first: Span, others: &[Span]) -> OxcDiagnostic {
+    let count = others.len() + 1;
+    let labels = std::iter::once(first.primary_label("It is first used here"))
+        .chain(others.iter().copied().map(LabeledSpan::underline));
+
+    OxcDiagnostic::warn(format!(
+        "Define a constant instead of duplicating this literal {count} times."
+    ))
+    .with_help("Extract the string to a constant and reuse it.")
+    .with_labels(labels)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateString(Box<NoDuplicateStringConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoDuplicateStringConfig {
+    threshold: usize,
+    min_length: usize,
+}
+
+impl std::ops::Deref for NoDuplicateString {
+    type Target = NoDuplicateStringConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoDuplicateStringConfig {
+    fn default() -> Self {
+        Self { threshold: 3, min_length: 10 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow string literals which are duplicated in a file.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Duplicated string literals make refactoring error-prone, as every occurrence must be
+    /// updated. A constant can be changed in a single place, and its name documents what the
+    /// string means.
+    ///
+    /// Strings shorter than `minLength` are ignored, as well as strings which can't be
+    /// replaced by a constant: module sources, directives, property keys, literal types, and
+    /// JSX attribute values.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function run() {
+    ///   prepare('action1 action2');
+    ///   execute('action1 action2');
+    ///   release('action1 action2');
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const ACTIONS = 'action1 action2';
+    ///
+    /// function run() {
+    ///   prepare(ACTIONS);
+    ///   execute(ACTIONS);
+    ///   release(ACTIONS);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### threshold
+    ///
+    /// `{ type: number, default: 3 }`
+    ///
+    /// The number of occurrences of a string from which it is reported.
+    ///
+    /// #### minLength
+    ///
+    /// `{ type: number, default: 10 }`
+    ///
+    /// The minimum length of the reported strings.
+    ///
+    /// Example:
+    /// ```json
+    /// "oxc/no-duplicate-string": [
+    ///   "error",
+    ///   { "threshold": 2, "minLength": 5 }
+    /// ]
+    /// ```
+    NoDuplicateString,
+    oxc,
+    pedantic
+);
+
+impl Rule for NoDuplicateString {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = NoDuplicateStringConfig::default();
+        let config = value.get(0);
+        let get_usize = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_u64)
+                .map(|n| n as usize)
+        };
+
+        Self(Box::new(NoDuplicateStringConfig {
+            threshold: get_usize("threshold").unwrap_or(default.threshold),
+            min_length: get_usize("minLength").unwrap_or(default.min_length),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let mut occurrences: FxHashMap<&str, Vec<Span>> = FxHashMap::default();
+
+        for node in ctx.nodes() {
+            let AstKind::StringLiteral(literal) = node.kind() else {
+                continue;
+            };
+            if literal.value.chars().count() < self.min_length {
+                continue;
+            }
+            if is_ignored(ctx.nodes().parent_kind(node.id()), literal.span) {
+                continue;
+            }
+            occurrences.entry(literal.value.as_str()).or_default().push(literal.span);
+        }
+
+        let mut duplicates = occurrences
+            .into_values()
+            .filter(|spans| spans.len() >= self.threshold.max(2))
+            .collect::<Vec<_>>();
+        // Report in source order.
+        duplicates.sort_unstable_by_key(|spans| spans[0].start);
+
+        for spans in duplicates {
+            ctx.diagnostic(no_duplicate_string_diagnostic(spans[0], &spans[1..]));
+        }
+    }
+}
+
+/// Whether a string literal with `span` and `parent` can't be replaced by a constant.
+fn is_ignored(parent: AstKind, span: Span) -> bool {
+    match parent {
+        AstKind::ImportDeclaration(_)
+        | AstKind::ImportExpression(_)
+        | AstKind::ImportAttribute(_)
+        | AstKind::ImportSpecifier(_)
+        | AstKind::ExportNamedDeclaration(_)
+        | AstKind::ExportAllDeclaration(_)
+        | AstKind::ExportSpecifier(_)
+        | AstKind::TSExternalModuleReference(_)
+        | AstKind::TSModuleDeclaration(_)
+        | AstKind::Directive(_)
+        | AstKind::TSLiteralType(_)
+        | AstKind::TSEnumMember(_)
+        | AstKind::JSXAttribute(_) => true,
+        AstKind::ObjectProperty(prop) => prop.key.span() == span,
+        AstKind::PropertyDefinition(prop) => prop.key.span() == span,
+        AstKind::MethodDefinition(method) => method.key.span() == span,
+        AstKind::AccessorProperty(prop) => prop.key.span() == span,
+        AstKind::TSPropertySignature(prop) => prop.key.span() == span,
+        AstKind::TSMethodSignature(method) => method.key.span() == span,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("foo('some string'); bar('some string');", None),
+        ("foo('some string'); bar('some string'); baz('other string');", None),
+        ("foo('short'); bar('short'); baz('short');", None),
+        ("foo('12345678'); bar('12345678'); baz('12345678');", None),
+        (
+            "import a from 'some/module/path'; import b from 'some/module/path'; import c from 'some/module/path';",
+            None,
+        ),
+        (
+            "export * from 'some/module/path'; export { a } from 'some/module/path'; const m = import('some/module/path');",
+            None,
+        ),
+        (
+            "const a = { 'some property': 1 }; const b = { 'some property': 2 }; const c = { 'some property': 3 };",
+            None,
+        ),
+        (
+            "class A { 'some property' = 1; 'some method'() {} } class B { 'some property' = 1; 'some method'() {} } class C { 'some property' = 1; 'some method'() {} }",
+            None,
+        ),
+        (
+            "type A = 'some literal type'; type B = 'some literal type'; type C = 'some literal type';",
+            None,
+        ),
+        (
+            "interface A { 'some property': 1; 'some method'(): void } interface B { 'some property': 1; 'some method'(): void } interface C { 'some property': 1; 'some method'(): void }",
+            None,
+        ),
+        (
+            "function a() { 'use something'; } function b() { 'use something'; } function c() { 'use something'; }",
+            None,
+        ),
+        (
+            "<div className='some class name' />; <div className='some class name' />; <div className='some class name' />",
+            None,
+        ),
+        (
+            "foo('some string'); bar('some string'); baz('some string');",
+            Some(json!([{ "threshold": 4 }])),
+        ),
+        (
+            "foo('some string'); bar('some string'); baz('some string');",
+            Some(json!([{ "minLength": 12 }])),
+        ),
+        ("foo('some string');", Some(json!([{ "threshold": 1 }]))),
+    ];
+
+    let fail = vec![
+        ("foo('some string'); bar('some string'); baz('some string');", None),
+        ("foo('some string', 'some string', 'some string', 'some string');", None),
+        (
+            "const a = { key: 'some string' }; const b = { key: 'some string' }; const c = { key: 'some string' };",
+            None,
+        ),
+        ("foo('some string'); bar(\"some string\"); baz(`some string`); qux('some string');", None),
+        (
+            "foo('some string'); bar('some string'); foo('other string'); bar('other string'); baz('other string'); baz('some string');",
+            None,
+        ),
+        ("foo('some string'); bar('some string');", Some(json!([{ "threshold": 2 }]))),
+        ("foo('short'); bar('short'); baz('short');", Some(json!([{ "minLength": 5 }]))),
+        ("foo('åäöåäöåäöå'); bar('åäöåäöåäöå'); baz('åäöåäöåäöå');", None),
+    ];
+
+    Tester::new(NoDuplicateString::NAME, NoDuplicateString::PLUGIN, pass, fail).test_and_snapshot();
+}
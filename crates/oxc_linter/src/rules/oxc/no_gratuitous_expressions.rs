@@ -0,0 +1,279 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, IdentifierReference, LogicalOperator, Statement, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_gratuitous_expressions_diagnostic(
+    name: &str,
+    is_truthy: bool,
+    value: bool,
+    span: Span,
+    guard_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("This condition is always {value}."))
+        .with_help(format!(
+            "`{name}` is already known to be {} here. Remove the redundant condition.",
+            if is_truthy { "truthy" } else { "falsy" }
+        ))
+        .with_labels([
+            span.primary_label(format!("always {value}")),
+            guard_span.label(format!("`{name}` is checked here")),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoGratuitousExpressions;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow conditions which are always `true` or always `false` because of a preceding
+    /// condition on the same variable.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A condition which is already guaranteed by an enclosing or preceding check is either
+    /// redundant, or a sign that the wrong variable is checked. Either way, one of its branches
+    /// is dead code.
+    ///
+    /// Only conditions on a variable, e.g. `x` or `!x`, which is never reassigned are checked.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function foo(a) {
+    ///   if (a) {
+    ///     if (a) {
+    ///       bar();
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// function foo(a) {
+    ///   if (!a) {
+    ///     return;
+    ///   }
+    ///   if (a) {
+    ///     bar();
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function foo(a, b) {
+    ///   if (a) {
+    ///     if (b) {
+    ///       bar();
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// function foo(a) {
+    ///   if (!a) {
+    ///     return;
+    ///   }
+    ///   bar();
+    /// }
+    /// ```
+    NoGratuitousExpressions,
+    oxc,
+    suspicious
+);
+
+impl Rule for NoGratuitousExpressions {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let test = match node.kind() {
+            AstKind::IfStatement(stmt) => &stmt.test,
+            AstKind::ConditionalExpression(expr) => &expr.test,
+            _ => return,
+        };
+        let Some((ident, is_positive)) = condition(test) else {
+            return;
+        };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        // The truthiness of a variable is only known if it never changes.
+        if !ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+            || ctx.scoping().get_resolved_references(symbol_id).any(Reference::is_write)
+        {
+            return;
+        }
+
+        let Some((is_truthy, guard_span)) = known_truthiness(node, symbol_id, ctx) else {
+            return;
+        };
+        // A guard before the declaration may see a different value, e.g. of a hoisted `var`.
+        let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+        if declaration.span().start > guard_span.start {
+            return;
+        }
+
+        ctx.diagnostic(no_gratuitous_expressions_diagnostic(
+            &ident.name,
+            is_truthy,
+            is_truthy == is_positive,
+            test.span(),
+            guard_span,
+        ));
+    }
+}
+
+/// For a condition like `x` or `!x`, the identifier and whether the condition is `true` when
+/// the identifier is truthy.
+fn condition<'a, 'b>(test: &'b Expression<'a>) -> Option<(&'b IdentifierReference<'a>, bool)> {
+    match test.without_parentheses() {
+        Expression::Identifier(ident) => Some((ident, true)),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+            match unary.argument.without_parentheses() {
+                Expression::Identifier(ident) => Some((ident, false)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether the symbol is known to be truthy at `node`, because of an enclosing or preceding
+/// condition in the same function, and the span of that condition.
+fn known_truthiness(
+    node: &AstNode,
+    symbol_id: SymbolId,
+    ctx: &LintContext,
+) -> Option<(bool, Span)> {
+    // Whether `test` is a condition on the symbol, and if so, the truthiness of the symbol when
+    // `test` evaluates to `branch`.
+    let guard = |test: &Expression, branch: bool| {
+        let (ident, is_positive) = condition(test)?;
+        let reference = ctx.scoping().get_reference(ident.reference_id());
+        (reference.symbol_id() == Some(symbol_id)).then(|| (is_positive == branch, test.span()))
+    };
+
+    let mut child_span = node.span();
+    for ancestor in ctx.nodes().ancestor_kinds(node.id()) {
+        let known = match ancestor {
+            AstKind::IfStatement(stmt) => {
+                if stmt.consequent.span() == child_span {
+                    guard(&stmt.test, true)
+                } else if stmt.alternate.as_ref().is_some_and(|alt| alt.span() == child_span) {
+                    guard(&stmt.test, false)
+                } else {
+                    None
+                }
+            }
+            AstKind::ConditionalExpression(expr) => {
+                if expr.consequent.span() == child_span {
+                    guard(&expr.test, true)
+                } else if expr.alternate.span() == child_span {
+                    guard(&expr.test, false)
+                } else {
+                    None
+                }
+            }
+            AstKind::LogicalExpression(expr) if expr.right.span() == child_span => {
+                match expr.operator {
+                    LogicalOperator::And => guard(&expr.left, true),
+                    LogicalOperator::Or => guard(&expr.left, false),
+                    LogicalOperator::Coalesce => None,
+                }
+            }
+            AstKind::BlockStatement(block) => preceding_guard(&block.body, child_span, guard),
+            AstKind::FunctionBody(body) => preceding_guard(&body.statements, child_span, guard),
+            AstKind::SwitchCase(case) => preceding_guard(&case.consequent, child_span, guard),
+            AstKind::Program(program) => preceding_guard(&program.body, child_span, guard),
+            _ => None,
+        };
+        if known.is_some() {
+            return known;
+        }
+        if matches!(
+            ancestor,
+            AstKind::Function(_)
+                | AstKind::ArrowFunctionExpression(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::PropertyDefinition(_)
+        ) {
+            return None;
+        }
+        child_span = ancestor.span();
+    }
+    None
+}
+
+/// Finds an `if` statement before the statement at `child_span` which exits unless its
+/// condition is `false`, e.g. `if (!x) return;`.
+fn preceding_guard(
+    statements: &[Statement],
+    child_span: Span,
+    guard: impl Fn(&Expression, bool) -> Option<(bool, Span)>,
+) -> Option<(bool, Span)> {
+    statements.iter().take_while(|stmt| stmt.span() != child_span).find_map(|stmt| match stmt {
+        Statement::IfStatement(stmt) if stmt.alternate.is_none() && exits(&stmt.consequent) => {
+            guard(&stmt.test, false)
+        }
+        _ => None,
+    })
+}
+
+/// Whether `stmt` always jumps out of the enclosing statement list.
+fn exits(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::ReturnStatement(_)
+        | Statement::ThrowStatement(_)
+        | Statement::BreakStatement(_)
+        | Statement::ContinueStatement(_) => true,
+        Statement::BlockStatement(block) => block.body.last().is_some_and(exits),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function f(a, b) { if (a) { if (b) {} } }",
+        "function f(a) { if (a) {} if (a) {} }",
+        "function f(a) { if (!a) { foo(); } if (a) {} }",
+        "function f(a) { if (a) { a = foo(); if (a) {} } }",
+        "function f(a) { if (!a) return; a = foo(); if (a) {} }",
+        "function f(a) { if (a) { const g = () => { if (a) {} }; } }",
+        "function f(a) { if (a.b) { if (a.b) {} } }",
+        "function f(a) { if (a) { let a = foo(); if (a) {} } }",
+        "function f(a) { if (a) {} else { foo(); } if (a) {} }",
+        "function f(a) { if (a) { return; } else { foo(); } if (a) {} }",
+        "function f(a) { if (!a) { if (b) return; } if (a) {} }",
+        "function f(a) { const b = a ?? (a ? 1 : 2); }",
+        "function f() { if (!x) return; if (x) {} }",
+        "function f() { if (x) return; var x = foo(); if (x) {} }",
+        "function f(a) { if (a) { foo(); } else if (b) { bar(); } }",
+    ];
+
+    let fail = vec![
+        "function f(a) { if (a) { if (a) { foo(); } } }",
+        "function f(a) { if (a) { if (!a) { foo(); } } }",
+        "function f(a) { if (!a) { return; } if (a) { foo(); } }",
+        "function f(a) { if (a) return; if (a) { foo(); } }",
+        "function f(a) { if (!a) throw new Error(); return a ? 1 : 2; }",
+        "function f(a) { if (a) { foo(); } else if (a) { bar(); } }",
+        "function f(a) { if (a) { foo(); } else { if (!a) { bar(); } } }",
+        "function f(a) { return a && (a ? 1 : 2); }",
+        "function f(a) { return a || (a ? 1 : 2); }",
+        "function f(a) { return a ? (a ? 1 : 2) : 3; }",
+        "function f(xs) { for (const x of xs) { if (!x) continue; if (x) { foo(); } } }",
+        "function f(a) { switch (b) { case 1: if (!a) break; if (a) { foo(); } } }",
+        "function f(a) { if ((a)) { if (!(a)) { foo(); } } }",
+        "const a = foo(); if (a) { if (a) { bar(); } }",
+    ];
+
+    Tester::new(NoGratuitousExpressions::NAME, NoGratuitousExpressions::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
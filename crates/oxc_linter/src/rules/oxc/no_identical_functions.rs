@@ -0,0 +1,432 @@
+use oxc_ast::{
+    AstKind,
+    ast::{FormalParameters, FunctionBody, RegExpLiteral, StringLiteral, TemplateElement},
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{ast_util::get_function_name_span, context::LintContext, rule::Rule};
+
+fn no_identical_functions_diagnostic(first: Span, duplicate: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Function has the same implementation as another function.")
+        .with_help("Extract the shared implementation into a single function and reuse it.")
+        .with_labels([
+            first.label("It is first implemented here"),
+            duplicate.label("and implemented again here"),
+        ])
+}
+
+#[derive(Debug, Clone)]
+pub struct NoIdenticalFunctions {
+    min_lines: usize,
+}
+
+impl Default for NoIdenticalFunctions {
+    fn default() -> Self {
+        Self { min_lines: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow functions with identical implementations.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with the same parameters and body are duplicated code. A bug fixed in one of
+    /// them is easily left in the other ones. The shared implementation should be extracted
+    /// into a single function instead.
+    ///
+    /// Functions are compared by their source text, ignoring comments and differences in
+    /// whitespace outside of string, template and regular expression literals. An `async` or
+    /// generator function is only identical to another `async` or generator function.
+    /// Functions whose body spans fewer than `minLines` lines, and arrow functions with an
+    /// expression body, are ignored.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function getFullName(user) {
+    ///   const name = `${user.firstName} ${user.lastName}`;
+    ///   return name.trim();
+    /// }
+    ///
+    /// function getDisplayName(user) {
+    ///   const name = `${user.firstName} ${user.lastName}`;
+    ///   return name.trim();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function getFullName(user) {
+    ///   const name = `${user.firstName} ${user.lastName}`;
+    ///   return name.trim();
+    /// }
+    ///
+    /// function getDisplayName(user) {
+    ///   return getFullName(user);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### minLines
+    ///
+    /// `{ type: number, default: 3 }`
+    ///
+    /// The minimum number of lines of a function body, including its braces, for the function
+    /// to be checked.
+    ///
+    /// Example:
+    /// ```json
+    /// "oxc/no-identical-functions": ["error", { "minLines": 5 }]
+    /// ```
+    NoIdenticalFunctions,
+    oxc,
+    pedantic
+);
+
+impl Rule for NoIdenticalFunctions {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let min_lines = value
+            .get(0)
+            .and_then(|config| config.get("minLines"))
+            .and_then(serde_json::Value::as_u64)
+            .map_or(Self::default().min_lines, |n| n as usize);
+
+        Self { min_lines }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        // (async, generator, normalized implementation) -> name span of the first function with it.
+        let mut implementations: FxHashMap<(bool, bool, String), Span> = FxHashMap::default();
+
+        for node in ctx.nodes() {
+            let (is_async, is_generator, params, body) = match node.kind() {
+                AstKind::Function(func) => {
+                    let Some(body) = &func.body else {
+                        continue;
+                    };
+                    (func.r#async, func.generator, &*func.params, &**body)
+                }
+                AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => {
+                    (arrow.r#async, false, &*arrow.params, &*arrow.body)
+                }
+                _ => continue,
+            };
+            if ctx.source_range(body.span).lines().count() < self.min_lines {
+                continue;
+            }
+
+            let span = get_function_name_span(node, ctx.nodes().parent_node(node.id()));
+            let implementation = (is_async, is_generator, normalize(params, body, ctx));
+            if let Some(&first) = implementations.get(&implementation) {
+                ctx.diagnostic(no_identical_functions_diagnostic(first, span));
+            } else {
+                implementations.insert(implementation, span);
+            }
+        }
+    }
+}
+
+/// The source text of `params` and `body`, without comments and with every run of whitespace
+/// outside of literals replaced by a single space.
+fn normalize(params: &FormalParameters, body: &FunctionBody, ctx: &LintContext) -> String {
+    let mut literals = LiteralCollector::default();
+    literals.visit_formal_parameters(params);
+    literals.visit_function_body(body);
+
+    // Comments are left out, and literals are kept as they are.
+    let mut skipped = ctx
+        .comments_range(params.span.start..body.span.end)
+        .map(|comment| (comment.span, false))
+        .chain(literals.spans.into_iter().map(|span| (span, true)))
+        .collect::<Vec<_>>();
+    skipped.sort_unstable_by_key(|(span, _)| span.start);
+
+    let mut normalized = String::new();
+    for span in [params.span, body.span] {
+        let mut start = span.start;
+        for &(skipped_span, is_literal) in
+            skipped.iter().filter(|(skipped_span, _)| span.contains_inclusive(*skipped_span))
+        {
+            push_normalized(
+                &mut normalized,
+                ctx.source_range(Span::new(start, skipped_span.start)),
+            );
+            if is_literal {
+                normalized.push_str(ctx.source_range(skipped_span));
+            }
+            start = skipped_span.end;
+        }
+        push_normalized(&mut normalized, ctx.source_range(Span::new(start, span.end)));
+    }
+    normalized
+}
+
+fn push_normalized(normalized: &mut String, text: &str) {
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !normalized.ends_with(' ') {
+                normalized.push(' ');
+            }
+        } else {
+            normalized.push(c);
+        }
+    }
+}
+
+/// Collects the spans of the literals in which whitespace is significant.
+#[derive(Default)]
+struct LiteralCollector {
+    spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for LiteralCollector {
+    fn visit_string_literal(&mut self, it: &StringLiteral<'a>) {
+        self.spans.push(it.span);
+    }
+
+    fn visit_template_element(&mut self, it: &TemplateElement<'a>) {
+        self.spans.push(it.span);
+    }
+
+    fn visit_reg_exp_literal(&mut self, it: &RegExpLiteral<'a>) {
+        self.spans.push(it.span);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "
+            function foo() {
+                a();
+                b();
+            }
+            function bar() {
+                a();
+                c();
+            }
+            ",
+            None,
+        ),
+        ("function foo() { a(); b(); }\nfunction bar() { a(); b(); }", None),
+        (
+            "
+            function foo(x) {
+                return x + 1;
+            }
+            function bar(y) {
+                return x + 1;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            const foo = () =>
+                a(b, c);
+            const bar = () =>
+                a(b, c);
+            ",
+            None,
+        ),
+        (
+            "
+            class A {
+                foo() {
+                    return this.a;
+                }
+                bar() {
+                    return this.b;
+                }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo() {
+                a();
+            }
+            function bar() {
+                a();
+            }
+            ",
+            Some(json!([{ "minLines": 4 }])),
+        ),
+        ("declare function foo(): void;\ndeclare function bar(): void;", None),
+        (
+            "
+            function foo() {
+                return 'a b';
+            }
+            function bar() {
+                return 'a  b';
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo(x) {
+                return `${x} items`;
+            }
+            function bar(x) {
+                return `${x}  items`;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo(s) {
+                return s.split(/ /);
+            }
+            function bar(s) {
+                return s.split(/  /);
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo() {
+                return a();
+            }
+            async function bar() {
+                return a();
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo() {
+                a();
+            }
+            function* bar() {
+                a();
+            }
+            ",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+            function foo() {
+                a();
+                b();
+            }
+            function bar() {
+                a();
+                b();
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo(x) {
+                // increment
+                return x + 1;
+            }
+            function bar(x) {
+                return x   +   1; /* also increment */
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class A {
+                foo() {
+                    return this.a;
+                }
+                bar() {
+                    return this.a;
+                }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            const foo = () => {
+                a();
+            };
+            const bar = function () {
+                a();
+            };
+            ",
+            None,
+        ),
+        (
+            "
+            const obj = {
+                foo() {
+                    a();
+                },
+                bar: function () {
+                    a();
+                },
+            };
+            ",
+            None,
+        ),
+        (
+            "
+            function foo() {
+                a();
+            }
+            function bar() {
+                a();
+            }
+            function baz() {
+                a();
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            list.map(function (item) {
+                return item.id;
+            });
+            list.map(function (item) {
+                return item.id;
+            });
+            ",
+            None,
+        ),
+        ("function foo() { a(); }\nfunction bar() { a(); }", Some(json!([{ "minLines": 1 }]))),
+        (
+            "
+            async function foo(x) {
+                await a(`${x} items`, 'a b');
+            }
+            async function bar(x) {
+                await a(`${x} items`,   'a b');
+            }
+            ",
+            None,
+        ),
+    ];
+
+    Tester::new(NoIdenticalFunctions::NAME, NoIdenticalFunctions::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,195 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+use crate::{AstNode, context::LintContext, fixer::RuleFixer, rule::Rule};
+
+fn no_inverted_boolean_check_diagnostic(
+    operator: BinaryOperator,
+    inverse: BinaryOperator,
+    span: Span,
+) -> OxcDiagnostic {
+    let help = format!("Replace `!(a {} b)` with `a {} b`.", operator.as_str(), inverse.as_str());
+    let help = if operator.is_equality() {
+        help
+    } else {
+        format!("{help} Note that they differ when an operand is `NaN`.")
+    };
+
+    OxcDiagnostic::warn(format!(
+        "Use the opposite operator (`{}`) instead of negating the comparison.",
+        inverse.as_str()
+    ))
+    .with_help(help)
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInvertedBooleanCheck;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow negating a comparison instead of using the opposite operator.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Negating a whole comparison, e.g. `!(a === b)`, is harder to read than the comparison
+    /// with the opposite operator, `a !== b`.
+    ///
+    /// The fix is only applied automatically for `==`, `!=`, `===` and `!==`. For `<`, `<=`, `>`
+    /// and `>=` it is offered as a suggestion, as the opposite comparison differs when an
+    /// operand is `NaN`: `!(NaN < 1)` is `true`, while `NaN >= 1` is `false`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// if (!(a === 2)) {}
+    /// const isOutside = !(index < length);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// if (a !== 2) {}
+    /// const isOutside = index >= length;
+    /// ```
+    NoInvertedBooleanCheck,
+    oxc,
+    style,
+    conditional_fix_suggestion
+);
+
+impl Rule for NoInvertedBooleanCheck {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::UnaryExpression(unary) = node.kind() else {
+            return;
+        };
+        if unary.operator != UnaryOperator::LogicalNot {
+            return;
+        }
+        let Expression::BinaryExpression(binary) = unary.argument.without_parentheses() else {
+            return;
+        };
+        let (inverse, is_equality) = match binary.operator.equality_inverse_operator() {
+            Some(inverse) => (inverse, true),
+            None => match negated_comparison_operator(binary.operator) {
+                Some(inverse) => (inverse, false),
+                None => return,
+            },
+        };
+
+        let diagnostic = no_inverted_boolean_check_diagnostic(binary.operator, inverse, unary.span);
+        if ctx.has_comments_between(unary.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let fix = |fixer: RuleFixer<'_, 'a>| {
+            let comparison = format!(
+                "{} {} {}",
+                ctx.source_range(binary.left.span()),
+                inverse.as_str(),
+                ctx.source_range(binary.right.span())
+            );
+            let replacement = if needs_parentheses(ctx.nodes().parent_kind(node.id())) {
+                format!("({comparison})")
+            } else {
+                comparison
+            };
+            fixer.replace(unary.span, replacement)
+        };
+        if is_equality {
+            ctx.diagnostic_with_fix(diagnostic, fix);
+        } else {
+            ctx.diagnostic_with_suggestion(diagnostic, fix);
+        }
+    }
+}
+
+/// The operator of the comparison which is `true` when the comparison with `operator` is `false`,
+/// provided that no operand is `NaN`.
+fn negated_comparison_operator(operator: BinaryOperator) -> Option<BinaryOperator> {
+    match operator {
+        BinaryOperator::LessThan => Some(BinaryOperator::GreaterEqualThan),
+        BinaryOperator::LessEqualThan => Some(BinaryOperator::GreaterThan),
+        BinaryOperator::GreaterThan => Some(BinaryOperator::LessEqualThan),
+        BinaryOperator::GreaterEqualThan => Some(BinaryOperator::LessThan),
+        _ => None,
+    }
+}
+
+/// Whether a comparison replacing a `!` expression with `parent` must be parenthesized.
+fn needs_parentheses(parent: AstKind) -> bool {
+    matches!(
+        parent,
+        AstKind::BinaryExpression(_)
+            | AstKind::UnaryExpression(_)
+            | AstKind::AwaitExpression(_)
+            | AstKind::StaticMemberExpression(_)
+            | AstKind::ComputedMemberExpression(_)
+            | AstKind::CallExpression(_)
+            | AstKind::NewExpression(_)
+            | AstKind::TaggedTemplateExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (a !== b) {}",
+        "const x = !a;",
+        "const x = !(a);",
+        "const x = !(a && b);",
+        "const x = !(a || b);",
+        "const x = !(a + b);",
+        "const x = !(a instanceof B);",
+        "const x = !('a' in b);",
+        "const x = -(a < b);",
+        "!(a === b).toString();",
+    ];
+
+    let fail = vec![
+        "if (!(a === b)) {}",
+        "if (!(a !== b)) {}",
+        "if (!(a == b)) {}",
+        "if (!(a != b)) {}",
+        "const x = !(a < b);",
+        "const x = !(a <= b);",
+        "const x = !(a > b);",
+        "const x = !(a >= b);",
+        "const x = !((a === b));",
+        "const x = !(a.length === b.length) && c;",
+        "const x = !(a === b) + 1;",
+        "const x = !(a /* b */ === b);",
+        "const x = !(a == b == c);",
+    ];
+
+    let fix = vec![
+        ("if (!(a === b)) {}", "if (a !== b) {}"),
+        ("if (!(a !== b)) {}", "if (a === b) {}"),
+        ("if (!(a == b)) {}", "if (a != b) {}"),
+        ("if (!(a != b)) {}", "if (a == b) {}"),
+        ("const x = !(a < b);", "const x = a >= b;"),
+        ("const x = !(a <= b);", "const x = a > b;"),
+        ("const x = !(a > b);", "const x = a <= b;"),
+        ("const x = !(a >= b);", "const x = a < b;"),
+        ("const x = !((a === b));", "const x = a !== b;"),
+        ("const x = !(a.length === b.length) && c;", "const x = a.length !== b.length && c;"),
+        ("const x = !(a === b) + 1;", "const x = (a !== b) + 1;"),
+        ("const x = !(a /* b */ === b);", "const x = !(a /* b */ === b);"),
+        ("const x = !(a == b == c);", "const x = a == b != c;"),
+        ("foo(!(a === b));", "foo(a !== b);"),
+        ("(!(a === b)).toString();", "(a !== b).toString();"),
+    ];
+
+    Tester::new(NoInvertedBooleanCheck::NAME, NoInvertedBooleanCheck::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,302 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BreakStatement, DoWhileStatement, Expression, ForInStatement,
+        ForOfStatement, ForStatement, Function, Statement, SwitchCase, SwitchStatement,
+        WhileStatement,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_small_switch_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`switch` statements should have at least two `case` clauses.")
+        .with_help("Use an `if` statement instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSmallSwitch;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `switch` statements with fewer than two `case` clauses.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `switch` statement is meant to choose between several branches. With a single `case`
+    /// clause, or only a `default` clause, an `if` statement expresses the same logic in a
+    /// shorter and more readable way.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// switch (value) {
+    ///   case 1:
+    ///     foo();
+    ///     break;
+    ///   default:
+    ///     bar();
+    /// }
+    ///
+    /// switch (value) {
+    ///   default:
+    ///     foo();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// if (value === 1) {
+    ///   foo();
+    /// } else {
+    ///   bar();
+    /// }
+    ///
+    /// switch (value) {
+    ///   case 1:
+    ///     foo();
+    ///     break;
+    ///   case 2:
+    ///     bar();
+    ///     break;
+    /// }
+    /// ```
+    NoSmallSwitch,
+    oxc,
+    style,
+    conditional_suggestion
+);
+
+impl Rule for NoSmallSwitch {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SwitchStatement(switch) = node.kind() else {
+            return;
+        };
+        if switch.cases.iter().filter(|case| !case.is_default_case()).count() >= 2 {
+            return;
+        }
+
+        let diagnostic = no_small_switch_diagnostic(Span::sized(switch.span.start, 6));
+        match if_statement_text(switch, ctx) {
+            Some(replacement) if !ctx.has_comments_between(switch.span) => {
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    fixer.replace(switch.span, replacement)
+                });
+            }
+            _ => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// The `if` statement equivalent to a `switch` with one `case` clause and an optional `default`
+/// clause, if the clauses can be turned into blocks.
+fn if_statement_text(switch: &SwitchStatement, ctx: &LintContext) -> Option<String> {
+    let case = switch.cases.iter().find(|case| !case.is_default_case())?;
+    let default = switch.cases.iter().find(|case| case.is_default_case());
+    let last = switch.cases.last()?;
+
+    let discriminant = operand_text(&switch.discriminant, ctx);
+    let test = operand_text(case.test.as_ref()?, ctx);
+    let consequent = block_text(case, case.span == last.span, ctx)?;
+    let Some(default) = default else {
+        return Some(format!("if ({discriminant} === {test}) {consequent}"));
+    };
+    let alternate = block_text(default, default.span == last.span, ctx)?;
+    Some(format!("if ({discriminant} === {test}) {consequent} else {alternate}"))
+}
+
+/// The statements of a `switch` clause as a block, without the trailing `break`.
+///
+/// Returns `None` if the clause can't be turned into a block: if it breaks out of the `switch`
+/// anywhere but at its end, or if it falls through into the next clause.
+fn block_text(case: &SwitchCase, is_last: bool, ctx: &LintContext) -> Option<String> {
+    let mut statements = case.consequent.as_slice();
+    if let [Statement::BlockStatement(block)] = statements {
+        statements = block.body.as_slice();
+    }
+
+    match statements.last() {
+        Some(Statement::BreakStatement(break_stmt)) if break_stmt.label.is_none() => {
+            statements = &statements[..statements.len() - 1];
+        }
+        Some(
+            Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::ContinueStatement(_)
+            | Statement::BreakStatement(_),
+        ) => {}
+        _ if is_last => {}
+        _ => return None,
+    }
+
+    let mut finder = SwitchBreakFinder::default();
+    for statement in statements {
+        finder.visit_statement(statement);
+    }
+    if finder.found {
+        return None;
+    }
+
+    match (statements.first(), statements.last()) {
+        (Some(first), Some(last)) => Some(format!(
+            "{{ {} }}",
+            ctx.source_range(Span::new(first.span().start, last.span().end))
+        )),
+        _ => Some("{}".to_string()),
+    }
+}
+
+/// The source text of `expr` as an operand of `===`.
+fn operand_text<'a>(expr: &Expression, ctx: &LintContext<'a>) -> Cow<'a, str> {
+    let text = ctx.source_range(expr.span());
+    let needs_parentheses = match expr {
+        Expression::BinaryExpression(binary) => {
+            binary.operator.is_equality() || binary.operator.is_bitwise()
+        }
+        Expression::LogicalExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::SequenceExpression(_)
+        | Expression::ArrowFunctionExpression(_) => true,
+        _ => false,
+    };
+    if needs_parentheses { format!("({text})").into() } else { text.into() }
+}
+
+/// Finds `break` statements which would exit the `switch` statement they are in.
+#[derive(Default)]
+struct SwitchBreakFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SwitchBreakFinder {
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_none() {
+            self.found = true;
+        }
+    }
+
+    fn visit_switch_statement(&mut self, _it: &SwitchStatement<'a>) {}
+
+    fn visit_for_statement(&mut self, _it: &ForStatement<'a>) {}
+
+    fn visit_for_in_statement(&mut self, _it: &ForInStatement<'a>) {}
+
+    fn visit_for_of_statement(&mut self, _it: &ForOfStatement<'a>) {}
+
+    fn visit_while_statement(&mut self, _it: &WhileStatement<'a>) {}
+
+    fn visit_do_while_statement(&mut self, _it: &DoWhileStatement<'a>) {}
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "switch (a) { case 1: foo(); break; case 2: bar(); break; }",
+        "switch (a) { case 1: case 2: foo(); break; }",
+        "switch (a) { case 1: foo(); break; case 2: bar(); break; default: baz(); }",
+        "
+        function reducer(state, action) {
+            switch (action.type) {
+                case 'add':
+                    return state + 1;
+                case 'remove':
+                    return state - 1;
+                default:
+                    return state;
+            }
+        }
+        ",
+        "if (a === 1) { foo(); }",
+    ];
+
+    let fail = vec![
+        "switch (a) { case 1: foo(); break; }",
+        "switch (a) { case 1: foo(); break; default: bar(); }",
+        "switch (a) { default: foo(); }",
+        "switch (a) {}",
+        "
+        function reducer(state, action) {
+            switch (action.type) {
+                case 'add':
+                    return state + 1;
+                default:
+                    return state;
+            }
+        }
+        ",
+        "switch (a) { default: bar(); break; case 1: foo(); }",
+        "switch (a) { case 1: { foo(); break; } default: { bar(); } }",
+        "switch (a) { case 1: foo(); default: bar(); }",
+        "switch (a) { case 1: if (b) { break; } foo(); }",
+        "switch (a) { case 1: for (const x of b) { if (x) { break; } } foo(); }",
+        "switch (a) { case 1: break; }",
+        "switch (a || b) { case c ? 1 : 2: foo(); }",
+        "switch (a) { case 1: /* comment */ foo(); }",
+        "function f() { switch (a) { case 1: switch (b) { case 2: foo(); } } }",
+    ];
+
+    let fix = vec![
+        ("switch (a) { case 1: foo(); break; }", "if (a === 1) { foo(); }"),
+        (
+            "switch (a) { case 1: foo(); break; default: bar(); }",
+            "if (a === 1) { foo(); } else { bar(); }",
+        ),
+        (
+            "function f() { switch (action.type) { case 'add': return state + 1; default: return state; } }",
+            "function f() { if (action.type === 'add') { return state + 1; } else { return state; } }",
+        ),
+        (
+            "switch (a) { default: bar(); break; case 1: foo(); }",
+            "if (a === 1) { foo(); } else { bar(); }",
+        ),
+        (
+            "switch (a) { case 1: { foo(); break; } default: { bar(); } }",
+            "if (a === 1) { foo(); } else { bar(); }",
+        ),
+        (
+            "switch (a) { case 1: foo(); default: bar(); }",
+            "switch (a) { case 1: foo(); default: bar(); }",
+        ),
+        (
+            "switch (a) { case 1: if (b) { break; } foo(); }",
+            "switch (a) { case 1: if (b) { break; } foo(); }",
+        ),
+        (
+            "switch (a) { case 1: for (const x of b) { if (x) { break; } } foo(); }",
+            "if (a === 1) { for (const x of b) { if (x) { break; } } foo(); }",
+        ),
+        ("switch (a) { case 1: break; }", "if (a === 1) {}"),
+        ("switch (a || b) { case c ? 1 : 2: foo(); }", "if ((a || b) === (c ? 1 : 2)) { foo(); }"),
+        ("switch (a) { default: foo(); }", "switch (a) { default: foo(); }"),
+        (
+            "switch (a) { case 1: /* comment */ foo(); }",
+            "switch (a) { case 1: /* comment */ foo(); }",
+        ),
+        (
+            "lbl: switch (a) { case 1: if (b) break lbl; foo(); }",
+            "lbl: if (a === 1) { if (b) break lbl; foo(); }",
+        ),
+    ];
+
+    Tester::new(NoSmallSwitch::NAME, NoSmallSwitch::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,207 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPatternKind, Expression, Statement, VariableDeclarationKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn prefer_immediate_return_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Immediately return this expression instead of assigning it to the temporary variable `{name}`."
+    ))
+    .with_help("Return the expression directly.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferImmediateReturn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow declaring a variable only to return it immediately.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A variable which is declared and then immediately returned, without being used
+    /// anywhere else, is redundant. Returning the expression directly is shorter and avoids
+    /// having to read an extra name.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function getArea(width, height) {
+    ///   const area = width * height;
+    ///   return area;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function getArea(width, height) {
+    ///   return width * height;
+    /// }
+    ///
+    /// function getArea(width, height) {
+    ///   const area = width * height;
+    ///   log(area);
+    ///   return area;
+    /// }
+    /// ```
+    PreferImmediateReturn,
+    oxc,
+    style,
+    fix
+);
+
+impl Rule for PreferImmediateReturn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ReturnStatement(return_stmt) = node.kind() else {
+            return;
+        };
+        let Some(Expression::Identifier(ident)) = &return_stmt.argument else {
+            return;
+        };
+
+        let statements = match ctx.nodes().parent_kind(node.id()) {
+            AstKind::FunctionBody(body) => &body.statements,
+            AstKind::BlockStatement(block) => &block.body,
+            AstKind::SwitchCase(case) => &case.consequent,
+            _ => return,
+        };
+        let Some(index) = statements.iter().position(|stmt| stmt.span() == return_stmt.span) else {
+            return;
+        };
+        let Some(Statement::VariableDeclaration(decl)) =
+            index.checked_sub(1).map(|index| &statements[index])
+        else {
+            return;
+        };
+        if matches!(decl.kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+        {
+            return;
+        }
+        let [declarator] = decl.declarations.as_slice() else {
+            return;
+        };
+        let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+            return;
+        };
+        let Some(init) = &declarator.init else {
+            return;
+        };
+
+        // The returned identifier must be the only reference to the variable.
+        let symbol_id = id.symbol_id();
+        if ctx.scoping().get_reference(ident.reference_id()).symbol_id() != Some(symbol_id)
+            || ctx.scoping().get_resolved_reference_ids(symbol_id).len() != 1
+        {
+            return;
+        }
+
+        let diagnostic = prefer_immediate_return_diagnostic(&id.name, decl.span);
+        let span = Span::new(decl.span.start, return_stmt.span.end);
+        // Inlining the expression would drop the type annotation.
+        if declarator.id.type_annotation.is_some() || ctx.has_comments_between(span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let semicolon =
+                if ctx.source_range(return_stmt.span).ends_with(';') { ";" } else { "" };
+            fixer.replace(span, format!("return {}{semicolon}", ctx.source_range(init.span())))
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { return bar(); }",
+        "function foo() { const x = bar(); baz(x); return x; }",
+        "function foo() { const x = bar(); baz(); return x; }",
+        "function foo() { let x = bar(); x += 1; return x; }",
+        "function foo() { const x = bar(); return x + 1; }",
+        "function foo() { const x = bar(); return y; }",
+        "function foo() { const x = bar(), y = baz(); return x; }",
+        "function foo() { const { x } = bar(); return x; }",
+        "function foo() { let x; return x; }",
+        "function foo() { const x = bar(); return () => x; }",
+        "function foo() { const x = bar(); if (a) { return x; } }",
+        "function foo() { if (a) { const x = bar(); } return x; }",
+        "function foo() { const x = bar(); return; }",
+        "function foo() { const x = bar(); type T = typeof x; return x; }",
+        "async function foo() { await using x = bar(); return x; }",
+        "function foo() { using x = bar(); return x; }",
+    ];
+
+    let fail = vec![
+        "function foo() { const x = bar(); return x; }",
+        "function foo() { let x = bar(); return x; }",
+        "function foo() { var x = bar(); return x; }",
+        "const foo = () => { const x = a + b; return x; };",
+        "
+        function foo(a) {
+            if (a) {
+                const result = a.map((item) => item * 2);
+                return result;
+            }
+            return [];
+        }
+        ",
+        "
+        function foo(a) {
+            switch (a) {
+                case 1:
+                    const x = bar();
+                    return x;
+            }
+        }
+        ",
+        "function foo() { const x: number = bar(); return x; }",
+        "function foo() { const x = bar(); /* result */ return x; }",
+        "class A { foo() { const x = this.bar; return x; } }",
+    ];
+
+    let fix = vec![
+        ("function foo() { const x = bar(); return x; }", "function foo() { return bar(); }"),
+        ("function foo() { let x = bar(); return x }", "function foo() { return bar() }"),
+        (
+            "const foo = () => { const x = a + b; return x; };",
+            "const foo = () => { return a + b; };",
+        ),
+        ("function foo() { const x = { a: 1 }; return x; }", "function foo() { return { a: 1 }; }"),
+        ("function foo() { const x = (a, b); return x; }", "function foo() { return (a, b); }"),
+        (
+            "
+            function foo() {
+                const x = bar();
+                return x;
+            }
+            ",
+            "
+            function foo() {
+                return bar();
+            }
+            ",
+        ),
+        (
+            "function foo() { const x: number = bar(); return x; }",
+            "function foo() { const x: number = bar(); return x; }",
+        ),
+        (
+            "function foo() { const x = bar(); /* result */ return x; }",
+            "function foo() { const x = bar(); /* result */ return x; }",
+        ),
+    ];
+
+    Tester::new(PreferImmediateReturn::NAME, PreferImmediateReturn::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
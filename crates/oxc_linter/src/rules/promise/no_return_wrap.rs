@@ -93,7 +93,7 @@ declare_oxc_lint!(
     /// myPromise().then(() => 4)
     /// myPromise().then(function() { return 4 })
     ///
-    /// myPromise().then(() => throw "err")
+    /// myPromise().then(() => { throw "err" })
     /// myPromise().then(function() { throw "err" })
     /// ```
     ///
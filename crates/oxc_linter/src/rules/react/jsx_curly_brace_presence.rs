@@ -113,7 +113,7 @@ declare_oxc_lint!(
     ///
     /// ## Rule Options
     ///
-    /// ```js
+    /// ```text
     /// ...
     /// "react/jsx-curly-brace-presence": [<enabled>, { "props": <string>, "children": <string>, "propElementValues": <string> }]
     /// ...
@@ -121,7 +121,7 @@ declare_oxc_lint!(
     ///
     /// or alternatively
     ///
-    /// ```js
+    /// ```text
     /// ...
     /// "react/jsx-curly-brace-presence": [<enabled>, <string>]
     /// ...
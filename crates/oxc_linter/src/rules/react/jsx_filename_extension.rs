@@ -86,7 +86,7 @@ declare_oxc_lint!(
     /// #### `allow` (default: `"always"`)
     /// When to allow a JSX filename extension. By default all files may have a JSX extension.
     /// Set this to `as-needed` to only allow JSX file extensions in files that contain JSX syntax.
-    /// ```js
+    /// ```json
     /// "rules": {
     ///     "react/jsx-filename-extension": ["error", { "allow": "as-needed" }]
     /// }
@@ -94,7 +94,7 @@ declare_oxc_lint!(
     ///
     /// #### `extensions` (default: `[".jsx"]`)
     /// The set of allowed extensions is configurable. By default `'.jsx'` is allowed. If you wanted to allow both `'.jsx'` and `'.tsx'`, the configuration would be:
-    /// ```js
+    /// ```json
     /// "rules": {
     ///     "react/jsx-filename-extension": ["error", { "extensions": [".jsx", ".tsx"] }]
     /// }
@@ -102,7 +102,7 @@ declare_oxc_lint!(
     ///
     /// #### `ignoreFilesWithoutCode` (default: `false`)
     /// If enabled, files that do not contain code (i.e. are empty, contain only whitespaces or comments) will not be rejected.
-    /// ```js
+    /// ```json
     /// "rules": {
     ///     "react/jsx-filename-extension": ["error", { "ignoreFilesWithoutCode": true }]
     /// }
@@ -20,7 +20,8 @@ fn adjacent_overload_signatures_diagnostic(
     first: Option<Span>,
     second: Span,
 ) -> OxcDiagnostic {
-    let mut d = OxcDiagnostic::warn(format!("All {fn_name:?} signatures should be adjacent."));
+    let mut d = OxcDiagnostic::warn(format!("All {fn_name:?} signatures should be adjacent."))
+        .with_help(format!("Move this signature next to the other {fn_name:?} signatures."));
     if let Some(span) = first {
         d = d.and_label(span);
     }
@@ -4,9 +4,9 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, fixer::Fix, rule::Rule};
 
 fn consistent_generic_constructors_diagnostic_prefer_annotation(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -74,10 +74,20 @@ declare_oxc_lint!(
     /// const a = new Foo<string>();
     /// const a: Foo<string> = new Foo(); // prefer type annotation
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `{ type: "constructor" | "type-annotation", default: "constructor" }`
+    ///
+    /// - `"constructor"`: type arguments go on the constructor call, e.g. `const a = new Foo<string>()`.
+    /// - `"type-annotation"`: type arguments go on the type annotation, e.g. `const a: Foo<string> = new Foo()`.
+    ///
+    /// Declarations with type arguments on both sides, such as
+    /// `const a: Foo<string> = new Foo<number>()`, are not checked.
     ConsistentGenericConstructors,
     typescript,
     style,
-    pending
+    fix
 );
 
 impl Rule for ConsistentGenericConstructors {
@@ -86,7 +96,8 @@ impl Rule for ConsistentGenericConstructors {
             AstKind::VariableDeclarator(variable_declarator) => {
                 let type_ann = variable_declarator.id.type_annotation.as_ref();
                 let init = variable_declarator.init.as_ref();
-                self.check(type_ann, init, ctx);
+                let annotation_position = variable_declarator.id.kind.span().end;
+                self.check(type_ann, init, Some(annotation_position), ctx);
             }
             AstKind::AssignmentPattern(assignment_pattern) => {
                 if !matches!(ctx.nodes().parent_kind(node.id()), AstKind::FormalParameter(_)) {
@@ -95,12 +106,27 @@ impl Rule for ConsistentGenericConstructors {
 
                 let type_ann = assignment_pattern.left.type_annotation.as_ref();
                 let init = &assignment_pattern.right;
-                self.check(type_ann, Some(init), ctx);
+                let annotation_position = assignment_pattern.left.kind.span().end;
+                self.check(type_ann, Some(init), Some(annotation_position), ctx);
             }
             AstKind::PropertyDefinition(property_definition) => {
                 let type_ann = property_definition.type_annotation.as_ref();
                 let init = property_definition.value.as_ref();
-                self.check(type_ann, init, ctx);
+                // the annotation goes after `?` or `!`, and after the brackets of a computed key
+                let annotation_position = if property_definition.optional
+                    || property_definition.definite
+                {
+                    None
+                } else if property_definition.computed {
+                    let key_end = property_definition.key.span().end;
+                    let after_key = &ctx.source_text()[key_end as usize..];
+                    #[expect(clippy::cast_possible_truncation)]
+                    let bracket = key_end + (after_key.len() - after_key.trim_start().len()) as u32;
+                    ctx.source_text()[bracket as usize..].starts_with(']').then_some(bracket + 1)
+                } else {
+                    Some(property_definition.key.span().end)
+                };
+                self.check(type_ann, init, annotation_position, ctx);
             }
             _ => {}
         }
@@ -122,10 +148,13 @@ impl Rule for ConsistentGenericConstructors {
 }
 
 impl ConsistentGenericConstructors {
+    /// `annotation_position` is where a type annotation is inserted when moving the type
+    /// arguments of the constructor, or `None` if it can't be added.
     fn check(
         &self,
         type_annotation: Option<&oxc_allocator::Box<TSTypeAnnotation>>,
         init: Option<&Expression>,
+        annotation_position: Option<u32>,
         ctx: &LintContext,
     ) {
         let Some(init) = init else { return };
@@ -152,30 +181,61 @@ impl ConsistentGenericConstructors {
         if matches!(self.0.option, PreferGenericType::TypeAnnotation) {
             if type_annotation.is_none() {
                 if let Some(type_arguments) = &new_expression.type_arguments {
-                    ctx.diagnostic(consistent_generic_constructors_diagnostic_prefer_annotation(
+                    let diagnostic = consistent_generic_constructors_diagnostic_prefer_annotation(
                         type_arguments.span,
-                    ));
+                    );
+                    let Some(annotation_position) = annotation_position else {
+                        ctx.diagnostic(diagnostic);
+                        return;
+                    };
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        let annotation = format!(
+                            ": {}{}",
+                            identifier.name,
+                            ctx.source_range(type_arguments.span)
+                        );
+                        let mut fix = fixer.new_fix_with_capacity(2);
+                        fix.push(Fix::new(annotation, Span::empty(annotation_position)));
+                        fix.push(Fix::delete(type_arguments.span));
+                        fix.with_message("Move the type arguments to the type annotation")
+                    });
                 }
             }
             return;
         }
 
-        if let Some(type_arguments) = &type_annotation {
-            if has_type_parameters(&type_arguments.type_annotation)
-                && new_expression.type_arguments.is_none()
-            {
-                ctx.diagnostic(consistent_generic_constructors_diagnostic_prefer_constructor(
-                    type_arguments.span,
-                ));
-            }
+        let Some(type_annotation) = type_annotation else {
+            return;
+        };
+        let TSType::TSTypeReference(type_reference) = &type_annotation.type_annotation else {
+            return;
+        };
+        let Some(type_arguments) = &type_reference.type_arguments else {
+            return;
+        };
+        if new_expression.type_arguments.is_some() {
+            return;
         }
-    }
-}
 
-fn has_type_parameters(ts_type: &TSType) -> bool {
-    match ts_type {
-        TSType::TSTypeReference(type_ref) => type_ref.type_arguments.is_some(),
-        _ => false,
+        let diagnostic =
+            consistent_generic_constructors_diagnostic_prefer_constructor(type_annotation.span);
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            // keep comments of the removed type annotation, e.g. `a: /* comment */ Foo<string>`
+            let mut insertion = ctx
+                .comments_range(type_annotation.span.start..type_annotation.span.end)
+                .filter(|comment| !type_arguments.span.contains_inclusive(comment.span))
+                .map(|comment| ctx.source_range(comment.span))
+                .collect::<String>();
+            insertion.push_str(ctx.source_range(type_arguments.span));
+            // `new Foo` has no arguments list
+            if new_expression.span.end == identifier.span.end {
+                insertion.push_str("()");
+            }
+            let mut fix = fixer.new_fix_with_capacity(2);
+            fix.push(Fix::delete(type_annotation.span));
+            fix.push(Fix::new(insertion, Span::empty(identifier.span.end)));
+            fix.with_message("Move the type arguments to the constructor")
+        });
     }
 }
 
@@ -192,6 +252,7 @@ fn test() {
         ("const a: Foo = new Foo<string>();", None),
         ("const a: Bar = new Foo<string>();", None),
         ("const a: Bar<string> = new Foo<string>();", None),
+        ("const a: Foo<string> = new Foo<number>();", None),
         ("const a: Foo<string> = Foo<string>();", None),
         ("const a: Foo<string> = Foo();", None),
         ("const a: Foo = Foo<string>();", None),
@@ -241,6 +302,7 @@ fn test() {
         ("const a: Foo = new Foo();", Some(serde_json::json!(["type-annotation"]))),
         ("const a: Bar = new Foo<string>();", Some(serde_json::json!(["type-annotation"]))),
         ("const a: Bar<string> = new Foo<string>();", Some(serde_json::json!(["type-annotation"]))),
+        ("const a: Foo<string> = new Foo<number>();", Some(serde_json::json!(["type-annotation"]))),
         ("const a: Foo<string> = Foo<string>();", Some(serde_json::json!(["type-annotation"]))),
         ("const a: Foo<string> = Foo();", Some(serde_json::json!(["type-annotation"]))),
         ("const a: Foo = Foo<string>();", Some(serde_json::json!(["type-annotation"]))),
@@ -436,9 +498,10 @@ fn test() {
 			      ",
             Some(serde_json::json!(["type-annotation"])),
         ),
+        ("class Foo { a? = new Foo<string>(); }", Some(serde_json::json!(["type-annotation"]))),
     ];
 
-    let _fix = vec![
+    let fix = vec![
         ("const a: Foo<string> = new Foo();", "const a = new Foo<string>();", None),
         ("const a: Map<string, number> = new Map();", "const a = new Map<string, number>();", None),
         (
@@ -678,6 +741,11 @@ fn test() {
 			      ",
             Some(serde_json::json!(["type-annotation"])),
         ),
+        (
+            "class Foo { a? = new Foo<string>(); }",
+            "class Foo { a? = new Foo<string>(); }",
+            Some(serde_json::json!(["type-annotation"])),
+        ),
     ];
     Tester::new(
         ConsistentGenericConstructors::NAME,
@@ -685,5 +753,6 @@ fn test() {
         pass,
         fail,
     )
+    .expect_fix(fix)
     .test_and_snapshot();
 }
@@ -0,0 +1,376 @@
+use itertools::Itertools;
+use oxc_ast::{
+    AstKind,
+    ast::{
+        PropertyKey, TSFunctionType, TSMethodSignature, TSMethodSignatureKind, TSPropertySignature,
+        TSSignature, TSType,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    fixer::Fix,
+    rule::Rule,
+};
+
+fn method_signature_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Shorthand method signature is forbidden.")
+        .with_help("Use a function property instead.")
+        .with_label(span)
+}
+
+fn property_signature_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Function property signature is forbidden.")
+        .with_help("Use a method shorthand instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MethodSignatureStyle {
+    mode: Mode,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum Mode {
+    #[default]
+    Property,
+    Method,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using a particular method signature syntax.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript provides two ways to define an object/interface function property:
+    /// ```ts
+    /// interface Example {
+    ///   // method shorthand syntax
+    ///   func(arg: string): number;
+    ///
+    ///   // regular property with function type
+    ///   func: (arg: string) => number;
+    /// }
+    /// ```
+    ///
+    /// The two are very similar; most of the time it doesn't matter which one you use.
+    /// However, with `strictFunctionTypes` enabled, parameters of method shorthands are checked
+    /// bivariantly, while parameters of function properties are checked contravariantly,
+    /// which is more correct.
+    ///
+    /// This rule enforces the function property syntax by default. When fixing, overloaded
+    /// method signatures are merged into an intersection of function types.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"property"` option:
+    /// ```ts
+    /// interface T1 {
+    ///   func(arg: string): number;
+    /// }
+    /// type T2 = {
+    ///   func(arg: boolean): void;
+    /// };
+    /// interface T3 {
+    ///   func(arg: number): void;
+    ///   func(arg: string): void;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"property"` option:
+    /// ```ts
+    /// interface T1 {
+    ///   func: (arg: string) => number;
+    /// }
+    /// type T2 = {
+    ///   func: (arg: boolean) => void;
+    /// };
+    /// interface T3 {
+    ///   func: ((arg: number) => void) & ((arg: string) => void);
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the `"method"` option:
+    /// ```ts
+    /// interface T1 {
+    ///   func: (arg: string) => number;
+    /// }
+    /// type T2 = {
+    ///   func: (arg: boolean) => void;
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the `"method"` option:
+    /// ```ts
+    /// interface T1 {
+    ///   func(arg: string): number;
+    /// }
+    /// type T2 = {
+    ///   func(arg: boolean): void;
+    /// };
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `{ type: "property" | "method", default: "property" }`
+    ///
+    /// Example:
+    /// ```json
+    /// "typescript/method-signature-style": ["error", "method"]
+    /// ```
+    MethodSignatureStyle,
+    typescript,
+    style,
+    fix
+);
+
+impl Rule for MethodSignatureStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode =
+            value.get(0).and_then(serde_json::Value::as_str).map_or_else(Mode::default, |value| {
+                match value {
+                    "method" => Mode::Method,
+                    _ => Mode::Property,
+                }
+            });
+        Self { mode }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let members = match node.kind() {
+            AstKind::TSInterfaceDeclaration(decl) => &decl.body.body,
+            AstKind::TSTypeLiteral(literal) => &literal.members,
+            _ => return,
+        };
+
+        match self.mode {
+            Mode::Property => check_method_signatures(members, ctx),
+            Mode::Method => check_property_signatures(members, ctx),
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn check_method_signatures<'a>(members: &[TSSignature<'a>], ctx: &LintContext<'a>) {
+    let methods = members
+        .iter()
+        .enumerate()
+        .filter_map(|(index, member)| match member {
+            TSSignature::TSMethodSignature(method)
+                if method.kind == TSMethodSignatureKind::Method =>
+            {
+                Some((index, &**method, key_text(&method.key, method.computed, ctx)))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for (_, method, key) in &methods {
+        let overloads = methods.iter().filter(|(_, _, other)| other == key).collect::<Vec<_>>();
+
+        ctx.diagnostic_with_fix(method_signature_diagnostic(method.key.span()), |fixer| {
+            let (_, first, _) = overloads[0];
+            let optional = if first.optional { "?" } else { "" };
+            if overloads.len() == 1 {
+                let function_type = method_to_function_type(first, ctx);
+                return fixer
+                    .replace(method_body_span(first), format!("{key}{optional}: {function_type}"))
+                    .with_message("Convert to a function property");
+            }
+
+            // Overloads are merged into a single property with an intersection of their types.
+            let function_types = overloads
+                .iter()
+                .map(|(_, method, _)| format!("({})", method_to_function_type(method, ctx)))
+                .join(" & ");
+            let mut fix = fixer.new_fix_with_capacity(overloads.len());
+            fix.push(Fix::new(
+                format!("{key}{optional}: {function_types}"),
+                method_body_span(first),
+            ));
+            for (index, method, _) in &overloads[1..] {
+                // Also remove the whitespace between this overload and the preceding member.
+                let start = members[*index - 1].span().end;
+                fix.push(Fix::delete(Span::new(start, method.span.end)));
+            }
+            fix.with_message("Merge the overloads into a function property")
+        });
+    }
+}
+
+fn check_property_signatures<'a>(members: &[TSSignature<'a>], ctx: &LintContext<'a>) {
+    for member in members {
+        let TSSignature::TSPropertySignature(property) = member else {
+            continue;
+        };
+        // Method signatures can not be `readonly`.
+        if property.readonly {
+            continue;
+        }
+        let Some(TSType::TSFunctionType(function_type)) =
+            property.type_annotation.as_ref().map(|annotation| &annotation.type_annotation)
+        else {
+            continue;
+        };
+
+        ctx.diagnostic_with_fix(property_signature_diagnostic(property.key.span()), |fixer| {
+            fixer
+                .replace(
+                    Span::new(property.span.start, function_type.span.end),
+                    property_to_method(property, function_type, ctx),
+                )
+                .with_message("Convert to a method shorthand")
+        });
+    }
+}
+
+fn key_text(key: &PropertyKey, computed: bool, ctx: &LintContext) -> String {
+    let text = ctx.source_range(key.span());
+    if computed { format!("[{text}]") } else { text.to_string() }
+}
+
+/// Span of a method signature, excluding the trailing `;` or `,`.
+fn method_body_span(method: &TSMethodSignature) -> Span {
+    let end = method
+        .return_type
+        .as_ref()
+        .map_or(method.params.span.end, |return_type| return_type.span.end);
+    Span::new(method.span.start, end)
+}
+
+/// `f<T>(a: T): R` -> `<T>(a: T) => R`
+fn method_to_function_type(method: &TSMethodSignature, ctx: &LintContext) -> String {
+    let start =
+        method.type_parameters.as_ref().map_or(method.params.span.start, |tp| tp.span.start);
+    let params = ctx.source_range(Span::new(start, method.params.span.end));
+    let return_type = method
+        .return_type
+        .as_ref()
+        .map_or("any", |return_type| ctx.source_range(return_type.type_annotation.span()));
+    format!("{params} => {return_type}")
+}
+
+/// `f?: <T>(a: T) => R` -> `f?<T>(a: T): R`
+fn property_to_method(
+    property: &TSPropertySignature,
+    function_type: &TSFunctionType,
+    ctx: &LintContext,
+) -> String {
+    let key = key_text(&property.key, property.computed, ctx);
+    let optional = if property.optional { "?" } else { "" };
+    let start = function_type
+        .type_parameters
+        .as_ref()
+        .map_or(function_type.params.span.start, |tp| tp.span.start);
+    let params = ctx.source_range(Span::new(start, function_type.params.span.end));
+    let return_type = ctx.source_range(function_type.return_type.type_annotation.span());
+    format!("{key}{optional}{params}: {return_type}")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("interface Test { f: (a: string) => number; }", None),
+        ("interface Test { ['f']: (a: boolean) => void; }", None),
+        ("interface Test { f: <T>(a: T) => T; }", None),
+        ("interface Test { ['f']: <T extends {}>(a: T, b: T) => T; }", None),
+        ("interface Test { 'f!': </* a */ T>(/* b */ x: any /* c */) => void; }", None),
+        ("interface Test { get f(): number; }", None),
+        ("interface Test { set f(value: number); }", None),
+        ("type Test = { readonly f: (a: string) => number; };", None),
+        ("type Test = { f?: () => void; };", None),
+        ("interface Test { f(a: string): number; }", Some(json!(["method"]))),
+        ("interface Test { f<T>(a: T): T; }", Some(json!(["method"]))),
+        ("type Test = { f(): void; };", Some(json!(["method"]))),
+        ("interface Test { readonly f: (a: string) => number; }", Some(json!(["method"]))),
+        ("interface Test { f: number; }", Some(json!(["method"]))),
+    ];
+
+    let fail = vec![
+        ("interface Test { f(a: string): number; }", None),
+        ("interface Test { ['f'](a: boolean): void; }", None),
+        ("interface Test { f<T>(a: T): T; }", None),
+        ("type Test = { f?(): void; };", None),
+        ("interface Test { f(); }", None),
+        ("interface Test { f(this: Foo, a: string): void; }", None),
+        ("interface Test { f(a: string): void; g(): void; f(a: number): void; }", None),
+        ("interface Test { f: (a: string) => number; }", Some(json!(["method"]))),
+        ("interface Test { ['f']: (a: boolean) => void; }", Some(json!(["method"]))),
+        ("type Test = { f?: <T>(a: T) => T; };", Some(json!(["method"]))),
+    ];
+
+    let fix = vec![
+        (
+            "interface Test { f(a: string): number; }",
+            "interface Test { f: (a: string) => number; }",
+            None,
+        ),
+        (
+            "interface Test { ['f'](a: boolean): void }",
+            "interface Test { ['f']: (a: boolean) => void }",
+            None,
+        ),
+        ("interface Test { f<T>(a: T): T; }", "interface Test { f: <T>(a: T) => T; }", None),
+        (
+            "type Test = { f?(): void, g: number };",
+            "type Test = { f?: () => void, g: number };",
+            None,
+        ),
+        ("interface Test { f(); }", "interface Test { f: () => any; }", None),
+        (
+            "interface Test { f(this: Foo, a: string): void; }",
+            "interface Test { f: (this: Foo, a: string) => void; }",
+            None,
+        ),
+        (
+            "interface Test { f(a: string): void; g(): void; f(a: number): void; }",
+            "interface Test { f: ((a: string) => void) & ((a: number) => void); g(): void; }",
+            None,
+        ),
+        (
+            "
+            interface Test {
+                f(): void;
+                f(a: string): number;
+            }
+            ",
+            "
+            interface Test {
+                f: (() => void) & ((a: string) => number);
+            }
+            ",
+            None,
+        ),
+        (
+            "interface Test { f: (a: string) => number; }",
+            "interface Test { f(a: string): number; }",
+            Some(json!(["method"])),
+        ),
+        (
+            "interface Test { ['f']: (a: boolean) => void, }",
+            "interface Test { ['f'](a: boolean): void, }",
+            Some(json!(["method"])),
+        ),
+        (
+            "type Test = { f?: <T>(a: T) => T; };",
+            "type Test = { f?<T>(a: T): T; };",
+            Some(json!(["method"])),
+        ),
+    ];
+
+    Tester::new(MethodSignatureStyle::NAME, MethodSignatureStyle::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
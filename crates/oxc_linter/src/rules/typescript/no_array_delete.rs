@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPattern, Expression, TSType, TSTypeName},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{AstNode, ast_util::is_array_value, context::LintContext, rule::Rule};
+
+fn no_array_delete_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Using the `delete` operator with an array expression is unsafe.")
+        .with_help(
+            "Use `array.splice()` instead, which removes the element instead of leaving a hole.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayDelete;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow using the `delete` operator on array values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When using the `delete` operator with an array value, the array's `length` property is
+    /// not affected, but the element at the specified index is removed and leaves an empty slot
+    /// in the array. This is likely to lead to unexpected behavior. As mentioned in the
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/delete#deleting_array_elements),
+    /// the recommended way to remove an element from an array is by using the
+    /// `Array#splice()` method.
+    ///
+    /// This rule does not use type information. It only checks arrays which are known
+    /// syntactically: array literals, `new Array()`, `Array()`, `Array.from()` and
+    /// `Array.of()`, and variables which are never reassigned and are either initialized with
+    /// one of those or annotated with an array type.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const arr = [1, 2, 3];
+    /// delete arr[1];
+    ///
+    /// function f(arr: number[]) {
+    ///   delete arr[0];
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const arr = [1, 2, 3];
+    /// arr.splice(1, 1);
+    ///
+    /// const obj = { a: 1 };
+    /// delete obj.a;
+    /// ```
+    NoArrayDelete,
+    typescript,
+    correctness,
+    suggestion
+);
+
+impl Rule for NoArrayDelete {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::UnaryExpression(unary) = node.kind() else {
+            return;
+        };
+        if unary.operator != UnaryOperator::Delete {
+            return;
+        }
+        let Expression::ComputedMemberExpression(member) = unary.argument.without_parentheses()
+        else {
+            return;
+        };
+        if !is_array(&member.object, ctx) {
+            return;
+        }
+
+        let diagnostic = no_array_delete_diagnostic(unary.span);
+        if ctx.has_comments_between(unary.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            let object = ctx.source_range(member.object.span());
+            let index = ctx.source_range(member.expression.span());
+            // `delete arr[a, b]` must not become `arr.splice(a, b, 1)`.
+            let index = if matches!(member.expression, Expression::SequenceExpression(_)) {
+                Cow::Owned(format!("({index})"))
+            } else {
+                Cow::Borrowed(index)
+            };
+            fixer
+                .replace(unary.span, format!("{object}.splice({index}, 1)"))
+                .with_message("Use `array.splice()` instead.")
+        });
+    }
+}
+
+/// Whether `expr` is syntactically known to evaluate to an array.
+fn is_array(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            let scoping = ctx.scoping();
+            let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+                return false;
+            };
+            // The value of a reassigned variable may not be an array anymore.
+            if scoping.symbol_is_mutated(symbol_id) {
+                return false;
+            }
+            match ctx.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+                // Not `const [arr] = [[1, 2]]`, whose initializer isn't the value of `arr`.
+                AstKind::VariableDeclarator(decl) if decl.id.kind.is_binding_identifier() => {
+                    has_array_type(&decl.id)
+                        || decl.init.as_ref().is_some_and(|init| is_array_value(init, ctx))
+                }
+                AstKind::FormalParameter(param) => has_array_type(&param.pattern),
+                _ => false,
+            }
+        }
+        expr => is_array_value(expr, ctx),
+    }
+}
+
+/// Whether `pattern` is an identifier annotated with an array or tuple type, e.g. `arr: T[]`.
+fn has_array_type(pattern: &BindingPattern) -> bool {
+    if !pattern.kind.is_binding_identifier() {
+        return false;
+    }
+    let Some(type_annotation) = &pattern.type_annotation else {
+        return false;
+    };
+    match type_annotation.type_annotation.without_parenthesized() {
+        TSType::TSArrayType(_) | TSType::TSTupleType(_) => true,
+        TSType::TSTypeReference(reference) => matches!(
+            &reference.type_name,
+            TSTypeName::IdentifierReference(ident) if ident.name == "Array"
+        ),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const obj = { a: 1 }; delete obj.a;",
+        "const obj = { a: 1 }; delete obj['a'];",
+        "const obj: Record<string, number> = {}; delete obj[key];",
+        "delete foo[0];",
+        "function f(arr) { delete arr[0]; }",
+        "function f(arr: Foo) { delete arr[0]; }",
+        "function f({ arr }: { arr: number[] }) { delete arr[0]; }",
+        "let arr = [1, 2]; arr = {}; delete arr[0];",
+        "const arr = [1, 2]; delete arr.length;",
+        "const arr = [1, 2]; arr.splice(0, 1);",
+        "const map = new Map(); delete map[0];",
+        "class Array {} const arr = new Array(); delete arr[0];",
+        "const arr = [1, 2]; delete arr?.[0];",
+        "const [arr] = [[1, 2]]; delete arr[0];",
+    ];
+
+    let fail = vec![
+        "const arr = [1, 2, 3]; delete arr[0];",
+        "let arr = [1, 2, 3]; delete arr[i];",
+        "const arr = new Array(3); delete arr[0];",
+        "const arr = Array(3); delete arr[0];",
+        "const arr = Array.from(foo); delete arr[0];",
+        "const arr = Array.of(1, 2); delete arr[1];",
+        "const arr: number[] = getNumbers(); delete arr[0];",
+        "const arr: Array<number> = getNumbers(); delete arr[0];",
+        "const arr: [number, string] = getTuple(); delete arr[0];",
+        "function f(arr: string[]) { delete arr[0]; }",
+        "const arr = [1, 2] as const; delete arr[0];",
+        "delete [1, 2][0];",
+        "const arr = [1, 2]; delete (arr)[0];",
+        "const arr = [1, 2]; delete arr[0 + 1];",
+        "const arr = [1, 2]; delete arr[/* index */ 0];",
+        "const arr = [1, 2]; delete arr[a, b];",
+    ];
+
+    let fix = vec![
+        ("const arr = [1, 2, 3]; delete arr[0];", "const arr = [1, 2, 3]; arr.splice(0, 1);"),
+        ("let arr = [1, 2, 3]; delete arr[i];", "let arr = [1, 2, 3]; arr.splice(i, 1);"),
+        (
+            "function f(arr: string[]) { delete arr[0]; }",
+            "function f(arr: string[]) { arr.splice(0, 1); }",
+        ),
+        ("delete [1, 2][0];", "[1, 2].splice(0, 1);"),
+        ("const arr = [1, 2]; delete (arr)[0];", "const arr = [1, 2]; (arr).splice(0, 1);"),
+        ("const arr = [1, 2]; delete arr[0 + 1];", "const arr = [1, 2]; arr.splice(0 + 1, 1);"),
+        ("const arr = [1, 2]; delete arr[a, b];", "const arr = [1, 2]; arr.splice((a, b), 1);"),
+        ("const arr = [1, 2]; delete arr[(a, b)];", "const arr = [1, 2]; arr.splice((a, b), 1);"),
+        (
+            "const arr = [1, 2]; delete arr[/* index */ 0];",
+            "const arr = [1, 2]; delete arr[/* index */ 0];",
+        ),
+    ];
+
+    Tester::new(NoArrayDelete::NAME, NoArrayDelete::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
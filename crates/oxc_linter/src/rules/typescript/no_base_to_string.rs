@@ -0,0 +1,202 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, ObjectPropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_base_to_string_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{name}' will use Object's default stringification format ('[object Object]') when stringified."
+    ))
+    .with_help("Define a `toString` method, or format the value explicitly.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoBaseToString;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require values being converted to strings to provide a useful string representation.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript calls `toString()` on an object when it is converted to a string, such as
+    /// when it is interpolated into a template literal, concatenated with a string, or passed to
+    /// `String()`. The default `Object.prototype.toString()` returns `"[object Object]"`,
+    /// which is almost never what was intended.
+    ///
+    /// This rule does not use type information. It only checks object literals without a
+    /// `toString` or `valueOf` method, `new Object()`, and array literals containing such
+    /// objects.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// `Value: ${{ a: 1 }}`;
+    /// '' + {};
+    /// String({});
+    /// ({}).toString();
+    /// String([{}]);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// `Value: ${'a'}`;
+    /// '' + 1;
+    /// String([1, 2]);
+    /// String({ toString: () => 'a' });
+    /// JSON.stringify({ a: 1 });
+    /// ```
+    NoBaseToString,
+    typescript,
+    correctness
+);
+
+impl Rule for NoBaseToString {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TemplateLiteral(template) => {
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    AstKind::TaggedTemplateExpression(tagged) if tagged.quasi.span == template.span
+                ) {
+                    return;
+                }
+                for expression in &template.expressions {
+                    check_coerced(expression, template.span, ctx);
+                }
+            }
+            AstKind::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+                if binary.left.without_parentheses().is_string_literal() {
+                    check_coerced(&binary.right, binary.span, ctx);
+                } else if binary.right.without_parentheses().is_string_literal() {
+                    check_coerced(&binary.left, binary.span, ctx);
+                }
+            }
+            AstKind::CallExpression(call) => {
+                // `String(value)`
+                if let Expression::Identifier(ident) = &call.callee {
+                    if ident.name == "String" && ctx.is_reference_to_global_variable(ident) {
+                        if let Some(argument) =
+                            call.arguments.first().and_then(Argument::as_expression)
+                        {
+                            check_coerced(argument, call.span, ctx);
+                        }
+                    }
+                    return;
+                }
+                // `value.toString()`
+                let Some(member) = call.callee.without_parentheses().as_member_expression() else {
+                    return;
+                };
+                if matches!(member.static_property_name(), Some("toString" | "toLocaleString")) {
+                    check_coerced(member.object(), call.span, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_coerced(expr: &Expression, span: Span, ctx: &LintContext) {
+    if uses_base_to_string(expr, ctx) {
+        ctx.diagnostic(no_base_to_string_diagnostic(
+            ctx.source_range(expr.get_inner_expression().span()),
+            span,
+        ));
+    }
+}
+
+/// Whether `expr` is known to be stringified by `Object.prototype.toString`.
+fn uses_base_to_string(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::ObjectExpression(object) => object.properties.iter().all(|property| {
+            match property {
+                // `toString`, `valueOf` or `Symbol.toPrimitive` may be defined by a spread or
+                // computed property.
+                ObjectPropertyKind::SpreadProperty(_) => false,
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    property.key.static_name().is_some_and(|name| {
+                        !matches!(name.as_ref(), "toString" | "valueOf" | "__proto__")
+                    })
+                }
+            }
+        }),
+        Expression::NewExpression(new_expr) => {
+            matches!(
+                &new_expr.callee,
+                Expression::Identifier(ident)
+                    if ident.name == "Object" && ctx.is_reference_to_global_variable(ident)
+            ) && new_expr.arguments.is_empty()
+        }
+        // Arrays are stringified by joining their elements.
+        Expression::ArrayExpression(array) => array.elements.iter().any(|element| {
+            element.as_expression().is_some_and(|element| uses_base_to_string(element, ctx))
+        }),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "`${'a'}`;",
+        "`a${1}b`;",
+        "`${foo}`;",
+        "'' + 'a';",
+        "'' + 1;",
+        "1 + {};",
+        "foo + {};",
+        "String('a');",
+        "String(foo);",
+        "String();",
+        "String([]);",
+        "String([1, 2]);",
+        "`${[1, 'a']}`;",
+        "String({ toString() { return 'a'; } });",
+        "String({ toString: () => 'a' });",
+        "'' + { valueOf() { return 1; } };",
+        "String({ ...foo });",
+        "String({ [key]: 1 });",
+        "String({ __proto__: foo });",
+        "String(new Object(1));",
+        "String(new Foo());",
+        "tag`${{}}`;",
+        "foo.toString();",
+        "'a'.toString();",
+        "JSON.stringify({ a: 1 });",
+        "function f(String) { String({}); }",
+        "class Object {} String(new Object());",
+    ];
+
+    let fail = vec![
+        "`${{}}`;",
+        "`a${{ a: 1 }}b`;",
+        "`${foo}${{}}`;",
+        "'' + {};",
+        "'a' + { a: 1 };",
+        "const s = {} + '';",
+        "`a` + ({});",
+        "String({});",
+        "String({ a: 1, b() {} });",
+        "String({} as object);",
+        "String(new Object());",
+        "String([{}]);",
+        "`${[1, {}]}`;",
+        "({}).toString();",
+        "({ a: 1 }).toLocaleString();",
+        "foo(`${{}}`);",
+    ];
+
+    Tester::new(NoBaseToString::NAME, NoBaseToString::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -4,7 +4,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
 
 use crate::{
@@ -43,7 +43,7 @@ declare_oxc_lint!(
     NoConfusingNonNullAssertion,
     typescript,
     suspicious,
-    pending
+    suggestion
 );
 
 fn not_need_no_confusing_non_null_assertion_diagnostic(op_str: &str, span: Span) -> OxcDiagnostic {
@@ -90,6 +90,14 @@ fn is_confusable_operator(operator: BinaryOperator) -> bool {
     matches!(operator, BinaryOperator::Equality | BinaryOperator::StrictEquality)
 }
 
+/// Reports `diagnostic` with a suggestion to wrap the left-hand side in parentheses,
+/// e.g. `a! == b` becomes `(a!) == b`.
+fn wrap_left_in_parens(diagnostic: OxcDiagnostic, left: Span, ctx: &LintContext<'_>) {
+    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+        fixer.replace(left, format!("({})", ctx.source_range(left)))
+    });
+}
+
 impl Rule for NoConfusingNonNullAssertion {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
@@ -99,17 +107,18 @@ impl Rule for NoConfusingNonNullAssertion {
                 let Some(bang_depth) = get_depth_ends_in_bang(&binary_expr.left) else {
                     return;
                 };
-                if bang_depth == 0 {
-                    ctx.diagnostic(not_need_no_confusing_non_null_assertion_diagnostic(
+                let diagnostic = if bang_depth == 0 {
+                    not_need_no_confusing_non_null_assertion_diagnostic(
                         binary_expr.operator.as_str(),
                         binary_expr.span,
-                    ));
+                    )
                 } else {
-                    ctx.diagnostic(wrap_up_no_confusing_non_null_assertion_diagnostic(
+                    wrap_up_no_confusing_non_null_assertion_diagnostic(
                         binary_expr.operator.as_str(),
                         binary_expr.span,
-                    ));
-                }
+                    )
+                };
+                wrap_left_in_parens(diagnostic, binary_expr.left.span(), ctx);
             }
             AstKind::AssignmentExpression(assignment_expr)
                 if assignment_expr.operator == AssignmentOperator::Assign =>
@@ -118,10 +127,14 @@ impl Rule for NoConfusingNonNullAssertion {
                     return;
                 };
                 let SimpleAssignmentTarget::TSNonNullExpression(_) = simple_target else { return };
-                ctx.diagnostic(confusing_non_null_assignment_assertion_diagnostic(
-                    assignment_expr.operator.as_str(),
-                    assignment_expr.span,
-                ));
+                wrap_left_in_parens(
+                    confusing_non_null_assignment_assertion_diagnostic(
+                        assignment_expr.operator.as_str(),
+                        assignment_expr.span,
+                    ),
+                    assignment_expr.left.span(),
+                    ctx,
+                );
             }
             _ => {}
         }
@@ -154,6 +167,8 @@ fn test() {
         "a! **= b;",
         "a! != b;",
         "a! !== b;",
+        "(a!) == b;",
+        "(a!) === b;",
     ];
     let fail = vec![
         "a! == b;",
@@ -165,11 +180,15 @@ fn test() {
         "(obj = new new OuterObj().InnerObj).Name! = c;",
         "(a=b)! =c;",
     ];
-    // let fix = vec![
-    //     // source, expected, rule_config?
-    //     // ("f = 1 + d! == 2", "f = (1 + d!) == 2", None), TODO: Add suggest or the weird ;() fix
-    //     // ("f =  d! == 2", "f = d == 2", None), TODO: Add suggest remove bang
-    // ];
+    let fix = vec![
+        ("a! == b;", "(a!) == b;"),
+        ("a! === b;", "(a!) === b;"),
+        ("a + b! == c;", "(a + b!) == c;"),
+        ("f = 1 + d! == 2", "f = (1 + d!) == 2"),
+        ("(a==b)! ==c;", "((a==b)!) ==c;"),
+        ("a! = b;", "(a!) = b;"),
+    ];
     Tester::new(NoConfusingNonNullAssertion::NAME, NoConfusingNonNullAssertion::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
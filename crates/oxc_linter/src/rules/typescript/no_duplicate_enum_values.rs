@@ -48,7 +48,9 @@ declare_oxc_lint!(
     /// ### Examples
     ///
     /// This rule disallows defining an enum with multiple members initialized
-    /// to the same value. Members without initializers will not be checked.
+    /// to the same number or string literal. String literals and template
+    /// literals without expressions are compared by value. Members without
+    /// initializers or with other initializers, such as `1 + 1`, will not be checked.
     ///
     /// Example of **incorrect** code:
     /// ```ts
@@ -63,6 +65,12 @@ declare_oxc_lint!(
     ///     B = 'A',
     /// }
     /// ```
+    /// ```ts
+    /// enum E {
+    ///     A = 'A',
+    ///     B = `A`,
+    /// }
+    /// ```
     ///
     /// Example of **correct** code:
     /// ```ts
@@ -114,13 +122,18 @@ impl Rule for NoDuplicateEnumValues {
                         seen_number_values.push((num.value, num.span));
                     }
                 }
-                Expression::StringLiteral(s) => {
-                    if let Some(old_span) = seen_string_values.insert(s.value.as_str(), s.span) {
+                Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => {
+                    let Some(value) = static_string_value(initializer) else {
+                        continue;
+                    };
+                    // Keep the first occurrence, so that all duplicates point to it.
+                    let first_span = *seen_string_values.entry(value).or_insert(initializer.span());
+                    if first_span != initializer.span() {
                         // Formatting here for prettier messages. This makes it
                         // look like "Duplicate enum value 'A'"
-                        let v = format!("'{}'", s.value);
+                        let v = format!("'{value}'");
                         ctx.diagnostic(no_duplicate_enum_values_diagnostic(
-                            old_span,
+                            first_span,
                             enum_member,
                             &v,
                         ));
@@ -136,6 +149,17 @@ impl Rule for NoDuplicateEnumValues {
     }
 }
 
+/// Returns the value of string literals and template literals without expressions, e.g. `` `A` ``.
+fn static_string_value<'a>(expr: &Expression<'a>) -> Option<&'a str> {
+    match expr {
+        Expression::StringLiteral(s) => Some(s.value.as_str()),
+        Expression::TemplateLiteral(template) if template.is_no_substitution_template() => {
+            template.quasi().map(|quasi| quasi.as_str())
+        }
+        _ => None,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -238,6 +262,19 @@ fn test() {
 			    ",
             None,
         ),
+        ("enum E { A, B = 0 }", None),
+        ("enum E { A = 0, B }", None),
+        ("enum E { A = 1, B, C = 2 }", None),
+        ("enum E { A = '1', B = 1 }", None),
+        ("enum E { A = `A`, B = `B` }", None),
+        ("enum E { A = `${x}`, B = `${x}` }", None),
+        ("enum E { A = `A${x}`, B = 'A' }", None),
+        ("enum E { A = 1 + 1, B = 1 + 1 }", None),
+        ("enum E { A = foo(), B = foo() }", None),
+        ("enum E { A = X.C, B = X.C }", None),
+        ("enum E { A = 'A'.length, B = 'A'.length }", None),
+        ("enum E { ['A'] = 1, ['B'] = 2 }", None),
+        ("enum E { A = 1 } enum F { A = 1 }", None),
     ];
 
     let fail = vec![
@@ -270,6 +307,17 @@ fn test() {
 			      ",
             None,
         ),
+        ("enum E { A = 1, B = 2, C = 1 }", None),
+        ("enum E { A = `A`, B = 'A' }", None),
+        ("enum E { A = 'A', B = `A` }", None),
+        ("enum E { A = `A`, B = `A` }", None),
+        ("enum E { A = 1, B = 1, C = 1 }", None),
+        ("enum E { A = 'A', B = 'A', C = 'A' }", None),
+        ("enum E { A = 1, B = 1.0 }", None),
+        ("enum E { A = 0x10, B = 16 }", None),
+        ("enum E { A = 1, B = 'A', C = foo(), D = 1, E = 'A' }", None),
+        ("enum E { A = 1 + 1, B = 2, C = 2 }", None),
+        ("enum E { ['A'] = 1, ['B'] = 1 }", None),
     ];
 
     Tester::new(NoDuplicateEnumValues::NAME, NoDuplicateEnumValues::PLUGIN, pass, fail)
@@ -0,0 +1,218 @@
+use oxc_ast::{AstKind, ast::TSType};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn duplicate_constituent_diagnostic(
+    container: &str,
+    previous: &str,
+    span: Span,
+    previous_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{container} type constituent is duplicated with `{previous}`."))
+        .with_help("Remove the duplicate constituent.")
+        .with_labels([
+            span.label("This constituent is a duplicate"),
+            previous_span.label("It was first used here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateTypeConstituents;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate constituents of union or intersection types.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript supports types ("constituents") within union and intersection types being
+    /// duplicates of each other. However, developers typically expect each constituent to be
+    /// unique within its union or intersection. Duplicate values make the code overly verbose
+    /// and generally reduce readability.
+    ///
+    /// This rule does not use type information, so constituents are compared by their source
+    /// text, ignoring whitespace and wrapping parentheses. Type aliases, such as
+    /// `type Name = string`, are not resolved.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// type T1 = 'A' | 'A';
+    ///
+    /// type T2 = A | A | B;
+    ///
+    /// type T3 = { a: string } & { a: string };
+    ///
+    /// type T4 = [1, 2, 3] | [1, 2, 3];
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// type T1 = 'A' | 'B';
+    ///
+    /// type T2 = A | B | C;
+    ///
+    /// type T3 = { a: string } & { b: string };
+    ///
+    /// type T4 = [1, 2, 3] | [1, 2, 3, 4];
+    /// ```
+    NoDuplicateTypeConstituents,
+    typescript,
+    correctness,
+    fix
+);
+
+impl Rule for NoDuplicateTypeConstituents {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSUnionType(union) => check_constituents(&union.types, "Union", ctx),
+            AstKind::TSIntersectionType(intersection) => {
+                check_constituents(&intersection.types, "Intersection", ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn check_constituents(types: &[TSType], container: &str, ctx: &LintContext) {
+    let mut seen: Vec<(String, Span)> = Vec::with_capacity(types.len());
+
+    for (index, ty) in types.iter().enumerate() {
+        let span = ty.without_parenthesized().span();
+        let text = normalize(ctx.source_range(span));
+        let previous_span =
+            seen.iter().find(|(seen_text, _)| *seen_text == text).map(|(_, span)| *span);
+        let Some(previous_span) = previous_span else {
+            seen.push((text, span));
+            continue;
+        };
+
+        let diagnostic = duplicate_constituent_diagnostic(
+            container,
+            ctx.source_range(previous_span),
+            ty.span(),
+            previous_span,
+        );
+        // Remove the duplicate together with its preceding `|` or `&` separator.
+        let removal_span = Span::new(types[index - 1].span().end, ty.span().end);
+        if ctx.has_comments_between(removal_span) {
+            ctx.diagnostic(diagnostic);
+            continue;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(removal_span));
+    }
+}
+
+/// Removes whitespace outside of string and template literals, so that `{ a: 1 }` and `{a:1}`
+/// compare equal. Whitespace between two words is kept as a single space, so that
+/// `keyof A` and `keyofA` don't.
+fn normalize(text: &str) -> String {
+    let is_word_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$');
+    let mut result = String::with_capacity(text.len());
+    let mut quote = None;
+    let mut escaped = false;
+    let mut skipped_whitespace = false;
+    for c in text.chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None if c.is_whitespace() => {
+                skipped_whitespace = true;
+                continue;
+            }
+            None => {
+                if skipped_whitespace && is_word_char(c) && result.ends_with(is_word_char) {
+                    result.push(' ');
+                }
+                if matches!(c, '\'' | '"' | '`') {
+                    quote = Some(c);
+                }
+            }
+        }
+        skipped_whitespace = false;
+        result.push(c);
+    }
+    result
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "type T = 1 | 2;",
+        "type T = 'A' | 'B';",
+        "type T = A | B | C;",
+        "type T = A & B;",
+        "type T = { a: string } | { b: string };",
+        "type T = { a: string } & { b: string };",
+        "type T = [1, 2, 3] | [1, 2, 3, 4];",
+        "type T = 'a b' | 'ab';",
+        "type T = Foo<A> | Foo<B>;",
+        "type T = () => string | (() => number);",
+        "type T = A | (A & B);",
+        "type T = keyof A | A;",
+        "type T = keyof A | keyofA;",
+        "type T = typeof a | typeofa;",
+        "type T = A;",
+        "function f(a: string | number) {}",
+    ];
+
+    let fail = vec![
+        "type T = 1 | 1;",
+        "type T = 'A' | 'A';",
+        "type T = A | A | B;",
+        "type T = A | B | A;",
+        "type T = A | A | A;",
+        "type T = A & A;",
+        "type T = A & B & A;",
+        "type T = { a: string } & { a: string };",
+        "type T = { a: string } | {a:string};",
+        "type T = [1, 2, 3] | [1, 2, 3];",
+        "type T = A | (A);",
+        "type T = (A | B) | (A | B);",
+        "type T = Foo<A> | Foo< A >;",
+        "function f(a: string | number | string) {}",
+        "let a: Array<A & A>;",
+        "type T = A | /* comment */ A;",
+        "type T = keyof A | keyof  A;",
+    ];
+
+    let fix = vec![
+        ("type T = 1 | 1;", "type T = 1;"),
+        ("type T = A | A | B;", "type T = A | B;"),
+        ("type T = A | B | A;", "type T = A | B;"),
+        ("type T = A | A | A;", "type T = A;"),
+        ("type T = A & B & A;", "type T = A & B;"),
+        ("type T = { a: string } | {a:string};", "type T = { a: string };"),
+        ("type T = A | (A);", "type T = A;"),
+        ("type T =\n  | A\n  | A;", "type T =\n  | A;"),
+        ("function f(a: string | number | string) {}", "function f(a: string | number) {}"),
+        ("type T = A | /* comment */ A;", "type T = A | /* comment */ A;"),
+        ("type T = keyof A | keyof  A;", "type T = keyof A;"),
+    ];
+
+    Tester::new(NoDuplicateTypeConstituents::NAME, NoDuplicateTypeConstituents::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
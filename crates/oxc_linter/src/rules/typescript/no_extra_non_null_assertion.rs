@@ -10,7 +10,9 @@ use crate::{
 };
 
 fn no_extra_non_null_assertion_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("extra non-null assertion").with_label(span)
+    OxcDiagnostic::warn("extra non-null assertion")
+        .with_help("Remove the extra non-null assertion.")
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -66,7 +68,8 @@ declare_oxc_lint!(
     /// ```
     NoExtraNonNullAssertion,
     typescript,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoExtraNonNullAssertion {
@@ -106,7 +109,10 @@ impl Rule for NoExtraNonNullAssertion {
 
         if let Some(expr) = expr {
             let end = expr.span.end - 1;
-            ctx.diagnostic(no_extra_non_null_assertion_diagnostic(Span::empty(end)));
+            ctx.diagnostic_with_fix(
+                no_extra_non_null_assertion_diagnostic(Span::empty(end)),
+                |fixer| fixer.delete_range(Span::new(end, expr.span.end)),
+            );
         }
     }
 
@@ -125,6 +131,8 @@ fn test() {
         "function foo(bar?: { n: number }) { return bar?.n; }",
         "checksCounter?.textContent!.trim(); ",
         "function foo(key: string | null) { const obj = {}; return obj?.[key!]; }",
+        "const a = foo!.bar!;",
+        "const a = foo!.bar!.baz;",
     ];
 
     let fail = vec![
@@ -139,6 +147,18 @@ fn test() {
         "function foo(bar?: { n: number }) { return (bar!)?.(); }",
     ];
 
+    let fix = vec![
+        ("const a = foo!!.bar;", "const a = foo!.bar;"),
+        ("const a = foo!!!.bar;", "const a = foo!.bar;"),
+        ("const a = bar!!;", "const a = bar!;"),
+        ("const a = bar!?.n;", "const a = bar?.n;"),
+        ("const a = bar!?.[0];", "const a = bar?.[0];"),
+        ("const a = bar!?.();", "const a = bar?.();"),
+        ("const a = (foo!)!.bar;", "const a = (foo)!.bar;"),
+        ("const a = (bar)!?.n;", "const a = (bar)?.n;"),
+    ];
+
     Tester::new(NoExtraNonNullAssertion::NAME, NoExtraNonNullAssertion::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -0,0 +1,110 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, ast_util::is_array_value, context::LintContext, rule::Rule};
+
+fn no_for_in_array_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("For-in loops over arrays skips holes, returns indices as strings, and may visit the prototype chain or other enumerable properties.")
+        .with_help("Use a more robust iteration method such as for-of or array.forEach instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoForInArray;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow iterating over an array with a for-in loop.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A for-in loop (`for (const i in o)`) iterates over the properties of an Object.
+    /// While it is legal to use for-in loops with array values, it is not common. There are several
+    /// potential bugs with this:
+    ///
+    /// 1. It iterates over all enumerable properties, including non-index ones and the entire prototype chain.
+    /// 2. It skips holes in the array.
+    /// 3. The loop variable is a string, not a number.
+    ///
+    /// This rule does not use type information, so it only reports loops over expressions which
+    /// are arrays syntactically, such as array literals, `new Array()`, `Array.from()` and `Array.of()`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// for (const i in [1, 2, 3]) {
+    ///   console.log(i);
+    /// }
+    ///
+    /// for (const i in new Array(3)) {
+    ///   console.log(i);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// for (const value of [1, 2, 3]) {
+    ///   console.log(value);
+    /// }
+    ///
+    /// [1, 2, 3].forEach((value, i) => console.log(i, value));
+    ///
+    /// for (const key in { a: 1, b: 2 }) {
+    ///   console.log(key);
+    /// }
+    /// ```
+    NoForInArray,
+    typescript,
+    correctness
+);
+
+impl Rule for NoForInArray {
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ForInStatement(for_in_stmt) = node.kind() else {
+            return;
+        };
+        if !is_array_value(&for_in_stmt.right, ctx) {
+            return;
+        }
+        // Report the loop head, e.g. `for (const x in [1, 2])`.
+        let head = Span::new(for_in_stmt.span.start, for_in_stmt.body.span().start);
+        let head_len = ctx.source_range(head).trim_end().len();
+        ctx.diagnostic(no_for_in_array_diagnostic(Span::sized(head.start, head_len as u32)));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "for (const x of [3, 4, 5]) { console.log(x); }",
+        "for (const x in { a: 1, b: 2, c: 3 }) { console.log(x); }",
+        "for (const x in obj) { console.log(x); }",
+        "for (const x in new Map()) { console.log(x); }",
+        "for (const x in Object.keys(obj)) { console.log(x); }",
+        "for (const x in foo.from(bar)) { console.log(x); }",
+        "class Array {} for (const x in new Array()) { console.log(x); }",
+        "function f(Array) { for (const x in Array.from(foo)) { console.log(x); } }",
+    ];
+
+    let fail = vec![
+        "for (const x in [3, 4, 5]) { console.log(x); }",
+        "for (const x in ([3, 4, 5])) { console.log(x); }",
+        "for (x in [1, 2]) {}",
+        "for (const x in new Array()) { console.log(x); }",
+        "for (const x in new Array(3)) { console.log(x); }",
+        "for (const x in Array(3)) { console.log(x); }",
+        "for (const x in Array.from(foo)) { console.log(x); }",
+        "for (const x in Array.of(1, 2, 3)) { console.log(x); }",
+        "for (const x in [1, 2] /* comment */ ) {}",
+        "for (const x in [1, 2]) console.log(x);",
+    ];
+
+    Tester::new(NoForInArray::NAME, NoForInArray::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -87,7 +87,8 @@ impl Rule for NoImportTypeSideEffects {
             return;
         }
 
-        let Some(specifiers) = &import_decl.specifiers else {
+        // `import {} from 'mod'` is already a side effect import.
+        let Some(specifiers) = import_decl.specifiers.as_ref().filter(|s| !s.is_empty()) else {
             return;
         };
 
@@ -152,6 +153,7 @@ fn test() {
         "import T, { type U } from 'mod';",
         "import type * as T from 'mod';",
         "import 'mod';",
+        "import {} from 'mod';",
     ];
 
     let fail = vec![
@@ -159,6 +161,10 @@ fn test() {
         "import { type A as AA } from 'mod';",
         "import { type A, type B } from 'mod';",
         "import { type A as AA, type B as BB } from 'mod';",
+        "import {
+          type A,
+          type B,
+        } from 'mod';",
     ];
 
     let fix = vec![
@@ -170,6 +176,17 @@ fn test() {
             "import type { A as AA, B as BB } from 'mod';",
             None,
         ),
+        (
+            "import {
+          type A,
+          type B,
+        } from 'mod';",
+            "import type {
+          A,
+          B,
+        } from 'mod';",
+            None,
+        ),
     ];
     Tester::new(NoImportTypeSideEffects::NAME, NoImportTypeSideEffects::PLUGIN, pass, fail)
         .expect_fix(fix)
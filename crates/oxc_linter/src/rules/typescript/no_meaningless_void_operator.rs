@@ -0,0 +1,217 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSType},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_meaningless_void_operator_diagnostic(operand: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`void` operator shouldn't be used on {operand}; it should convey that a return value is being ignored."
+    ))
+    .with_help("Remove the `void` operator.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMeaninglessVoidOperator {
+    check_never: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the `void` operator except when used to discard a value.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `void` operator is useful to explicitly discard a value, e.g. a promise returned by
+    /// a function call. Applying it to an expression which is already `undefined` does nothing
+    /// and only confuses readers.
+    ///
+    /// This rule does not use type information. It only checks operands which are `undefined`
+    /// by their syntax: the `undefined` global, other `void` expressions, and type assertions
+    /// to `void` or `undefined`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// void undefined;
+    ///
+    /// void void foo();
+    ///
+    /// void (foo() as void);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// void fetch();
+    ///
+    /// (() => {})();
+    ///
+    /// void 0;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### checkNever
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also report `void` applied to type assertions to `never`.
+    ///
+    /// Example:
+    /// ```json
+    /// "typescript/no-meaningless-void-operator": [
+    ///   "error",
+    ///   { "checkNever": true }
+    /// ]
+    /// ```
+    NoMeaninglessVoidOperator,
+    typescript,
+    correctness,
+    fix
+);
+
+impl Rule for NoMeaninglessVoidOperator {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let check_never = value
+            .get(0)
+            .and_then(|config| config.get("checkNever"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { check_never }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::UnaryExpression(unary) = node.kind() else {
+            return;
+        };
+        if unary.operator != UnaryOperator::Void {
+            return;
+        }
+        let Some(operand) = self.get_meaningless_operand(&unary.argument, ctx) else {
+            return;
+        };
+
+        // Report on the `void` keyword.
+        let diagnostic =
+            no_meaningless_void_operator_diagnostic(operand, Span::sized(unary.span.start, 4));
+        if ctx.has_comments_between(unary.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        // The operand of `void` binds at least as tightly as the `void` expression it replaces.
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            fixer.replace_with(&unary.span, &unary.argument).with_message("Remove `void`")
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+impl NoMeaninglessVoidOperator {
+    /// Describes `argument` if applying `void` to it is meaningless.
+    fn get_meaningless_operand(
+        &self,
+        argument: &Expression,
+        ctx: &LintContext,
+    ) -> Option<&'static str> {
+        match argument.without_parentheses() {
+            Expression::Identifier(ident)
+                if ident.name == "undefined" && ctx.is_reference_to_global_variable(ident) =>
+            {
+                Some("`undefined`")
+            }
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                Some("`undefined`")
+            }
+            Expression::TSAsExpression(expr) => self.get_meaningless_type(&expr.type_annotation),
+            Expression::TSSatisfiesExpression(expr) => {
+                self.get_meaningless_type(&expr.type_annotation)
+            }
+            Expression::TSTypeAssertion(expr) => self.get_meaningless_type(&expr.type_annotation),
+            _ => None,
+        }
+    }
+
+    fn get_meaningless_type(&self, ty: &TSType) -> Option<&'static str> {
+        match ty {
+            TSType::TSVoidKeyword(_) => Some("`void`"),
+            TSType::TSUndefinedKeyword(_) => Some("`undefined`"),
+            TSType::TSNeverKeyword(_) if self.check_never => Some("`never`"),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("void fetch();", None),
+        ("void fn();", None),
+        ("void foo;", None),
+        ("void (() => {})();", None),
+        ("void 0;", None),
+        ("void 'foo';", None),
+        ("void null;", None),
+        ("void (foo as never);", None),
+        ("void (foo as number);", None),
+        ("void (foo satisfies unknown);", None),
+        ("let undefined = 1; void undefined;", None),
+        ("function f(undefined: number) { return void undefined; }", None),
+        ("void fetch();", Some(json!([{ "checkNever": true }]))),
+        ("void 0;", Some(json!([{ "checkNever": true }]))),
+        ("void 'foo';", Some(json!([{ "checkNever": true }]))),
+        ("void [];", Some(json!([{ "checkNever": true }]))),
+        ("void `foo`;", Some(json!([{ "checkNever": true }]))),
+        ("void (foo as string);", Some(json!([{ "checkNever": true }]))),
+    ];
+
+    let fail = vec![
+        ("void undefined;", None),
+        ("void (undefined);", None),
+        ("const x = () => void undefined;", None),
+        ("void void foo();", None),
+        ("void (void foo());", None),
+        ("void (foo() as void);", None),
+        ("void (foo as undefined);", None),
+        ("void (foo satisfies void);", None),
+        ("void (foo as never);", Some(json!([{ "checkNever": true }]))),
+        ("void undefined;", Some(json!([{ "checkNever": true }]))),
+        ("void /* keep */ undefined;", None),
+        ("void void void foo();", None),
+    ];
+
+    let fix = vec![
+        ("void undefined;", "undefined;", None),
+        ("void (undefined);", "(undefined);", None),
+        ("const x = () => void undefined;", "const x = () => undefined;", None),
+        ("void void foo();", "void foo();", None),
+        ("void (void foo());", "(void foo());", None),
+        ("void void void foo();", "void void foo();", None),
+        ("void (foo() as void);", "(foo() as void);", None),
+        ("void (foo as never);", "(foo as never);", Some(json!([{ "checkNever": true }]))),
+        ("void /* keep */ undefined;", "void /* keep */ undefined;", None),
+    ];
+
+    Tester::new(NoMeaninglessVoidOperator::NAME, NoMeaninglessVoidOperator::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
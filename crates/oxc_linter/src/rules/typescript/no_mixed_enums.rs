@@ -0,0 +1,186 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSEnumMember},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_mixed_enums_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Mixing number and string enums can be confusing.")
+        .with_help("Use either only number or only string values for the members of this enum.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMixedEnums;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow enums from having both number and string members.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript enums are allowed to assign numeric or string values to their members.
+    /// Most enums contain either all numbers or all strings, but in theory you can mix-and-match
+    /// within the same enum. Mixing enum member types is generally considered confusing and a
+    /// bad practice.
+    ///
+    /// This rule does not use type information. Members are classified by their initializer:
+    /// members without an initializer and number literals or arithmetic on them are numbers,
+    /// string literals and template literals are strings. Members with any other initializer,
+    /// e.g. a reference to another enum member, are not checked.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// enum Status {
+    ///   Unknown,
+    ///   Closed = 1,
+    ///   Open = 'open',
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// enum Status {
+    ///   Unknown = 0,
+    ///   Closed = 1,
+    ///   Open = 2,
+    /// }
+    ///
+    /// enum Status {
+    ///   Unknown = 'unknown',
+    ///   Closed = 'closed',
+    ///   Open = 'open',
+    /// }
+    /// ```
+    NoMixedEnums,
+    typescript,
+    pedantic
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberType {
+    Number,
+    String,
+    Unknown,
+}
+
+impl Rule for NoMixedEnums {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TSEnumDeclaration(enum_decl) = node.kind() else {
+            return;
+        };
+        let Some(first_member) = enum_decl.body.members.first() else {
+            return;
+        };
+
+        let desired_type = get_member_type(first_member);
+        if desired_type == MemberType::Unknown {
+            return;
+        }
+
+        for member in enum_decl.body.members.iter().skip(1) {
+            let member_type = get_member_type(member);
+            if member_type != MemberType::Unknown && member_type != desired_type {
+                let span = member.initializer.as_ref().map_or(member.span, GetSpan::span);
+                ctx.diagnostic(no_mixed_enums_diagnostic(span));
+                return;
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn get_member_type(member: &TSEnumMember) -> MemberType {
+    member.initializer.as_ref().map_or(MemberType::Number, get_expression_type)
+}
+
+fn get_expression_type(expr: &Expression) -> MemberType {
+    match expr.get_inner_expression() {
+        Expression::NumericLiteral(_) => MemberType::Number,
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => MemberType::String,
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus | UnaryOperator::BitwiseNot => {
+                if get_expression_type(&unary.argument) == MemberType::Number {
+                    MemberType::Number
+                } else {
+                    MemberType::Unknown
+                }
+            }
+            _ => MemberType::Unknown,
+        },
+        Expression::BinaryExpression(binary) => {
+            let left = get_expression_type(&binary.left);
+            let right = get_expression_type(&binary.right);
+            if binary.operator == BinaryOperator::Addition
+                && (left == MemberType::String || right == MemberType::String)
+            {
+                MemberType::String
+            } else if binary.operator.is_arithmetic() || binary.operator.is_bitwise() {
+                if left == MemberType::Number && right == MemberType::Number {
+                    MemberType::Number
+                } else {
+                    MemberType::Unknown
+                }
+            } else {
+                MemberType::Unknown
+            }
+        }
+        _ => MemberType::Unknown,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "enum Fruit {}",
+        "enum Fruit { Apple }",
+        "enum Fruit { Apple, Banana }",
+        "enum Fruit { Apple = 0, Banana = 1 }",
+        "enum Fruit { Apple, Banana = 1 }",
+        "enum Fruit { Apple = -1, Banana = +2, Cherry = ~3 }",
+        "enum Fruit { Apple = 1 << 0, Banana = 1 << 1, Cherry = Apple | Banana }",
+        "enum Fruit { Apple = 'apple' }",
+        "enum Fruit { Apple = 'apple', Banana = 'banana' }",
+        "enum Fruit { Apple = `apple`, Banana = 'banana' }",
+        "enum Fruit { Apple = 'app' + 'le', Banana = 'banana' }",
+        "enum Fruit { Apple = 'apple', Banana = Apple }",
+        "enum Fruit { Apple = 1, Banana = Apple }",
+        "enum Fruit { Apple = foo(), Banana = 'banana', Cherry = 1 }",
+        "enum Fruit { Apple = 0, Banana = (1) }",
+        "declare enum Fruit { Apple, Banana = 1 }",
+        "const enum Fruit { Apple = 'apple', Banana = 'banana' }",
+    ];
+
+    let fail = vec![
+        "enum Fruit { Apple, Banana = 'banana' }",
+        "enum Fruit { Apple = 0, Banana = 'banana' }",
+        "enum Fruit { Apple = 'apple', Banana = 1 }",
+        "enum Fruit { Apple = 'apple', Banana = 'banana', Cherry = -1 }",
+        "enum Fruit { Apple, Banana = `banana` }",
+        "enum Fruit { Apple, Banana = 'ban' + 'ana' }",
+        "enum Fruit { Apple = 1, Banana = 2 + 'x' }",
+        "enum Fruit { Apple = 0, Banana = 'banana', Cherry = 'cherry' }",
+        "enum Fruit { Apple = 0, Banana = foo(), Cherry = 'cherry' }",
+        "const enum Fruit { Apple, Banana = 'banana' }",
+        "export enum Fruit { Apple = 'apple', Banana = (1) }",
+    ];
+
+    Tester::new(NoMixedEnums::NAME, NoMixedEnums::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -119,9 +119,17 @@ declare_oxc_lint!(
     /// declare module 'foo' {}
     /// // anything inside a d.ts file
     /// ```
+    ///
+    /// `allowDefinitionFiles` only applies to whole `.d.ts` files. Ambient namespaces inside
+    /// regular `.ts` files, such as `declare namespace foo {}` or namespaces nested in
+    /// `declare global {}`, are only allowed by `allowDeclarations`.
+    ///
+    /// When `allowDefinitionFiles` is `false`, `.d.ts` files are linted like any other file, so
+    /// `allowDeclarations` only allows the namespaces declared with `declare` in them.
     NoNamespace,
     typescript,
-    restriction
+    restriction,
+    suggestion
 );
 
 impl Rule for NoNamespace {
@@ -174,7 +182,16 @@ impl Rule for NoNamespace {
                 .find("namespace")
                 .map(|i| Span::sized(declaration.span.start + i as u32, 9)),
         };
-        if let Some(span) = span {
+        let Some(span) = span else {
+            return;
+        };
+
+        if self.allow_declarations {
+            // `declare` goes after any `export` modifier, e.g. `export declare namespace foo {}`
+            ctx.diagnostic_with_suggestion(no_namespace_diagnostic(span), |fixer| {
+                fixer.insert_text_before_keyword(declaration.span, "declare ")
+            });
+        } else {
             ctx.diagnostic(no_namespace_diagnostic(span));
         }
     }
@@ -238,6 +255,10 @@ fn test() {
     		 }",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
         ),
+        (
+            "declare namespace foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": true, "allowDefinitionFiles": false }])),
+        ),
     ];
 
     let fail = vec![
@@ -388,7 +409,37 @@ fn test() {
     		 }",
             Some(serde_json::json!([{ "allowDeclarations": true }])),
         ),
+        ("declare namespace foo {}", Some(serde_json::json!([{ "allowDefinitionFiles": true }]))),
+        (
+            "declare global { namespace foo {} }",
+            Some(serde_json::json!([{ "allowDefinitionFiles": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "module foo {}",
+            "declare module foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": true }])),
+        ),
+        (
+            "namespace foo {}",
+            "declare namespace foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": true }])),
+        ),
+        (
+            "export namespace foo {}",
+            "export declare namespace foo {}",
+            Some(serde_json::json!([{ "allowDeclarations": true }])),
+        ),
+        (
+            "export  namespace Foo.Bar {}",
+            "export  declare namespace Foo.Bar {}",
+            Some(serde_json::json!([{ "allowDeclarations": true }])),
+        ),
     ];
 
-    Tester::new(NoNamespace::NAME, NoNamespace::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoNamespace::NAME, NoNamespace::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     AstKind,
-    ast::{ChainElement, Expression, match_member_expression},
+    ast::{CallExpression, ChainElement, Expression, MemberExpression, match_member_expression},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -66,39 +66,14 @@ impl Rule for NoNonNullAssertedOptionalChain {
             return;
         };
 
+        #[expect(clippy::unnested_or_patterns)]
         let chain_span = match non_null_expr.expression.get_inner_expression() {
-            Expression::ChainExpression(chain) => match &chain.expression {
-                ChainElement::ComputedMemberExpression(member) if member.optional => {
-                    Some(member.object.span())
-                }
-                ChainElement::StaticMemberExpression(member) if member.optional => {
-                    Some(member.object.span())
-                }
-                ChainElement::PrivateFieldExpression(member) if member.optional => {
-                    Some(member.object.span())
-                }
-                ChainElement::CallExpression(call) if call.optional => Some(call.callee.span()),
-                _ => None,
-            },
-            Expression::CallExpression(call) => {
-                if call.optional && !is_parent_member_or_call(node, ctx) {
-                    Some(call.callee.span())
-                } else if let Some(member) = call.callee.as_member_expression() {
-                    if member.optional() && !is_parent_member_or_call(node, ctx) {
-                        Some(member.object().span())
-                    } else {
-                        None
-                    }
-                } else {
+            Expression::ChainExpression(chain) => chain_element_optional_span(&chain.expression),
+            expr @ (Expression::CallExpression(_) | match_member_expression!(Expression)) => {
+                if is_parent_member_or_call(node, ctx) {
                     None
-                }
-            }
-            expr @ match_member_expression!(Expression) => {
-                let member_expr = expr.to_member_expression();
-                if member_expr.optional() && !is_parent_member_or_call(node, ctx) {
-                    Some(member_expr.object().span())
                 } else {
-                    None
+                    optional_span(expr)
                 }
             }
             _ => None,
@@ -111,7 +86,6 @@ impl Rule for NoNonNullAssertedOptionalChain {
                 Span::sized(chain_span_end, 1),
                 Span::sized(non_null_end, 1),
             );
-            // ctx.diagnostic(diagnostic);
             ctx.diagnostic_with_fix(diagnostic, |fixer| {
                 fixer.delete_range(Span::sized(non_null_end, 1))
             });
@@ -123,6 +97,35 @@ impl Rule for NoNonNullAssertedOptionalChain {
     }
 }
 
+/// Returns the span of the object or callee before the last `?.` of an optional chain,
+/// e.g. `foo` in `foo?.bar.baz`.
+fn optional_span(expr: &Expression) -> Option<Span> {
+    match expr {
+        Expression::CallExpression(call) => call_optional_span(call),
+        Expression::TSNonNullExpression(expr) => optional_span(&expr.expression),
+        expr @ match_member_expression!(Expression) => {
+            member_optional_span(expr.to_member_expression())
+        }
+        _ => None,
+    }
+}
+
+fn chain_element_optional_span(element: &ChainElement) -> Option<Span> {
+    match element {
+        ChainElement::CallExpression(call) => call_optional_span(call),
+        ChainElement::TSNonNullExpression(expr) => optional_span(&expr.expression),
+        element => member_optional_span(element.as_member_expression()?),
+    }
+}
+
+fn call_optional_span(call: &CallExpression) -> Option<Span> {
+    if call.optional { Some(call.callee.span()) } else { optional_span(&call.callee) }
+}
+
+fn member_optional_span(member: &MemberExpression) -> Option<Span> {
+    if member.optional() { Some(member.object().span()) } else { optional_span(member.object()) }
+}
+
 fn is_parent_member_or_call(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
     let parent_kind = ctx.nodes().parent_kind(node.id());
     matches!(parent_kind, AstKind::CallExpression(_)) || parent_kind.is_member_expression_kind()
@@ -147,6 +150,10 @@ fn test() {
         "foo?.bar!();",
         "foo?.['bar']!.baz;",
         "foo?.get()!.bar()",
+        "foo?.bar.baz!.qux;",
+        "(foo?.bar.baz).qux!;",
+        "foo.bar.baz!;",
+        "foo.bar().baz!;",
     ];
 
     let fail = vec![
@@ -160,6 +167,11 @@ fn test() {
         "(foo?.bar)!()",
         "(foo?.bar!)",
         "(foo?.bar!)()",
+        "foo?.bar.baz!;",
+        "foo?.bar().baz!;",
+        "foo?.bar.baz()!;",
+        "(foo?.bar.baz)!;",
+        "foo?.bar!.baz!;",
     ];
 
     let fix = vec![
@@ -172,6 +184,8 @@ fn test() {
         ("(foo?.bar)!()", "(foo?.bar)()"),
         ("(foo?.bar!)", "(foo?.bar)"),
         ("(foo?.bar!)()", "(foo?.bar)()"),
+        ("foo?.bar.baz!", "foo?.bar.baz"),
+        ("(foo?.bar.baz)!", "(foo?.bar.baz)"),
     ];
 
     Tester::new(
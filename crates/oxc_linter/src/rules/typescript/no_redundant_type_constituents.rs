@@ -0,0 +1,453 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSLiteral, TSType, TSTypeAnnotation},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn literal_overridden_diagnostic(literal: &str, primitive: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{literal} is overridden by {primitive} in this union type."))
+        .with_help(format!("Remove `{literal}` from the union type."))
+        .with_label(span)
+}
+
+fn primitive_overridden_diagnostic(primitive: &str, literal: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{primitive} is overridden by the {literal} in this intersection type."
+    ))
+    .with_help(format!("Remove `{primitive}` from the intersection type."))
+    .with_label(span)
+}
+
+fn overridden_diagnostic(type_name: &str, container: Container, span: Span) -> OxcDiagnostic {
+    let container = container.as_str();
+    OxcDiagnostic::warn(format!(
+        "'{type_name}' is overridden by other types in this {container} type."
+    ))
+    .with_help(format!("Remove `{type_name}` from the {container} type."))
+    .with_label(span)
+}
+
+fn overrides_diagnostic(type_name: &str, container: Container, span: Span) -> OxcDiagnostic {
+    let container = container.as_str();
+    OxcDiagnostic::warn(format!(
+        "'{type_name}' overrides all other types in this {container} type."
+    ))
+    .with_help(format!("Replace the {container} type with `{type_name}`."))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRedundantTypeConstituents;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow members of unions and intersections that do nothing or override type information.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some types can override some other types ("constituents") in a union or intersection
+    /// and/or be overridden by some other types. TypeScript's set theory of types includes
+    /// cases where a constituent type might be useless in the parent union or intersection.
+    ///
+    /// Within `|` unions:
+    /// - `any` and `unknown` "override" all other union members
+    /// - `never` is dropped from unions in any position except when in a return type position
+    /// - primitive types such as `string` "override" any of their literal types such as `""`
+    ///
+    /// Within `&` intersections:
+    /// - `any` and `never` "override" all other intersection members
+    /// - `unknown` is dropped from intersections
+    /// - literal types "override" any primitive types in an intersection
+    /// - literal types such as `""` "override" any of their primitive types such as `string`
+    ///
+    /// This rule does not use type information, so it only checks the constituents as they are
+    /// written. Type aliases, such as `type Name = string`, and nested unions or intersections
+    /// are not resolved.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// type UnionAny = any | 'foo';
+    /// type UnionUnknown = unknown | 'foo';
+    /// type UnionNever = never | 'foo';
+    ///
+    /// type UnionBooleanLiteral = boolean | false;
+    /// type UnionNumberLiteral = number | 1;
+    /// type UnionStringLiteral = string | 'foo';
+    ///
+    /// type IntersectionAny = any & 'foo';
+    /// type IntersectionUnknown = string & unknown;
+    /// type IntersectionNever = string & never;
+    ///
+    /// type IntersectionBooleanLiteral = boolean & false;
+    /// type IntersectionNumberLiteral = number & 1;
+    /// type IntersectionStringLiteral = string & 'foo';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// type UnionAny = any;
+    /// type UnionUnknown = unknown;
+    /// type UnionNever = never;
+    ///
+    /// type UnionBooleanLiteral = boolean;
+    /// type UnionNumberLiteral = number;
+    /// type UnionStringLiteral = string;
+    ///
+    /// type IntersectionAny = any;
+    /// type IntersectionUnknown = string;
+    /// type IntersectionNever = string;
+    ///
+    /// type IntersectionBooleanLiteral = false;
+    /// type IntersectionNumberLiteral = 1;
+    /// type IntersectionStringLiteral = 'foo';
+    /// ```
+    NoRedundantTypeConstituents,
+    typescript,
+    suspicious,
+    fix
+);
+
+#[derive(Debug, Clone, Copy)]
+enum Container {
+    Union,
+    Intersection,
+}
+
+impl Container {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Union => "union",
+            Self::Intersection => "intersection",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Primitive {
+    BigInt,
+    Boolean,
+    Number,
+    String,
+}
+
+impl Primitive {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::BigInt => "bigint",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+            Self::String => "string",
+        }
+    }
+
+    /// Returns the primitive of a `string`, `number`, `bigint` or `boolean` keyword.
+    fn from_keyword(ty: &TSType) -> Option<Self> {
+        match ty {
+            TSType::TSBigIntKeyword(_) => Some(Self::BigInt),
+            TSType::TSBooleanKeyword(_) => Some(Self::Boolean),
+            TSType::TSNumberKeyword(_) => Some(Self::Number),
+            TSType::TSStringKeyword(_) => Some(Self::String),
+            _ => None,
+        }
+    }
+
+    /// Returns the primitive a literal type, such as `'foo'` or `1`, belongs to.
+    fn from_literal(ty: &TSType) -> Option<Self> {
+        let TSType::TSLiteralType(literal) = ty else {
+            return None;
+        };
+        match &literal.literal {
+            TSLiteral::BigIntLiteral(_) => Some(Self::BigInt),
+            TSLiteral::BooleanLiteral(_) => Some(Self::Boolean),
+            TSLiteral::NumericLiteral(_) => Some(Self::Number),
+            TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => Some(Self::String),
+            TSLiteral::UnaryExpression(unary) => match &unary.argument {
+                Expression::NumericLiteral(_) => Some(Self::Number),
+                Expression::BigIntLiteral(_) => Some(Self::BigInt),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl Rule for NoRedundantTypeConstituents {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSUnionType(union) => {
+                check_union(&union.types, union.span, is_return_type(node, ctx), ctx);
+            }
+            AstKind::TSIntersectionType(intersection) => {
+                check_intersection(&intersection.types, intersection.span, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn check_union<'a>(types: &[TSType<'a>], span: Span, is_return_type: bool, ctx: &LintContext<'a>) {
+    if types.len() < 2 {
+        return;
+    }
+
+    for (index, ty) in types.iter().enumerate() {
+        match ty.without_parenthesized() {
+            TSType::TSAnyKeyword(_) => {
+                report_overrides("any", ty, span, Container::Union, ctx);
+                return;
+            }
+            TSType::TSUnknownKeyword(_) => {
+                report_overrides("unknown", ty, span, Container::Union, ctx);
+                return;
+            }
+            // `never` documents that a function may not return, e.g. `string | never`
+            TSType::TSNeverKeyword(_) if !is_return_type => {
+                report_overridden(
+                    overridden_diagnostic("never", Container::Union, ty.span()),
+                    types,
+                    index,
+                    ctx,
+                );
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    let primitives = types
+        .iter()
+        .filter_map(|ty| Primitive::from_keyword(ty.without_parenthesized()))
+        .collect::<Vec<_>>();
+    if primitives.is_empty() {
+        return;
+    }
+
+    for (index, ty) in types.iter().enumerate() {
+        let Some(primitive) = Primitive::from_literal(ty.without_parenthesized()) else {
+            continue;
+        };
+        if primitives.contains(&primitive) {
+            let literal = ctx.source_range(ty.span());
+            report_overridden(
+                literal_overridden_diagnostic(literal, primitive.as_str(), ty.span()),
+                types,
+                index,
+                ctx,
+            );
+        }
+    }
+}
+
+fn check_intersection<'a>(types: &[TSType<'a>], span: Span, ctx: &LintContext<'a>) {
+    if types.len() < 2 {
+        return;
+    }
+
+    for (index, ty) in types.iter().enumerate() {
+        match ty.without_parenthesized() {
+            TSType::TSAnyKeyword(_) => {
+                report_overrides("any", ty, span, Container::Intersection, ctx);
+                return;
+            }
+            TSType::TSNeverKeyword(_) => {
+                report_overrides("never", ty, span, Container::Intersection, ctx);
+                return;
+            }
+            TSType::TSUnknownKeyword(_) => {
+                report_overridden(
+                    overridden_diagnostic("unknown", Container::Intersection, ty.span()),
+                    types,
+                    index,
+                    ctx,
+                );
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    for (index, ty) in types.iter().enumerate() {
+        let Some(primitive) = Primitive::from_keyword(ty.without_parenthesized()) else {
+            continue;
+        };
+        let literals = types
+            .iter()
+            .filter(|ty| Primitive::from_literal(ty.without_parenthesized()) == Some(primitive))
+            .map(|ty| ctx.source_range(ty.span()))
+            .collect::<Vec<_>>();
+        if literals.is_empty() {
+            continue;
+        }
+        report_overridden(
+            primitive_overridden_diagnostic(primitive.as_str(), &literals.join(" | "), ty.span()),
+            types,
+            index,
+            ctx,
+        );
+    }
+}
+
+/// Whether `node` is the return type annotation of a function or method signature.
+fn is_return_type(node: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let AstKind::TSTypeAnnotation(annotation) = nodes.parent_kind(node.id()) else {
+        return false;
+    };
+    let return_type: Option<&TSTypeAnnotation> = match nodes.parent_kind(nodes.parent_id(node.id()))
+    {
+        AstKind::Function(func) => func.return_type.as_deref(),
+        AstKind::ArrowFunctionExpression(func) => func.return_type.as_deref(),
+        AstKind::TSMethodSignature(signature) => signature.return_type.as_deref(),
+        AstKind::TSCallSignatureDeclaration(signature) => signature.return_type.as_deref(),
+        _ => None,
+    };
+    return_type.is_some_and(|return_type| return_type.span == annotation.span)
+}
+
+/// Reports a constituent that overrides all others, the fix replaces the whole type with it.
+fn report_overrides(
+    type_name: &str,
+    ty: &TSType,
+    container_span: Span,
+    container: Container,
+    ctx: &LintContext,
+) {
+    let diagnostic = overrides_diagnostic(type_name, container, ty.span());
+    if ctx.has_comments_between(container_span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        fixer.replace(container_span, type_name.to_string())
+    });
+}
+
+/// Reports a constituent that is overridden by others, the fix removes it together with its
+/// `|` or `&` separator.
+fn report_overridden(diagnostic: OxcDiagnostic, types: &[TSType], index: usize, ctx: &LintContext) {
+    let span = types[index].span();
+    let removal_span = if index > 0 {
+        Span::new(types[index - 1].span().end, span.end)
+    } else {
+        Span::new(span.start, types[1].span().start)
+    };
+    if ctx.has_comments_between(removal_span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(removal_span));
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "type T = number | string;",
+        "type T = 1 | 2;",
+        "type T = 'a' | 'b';",
+        "type T = true | false;",
+        "type T = 1n | 2n;",
+        "type T = string | number | boolean | bigint;",
+        "type T = string | `a${string}`;",
+        "type T = (string & {}) | 'a';",
+        "type T = Foo | 'a';",
+        "type T = string | Foo<'a'>;",
+        "type T = string | { a: 'a' };",
+        "type T = string | ['a'];",
+        "type T = number | 'a';",
+        "type T = string | 1;",
+        "type T = bigint | 1;",
+        "type T = number | 1n;",
+        "type T = boolean | 'true';",
+        "type T = number & string;",
+        "type T = 'a' & 'b';",
+        "type T = string & { __brand: 'a' };",
+        "type T = number & 'a';",
+        "type T = Foo & Bar;",
+        "type T = string;",
+        "type T = any;",
+        "type T = unknown;",
+        "type T = never;",
+        "type T = Array<any>;",
+        "function f(): string | Foo {}",
+        "function f(): string | never {}",
+        "const f = (): never | number => 1;",
+        "interface I { m(): string | never }",
+    ];
+
+    let fail = vec![
+        "type T = any | string;",
+        "type T = string | any;",
+        "type T = unknown | string;",
+        "type T = string | (unknown);",
+        "type T = never | string;",
+        "type T = string | never;",
+        "type T = string | 'a';",
+        "type T = 'a' | string;",
+        "type T = string | 'a' | 'b';",
+        "type T = string | `a`;",
+        "type T = number | 1;",
+        "type T = number | -1;",
+        "type T = bigint | 1n;",
+        "type T = boolean | false;",
+        "type T = number | string | 1 | 'a';",
+        "type T = any & string;",
+        "type T = string & never;",
+        "type T = unknown & string;",
+        "type T = string & unknown;",
+        "type T = string & 'a';",
+        "type T = 'a' & string;",
+        "type T = number & 1;",
+        "type T = boolean & false;",
+        "type T = bigint & 1n;",
+        "function f(a: string | 'a') {}",
+        "let a: Array<number | 1>;",
+        "type T = string | /* comment */ 'a';",
+        "function f(a: string | never) {}",
+        "function f(): string | any {}",
+        "type U<T> = T | any;",
+        "type U<T> = T | never;",
+        "type U<T> = T & unknown;",
+    ];
+
+    let fix = vec![
+        ("type T = any | string;", "type T = any;"),
+        ("type T = string | unknown;", "type T = unknown;"),
+        ("type T = never | string;", "type T = string;"),
+        ("type T = string | never;", "type T = string;"),
+        ("type T = string | 'a';", "type T = string;"),
+        ("type T = 'a' | string;", "type T = string;"),
+        ("type T = string | 'a' | 'b';", "type T = string;"),
+        ("type T = number | 1 | string;", "type T = number | string;"),
+        ("type T = boolean | false;", "type T = boolean;"),
+        ("type T = any & string;", "type T = any;"),
+        ("type T = string & never;", "type T = never;"),
+        ("type T = unknown & string;", "type T = string;"),
+        ("type T = string & 'a';", "type T = 'a';"),
+        ("type T = 'a' & string;", "type T = 'a';"),
+        ("function f(a: string | 'a') {}", "function f(a: string) {}"),
+        ("type T = string | /* comment */ 'a';", "type T = string | /* comment */ 'a';"),
+        ("type U<T> = T | any;", "type U<T> = any;"),
+        ("type U<T> = T | never;", "type U<T> = T;"),
+        ("type U<T> = T & unknown;", "type U<T> = T;"),
+    ];
+
+    Tester::new(NoRedundantTypeConstituents::NAME, NoRedundantTypeConstituents::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
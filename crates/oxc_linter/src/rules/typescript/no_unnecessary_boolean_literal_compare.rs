@@ -0,0 +1,416 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSLiteral, TSType},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+use crate::{
+    AstNode,
+    ast_util::outermost_paren_parent,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn boolean_compare_diagnostic(negated: bool, span: Span) -> OxcDiagnostic {
+    if negated {
+        OxcDiagnostic::warn(
+            "This expression unnecessarily compares a boolean value to a boolean instead of negating it.",
+        )
+        .with_help("Negate the boolean value instead of comparing it.")
+        .with_label(span)
+    } else {
+        OxcDiagnostic::warn(
+            "This expression unnecessarily compares a boolean value to a boolean instead of using it directly.",
+        )
+        .with_help("Use the boolean value directly instead of comparing it.")
+        .with_label(span)
+    }
+}
+
+fn nullable_compare_to_true_diagnostic(negated: bool, span: Span) -> OxcDiagnostic {
+    if negated {
+        OxcDiagnostic::warn(
+            "This expression unnecessarily compares a nullable boolean value to true instead of negating it.",
+        )
+        .with_help("Negate the nullable boolean value instead of comparing it.")
+        .with_label(span)
+    } else {
+        OxcDiagnostic::warn(
+            "This expression unnecessarily compares a nullable boolean value to true instead of using it directly.",
+        )
+        .with_help("Use the nullable boolean value directly instead of comparing it.")
+        .with_label(span)
+    }
+}
+
+fn nullable_compare_to_false_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "This expression unnecessarily compares a nullable boolean value to false instead of using the ?? operator to provide a default.",
+    )
+    .with_help("Use `?? true` to treat nullish values as `true` instead of comparing to `false`.")
+    .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnnecessaryBooleanLiteralCompare {
+    allow_comparing_nullable_booleans_to_true: bool,
+    allow_comparing_nullable_booleans_to_false: bool,
+}
+
+impl Default for NoUnnecessaryBooleanLiteralCompare {
+    fn default() -> Self {
+        Self {
+            allow_comparing_nullable_booleans_to_true: true,
+            allow_comparing_nullable_booleans_to_false: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary equality comparisons against boolean literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Comparing boolean values to boolean literals is unnecessary: those comparisons result
+    /// in the same booleans. Using the boolean values directly, or via a unary negation
+    /// (`!value`), is more concise and clearer.
+    ///
+    /// This rule does not use type information. It only checks operands that are booleans
+    /// by their syntax: negations (`!x`), comparisons (`a < b`, `a === b`, `a in b`,
+    /// `a instanceof b`), boolean literals, and type assertions to `boolean`.
+    /// An assertion to a union of `boolean` with `null` or `undefined`, such as
+    /// `x as boolean | undefined`, is a nullable boolean.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// declare const a: number;
+    /// declare const b: number;
+    ///
+    /// if (!a === true) {}
+    /// if ((a < b) === false) {}
+    /// if ((a as boolean) !== false) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// declare const a: number;
+    /// declare const b: number;
+    ///
+    /// if (!a) {}
+    /// if (!(a < b)) {}
+    /// if (a as boolean) {}
+    ///
+    /// // not a boolean by its syntax
+    /// if (a === true) {}
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowComparingNullableBooleansToTrue
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether to allow comparisons of nullable booleans to `true`.
+    /// When set to `false`, `(x as boolean | undefined) === true` is reported and fixed to
+    /// `(x as boolean | undefined)`.
+    ///
+    /// #### allowComparingNullableBooleansToFalse
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether to allow comparisons of nullable booleans to `false`.
+    /// When set to `false`, `(x as boolean | undefined) === false` is reported and fixed to
+    /// `!((x as boolean | undefined) ?? true)`.
+    NoUnnecessaryBooleanLiteralCompare,
+    typescript,
+    pedantic,
+    fix
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BooleanKind {
+    Boolean,
+    Nullable,
+}
+
+impl Rule for NoUnnecessaryBooleanLiteralCompare {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            allow_comparing_nullable_booleans_to_true: config
+                .and_then(|config| config.get("allowComparingNullableBooleansToTrue"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+            allow_comparing_nullable_booleans_to_false: config
+                .and_then(|config| config.get("allowComparingNullableBooleansToFalse"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(binary) = node.kind() else {
+            return;
+        };
+        let negated = match binary.operator {
+            BinaryOperator::Equality | BinaryOperator::StrictEquality => false,
+            BinaryOperator::Inequality | BinaryOperator::StrictInequality => true,
+            _ => return,
+        };
+        let (literal, expr) = match (&binary.left, &binary.right) {
+            (_, Expression::BooleanLiteral(literal)) => (literal.value, &binary.left),
+            (Expression::BooleanLiteral(literal), _) => (literal.value, &binary.right),
+            _ => return,
+        };
+        let Some(kind) = get_boolean_kind(expr) else {
+            return;
+        };
+
+        // `x === false` and `x !== true` need the value to be negated
+        let should_negate = literal == negated;
+        let diagnostic = match kind {
+            BooleanKind::Boolean => boolean_compare_diagnostic(should_negate, binary.span),
+            BooleanKind::Nullable if literal => {
+                if self.allow_comparing_nullable_booleans_to_true {
+                    return;
+                }
+                nullable_compare_to_true_diagnostic(should_negate, binary.span)
+            }
+            BooleanKind::Nullable => {
+                if self.allow_comparing_nullable_booleans_to_false {
+                    return;
+                }
+                nullable_compare_to_false_diagnostic(binary.span)
+            }
+        };
+
+        // `!(x === false)` is simplified to `x` as a whole
+        let (fix_span, should_negate) = match outermost_paren_parent(node, ctx).map(AstNode::kind) {
+            Some(AstKind::UnaryExpression(unary))
+                if unary.operator == UnaryOperator::LogicalNot =>
+            {
+                (unary.span, !should_negate)
+            }
+            _ => (binary.span, should_negate),
+        };
+        if ctx.has_comments_between(fix_span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let text = ctx.source_range(expr.span());
+            let operand =
+                if needs_parentheses(expr) { format!("({text})") } else { text.to_string() };
+            let replacement = if kind == BooleanKind::Nullable && !literal {
+                if should_negate {
+                    format!("!({operand} ?? true)")
+                } else {
+                    format!("({operand} ?? true)")
+                }
+            } else if should_negate {
+                format!("!{operand}")
+            } else if fix_span == binary.span {
+                // the operand binds at least as tightly as the comparison it replaces
+                text.to_string()
+            } else {
+                operand
+            };
+            fixer.replace(fix_span, replacement)
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn get_boolean_kind(expr: &Expression) -> Option<BooleanKind> {
+    match expr.without_parentheses() {
+        Expression::BooleanLiteral(_) => Some(BooleanKind::Boolean),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+            Some(BooleanKind::Boolean)
+        }
+        Expression::BinaryExpression(binary)
+            if binary.operator.is_equality()
+                || binary.operator.is_compare()
+                || binary.operator.is_relational() =>
+        {
+            Some(BooleanKind::Boolean)
+        }
+        Expression::TSAsExpression(expr) => get_type_boolean_kind(&expr.type_annotation),
+        Expression::TSSatisfiesExpression(expr) => get_type_boolean_kind(&expr.type_annotation),
+        Expression::TSTypeAssertion(expr) => get_type_boolean_kind(&expr.type_annotation),
+        _ => None,
+    }
+}
+
+/// Returns whether `ty` is `boolean`, or a union of `boolean` with `null` or `undefined`.
+fn get_type_boolean_kind(ty: &TSType) -> Option<BooleanKind> {
+    let is_boolean = |ty: &TSType| match ty {
+        TSType::TSBooleanKeyword(_) => true,
+        TSType::TSLiteralType(literal) => matches!(literal.literal, TSLiteral::BooleanLiteral(_)),
+        _ => false,
+    };
+    match ty.without_parenthesized() {
+        TSType::TSUnionType(union) => {
+            let mut has_boolean = false;
+            let mut has_nullish = false;
+            for ty in &union.types {
+                match ty.without_parenthesized() {
+                    TSType::TSNullKeyword(_) | TSType::TSUndefinedKeyword(_) => has_nullish = true,
+                    ty if is_boolean(ty) => has_boolean = true,
+                    _ => return None,
+                }
+            }
+            match (has_boolean, has_nullish) {
+                (true, true) => Some(BooleanKind::Nullable),
+                (true, false) => Some(BooleanKind::Boolean),
+                _ => None,
+            }
+        }
+        ty if is_boolean(ty) => Some(BooleanKind::Boolean),
+        _ => None,
+    }
+}
+
+/// Whether `expr` has to be wrapped in parentheses to be negated with `!`.
+fn needs_parentheses(expr: &Expression) -> bool {
+    !matches!(
+        expr,
+        Expression::Identifier(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::ThisExpression(_)
+            | Expression::ParenthesizedExpression(_)
+            | Expression::UnaryExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ChainExpression(_)
+            | Expression::StaticMemberExpression(_)
+            | Expression::ComputedMemberExpression(_)
+            | Expression::PrivateFieldExpression(_)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("declare const x: boolean; x === true;", None),
+        ("declare const x: number; x === false;", None),
+        ("foo() === true;", None),
+        ("a.b !== false;", None),
+        ("(a + b) === true;", None),
+        ("!a === 1;", None),
+        ("(a < b) === 'true';", None),
+        ("!a > true;", None),
+        ("(x as string) === true;", None),
+        ("(x as boolean | string) === false;", None),
+        ("(x as null | undefined) === true;", None),
+        ("(x as boolean | undefined) === true;", None),
+        ("(x as boolean | null) !== false;", None),
+        (
+            "(x as boolean | undefined) === false;",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToTrue": false }])),
+        ),
+        (
+            "(x as boolean | undefined) === true;",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToFalse": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("!a === true;", None),
+        ("!a === false;", None),
+        ("!a !== true;", None),
+        ("!a !== false;", None),
+        ("!a == true;", None),
+        ("!a != false;", None),
+        ("true === !a;", None),
+        ("false !== !a;", None),
+        ("(a < b) === true;", None),
+        ("(a === b) === false;", None),
+        ("(a in b) !== false;", None),
+        ("(a instanceof B) !== true;", None),
+        ("true === true;", None),
+        ("(x as boolean) === true;", None),
+        ("(x satisfies boolean) === false;", None),
+        ("(x as true | false) !== false;", None),
+        ("if (!(!a === false)) {}", None),
+        ("if ((!a /* comment */) === false) {}", None),
+        (
+            "(x as boolean | undefined) === true;",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToTrue": false }])),
+        ),
+        (
+            "(x as boolean | null) !== true;",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToTrue": false }])),
+        ),
+        (
+            "(x as boolean | undefined) === false;",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToFalse": false }])),
+        ),
+        (
+            "(x as boolean | null | undefined) !== false;",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToFalse": false }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("!a === true;", "!a;", None),
+        ("!a === false;", "!!a;", None),
+        ("!a !== true;", "!!a;", None),
+        ("!a !== false;", "!a;", None),
+        ("true === !a;", "!a;", None),
+        ("(a < b) === true;", "(a < b);", None),
+        ("(a < b) === false;", "!(a < b);", None),
+        ("a < b === false;", "!(a < b);", None),
+        ("a < b !== false;", "a < b;", None),
+        ("if (!(!a === false)) {}", "if (!a) {}", None),
+        ("if (!(a < b === false)) {}", "if ((a < b)) {}", None),
+        ("foo(!(a < b === true));", "foo(!(a < b));", None),
+        ("(x as boolean) !== true;", "!(x as boolean);", None),
+        ("if ((!a /* comment */) === false) {}", "if ((!a /* comment */) === false) {}", None),
+        (
+            "(x as boolean | undefined) === true;",
+            "(x as boolean | undefined);",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToTrue": false }])),
+        ),
+        (
+            "(x as boolean | undefined) !== true;",
+            "!(x as boolean | undefined);",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToTrue": false }])),
+        ),
+        (
+            "(x as boolean | undefined) === false;",
+            "!((x as boolean | undefined) ?? true);",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToFalse": false }])),
+        ),
+        (
+            "(x as boolean | undefined) !== false;",
+            "((x as boolean | undefined) ?? true);",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToFalse": false }])),
+        ),
+        (
+            "if (!((x as boolean | undefined) === false)) {}",
+            "if (((x as boolean | undefined) ?? true)) {}",
+            Some(serde_json::json!([{ "allowComparingNullableBooleansToFalse": false }])),
+        ),
+    ];
+
+    Tester::new(
+        NoUnnecessaryBooleanLiteralCompare::NAME,
+        NoUnnecessaryBooleanLiteralCompare::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
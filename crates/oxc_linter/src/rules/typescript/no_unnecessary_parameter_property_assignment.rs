@@ -1,3 +1,4 @@
+use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::{
     AstKind,
     ast::{
@@ -5,12 +6,12 @@ use oxc_ast::{
         FormalParameter, Function, MethodDefinitionKind, Statement,
     },
 };
-use oxc_ast_visit::Visit;
+use oxc_ast_visit::{Visit, walk};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::ScopeFlags;
+use oxc_semantic::{Reference, ScopeFlags};
 use oxc_span::{Atom, Span};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -37,7 +38,7 @@ declare_oxc_lint!(
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
-    /// ```js
+    /// ```ts
     /// class Foo {
     ///   constructor(public name: unknown) {
     ///     this.name = name;
@@ -46,7 +47,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```js
+    /// ```ts
     /// class Foo {
     ///   constructor(public name: unknown) {}
     /// }
@@ -54,7 +55,7 @@ declare_oxc_lint!(
     NoUnnecessaryParameterPropertyAssignment,
     typescript,
     correctness,
-    suggestion,
+    fix,
 );
 
 impl Rule for NoUnnecessaryParameterPropertyAssignment {
@@ -102,6 +103,7 @@ impl Rule for NoUnnecessaryParameterPropertyAssignment {
             parameter_properties,
             assigned_before_unnecessary: FxHashSet::default(),
             assigned_before_constructor,
+            removable_statements: FxHashMap::default(),
         };
         visitor.visit_function_body(function_body);
     }
@@ -112,6 +114,9 @@ struct AssignmentVisitor<'a, 'b> {
     parameter_properties: Vec<&'b FormalParameter<'a>>,
     assigned_before_unnecessary: FxHashSet<Atom<'a>>,
     assigned_before_constructor: FxHashSet<Atom<'a>>,
+    /// Spans of assignments which are a statement of their own, mapped to the span of that
+    /// statement. Only those can be removed without leaving invalid code behind.
+    removable_statements: FxHashMap<Span, Span>,
 }
 
 impl<'a> Visit<'a> for AssignmentVisitor<'a, '_> {
@@ -119,6 +124,17 @@ impl<'a> Visit<'a> for AssignmentVisitor<'a, '_> {
         // don't continue walking into functions as they have a different scoped "this"
     }
 
+    fn visit_statements(&mut self, statements: &ArenaVec<'a, Statement<'a>>) {
+        for statement in statements {
+            if let Statement::ExpressionStatement(expr) = statement {
+                if let Expression::AssignmentExpression(assignment) = &expr.expression {
+                    self.removable_statements.insert(assignment.span, expr.span);
+                }
+            }
+        }
+        walk::walk_statements(self, statements);
+    }
+
     fn visit_assignment_expression(&mut self, assignment_expr: &AssignmentExpression<'a>) {
         let Some(this_property_name) = get_property_name(&assignment_expr.left) else {
             return;
@@ -161,6 +177,10 @@ impl<'a> Visit<'a> for AssignmentVisitor<'a, '_> {
             }
             // property parameter is same symbol as identifier on the right of assignment
 
+            if self.ctx.symbol_references(binding_identifier.symbol_id()).any(Reference::is_write) {
+                continue; // the parameter may be changed, e.g. `foo = foo.trim()`
+            }
+
             if self.assigned_before_unnecessary.contains(&this_property_name) {
                 continue; // there already was an assignment inside the constructor
             }
@@ -169,15 +189,14 @@ impl<'a> Visit<'a> for AssignmentVisitor<'a, '_> {
                 continue; // there already was an assignment outside the constructor
             }
 
-            self.ctx.diagnostic_with_suggestion(
-                no_unnecessary_parameter_property_assignment_diagnostic(assignment_expr.span),
-                |fixer| {
-                    fixer.delete_range(Span::new(
-                        assignment_expr.span.start,
-                        assignment_expr.span.end + 1,
-                    ))
-                },
-            );
+            let diagnostic =
+                no_unnecessary_parameter_property_assignment_diagnostic(assignment_expr.span);
+            if let Some(statement_span) = self.removable_statements.get(&assignment_expr.span) {
+                self.ctx
+                    .diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(*statement_span));
+            } else {
+                self.ctx.diagnostic(diagnostic);
+            }
         }
     }
 }
@@ -366,6 +385,22 @@ fn test() {
         }
         ",
         "
+        class Foo {
+          constructor(private foo: string) {
+            foo = foo.trim();
+            this.foo = foo;
+          }
+        }
+        ",
+        "
+        class Foo {
+          constructor(private foo: string) {
+            this.foo = foo;
+            foo ||= 'default';
+          }
+        }
+        ",
+        "
         function Foo(foo) {
           this.foo = foo;
         }
@@ -666,6 +701,20 @@ fn test() {
           }
         }
         ",
+        "
+        class Foo {
+          constructor(private foo: string) {
+            if (maybeTrue) this.foo = foo;
+          }
+        }
+        ",
+        "
+        class Foo {
+          constructor(private foo: string) {
+            init(this.foo = foo);
+          }
+        }
+        ",
     ];
 
     let fix = vec![
@@ -1073,6 +1122,38 @@ fn test() {
             }
             ",
         ),
+        (
+            "
+            class Foo {
+              constructor(private foo: string) {
+                if (maybeTrue) this.foo = foo;
+              }
+            }
+            ",
+            "
+            class Foo {
+              constructor(private foo: string) {
+                if (maybeTrue) this.foo = foo;
+              }
+            }
+            ",
+        ),
+        (
+            "
+            class Foo {
+              constructor(private foo: string) {
+                init(this.foo = foo);
+              }
+            }
+            ",
+            "
+            class Foo {
+              constructor(private foo: string) {
+                init(this.foo = foo);
+              }
+            }
+            ",
+        ),
     ];
 
     Tester::new(
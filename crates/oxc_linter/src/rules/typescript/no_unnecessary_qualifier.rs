@@ -0,0 +1,396 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, IdentifierName, IdentifierReference, TSTypeName},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_unnecessary_qualifier_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Qualifier is unnecessary since `{name}` is in scope."))
+        .with_help("Remove the qualifier.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryQualifier;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary namespace qualifiers.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Members of a namespace or an enum can be referenced without a qualifier from within
+    /// the namespace or enum. Qualifying them anyway is redundant, and makes the code more
+    /// verbose.
+    ///
+    /// A qualifier is only reported when the unqualified name refers to the same member, i.e.
+    /// when it isn't shadowed by another declaration.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// namespace A {
+    ///   export type B = number;
+    ///   const x: A.B = 3;
+    /// }
+    ///
+    /// enum Direction {
+    ///   Up = 1,
+    ///   Down = Direction.Up + 1,
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// namespace A {
+    ///   export type B = number;
+    ///   const x: B = 3;
+    /// }
+    ///
+    /// enum Direction {
+    ///   Up = 1,
+    ///   Down = Up + 1,
+    /// }
+    /// ```
+    NoUnnecessaryQualifier,
+    typescript,
+    style,
+    fix
+);
+
+impl Rule for NoUnnecessaryQualifier {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some((qualifier_span, name)) = unnecessary_qualifier(node, ctx) else {
+            return;
+        };
+
+        // Only report the outermost unnecessary qualifier of `A.B.C`.
+        let mut current = node;
+        loop {
+            let parent = ctx.nodes().parent_node(current.id());
+            let is_qualifier = match parent.kind() {
+                AstKind::TSQualifiedName(qualified) => qualified.left.span() == current.span(),
+                AstKind::StaticMemberExpression(member) => member.object.span() == current.span(),
+                _ => false,
+            };
+            if !is_qualifier {
+                break;
+            }
+            if unnecessary_qualifier(parent, ctx).is_some() {
+                return;
+            }
+            current = parent;
+        }
+
+        let diagnostic = no_unnecessary_qualifier_diagnostic(&name.name, qualifier_span);
+        let fix_span = Span::new(qualifier_span.start, name.span.start);
+        if ctx.has_comments_between(fix_span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(fix_span));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+/// For a `qualifier.name` access whose qualifier can be removed, the span of the qualifier and
+/// the name.
+fn unnecessary_qualifier<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Span, &'a IdentifierName<'a>)> {
+    let (namespace, qualifier_span, name) = match node.kind() {
+        AstKind::TSQualifiedName(qualified) => {
+            (resolve_type_name(&qualified.left, ctx)?, qualified.left.span(), &qualified.right)
+        }
+        AstKind::StaticMemberExpression(member) if !member.optional => {
+            (resolve_expression(&member.object, ctx)?, member.object.span(), &member.property)
+        }
+        _ => return None,
+    };
+
+    // The access must be within the namespace or enum.
+    let declarations = declarations(namespace, ctx);
+    if !ctx.nodes().ancestor_ids(node.id()).any(|id| declarations.contains(&id)) {
+        return None;
+    }
+
+    let member = resolve_member(namespace, &name.name, ctx)?;
+    let in_scope = ctx.scoping().find_binding(node.scope_id(), &name.name);
+    (in_scope == Some(member)).then_some((qualifier_span, name))
+}
+
+/// Resolves a type name like `A.B` to the namespace or enum it refers to.
+fn resolve_type_name(name: &TSTypeName, ctx: &LintContext) -> Option<SymbolId> {
+    match name {
+        TSTypeName::IdentifierReference(ident) => resolve_identifier(ident, ctx),
+        TSTypeName::QualifiedName(qualified) => {
+            let namespace = resolve_type_name(&qualified.left, ctx)?;
+            resolve_namespace_member(namespace, &qualified.right.name, ctx)
+        }
+    }
+}
+
+/// Resolves an expression like `A.B` to the namespace or enum it refers to.
+fn resolve_expression(expr: &Expression, ctx: &LintContext) -> Option<SymbolId> {
+    match expr {
+        Expression::Identifier(ident) => resolve_identifier(ident, ctx),
+        Expression::StaticMemberExpression(member) if !member.optional => {
+            let namespace = resolve_expression(&member.object, ctx)?;
+            resolve_namespace_member(namespace, &member.property.name, ctx)
+        }
+        _ => None,
+    }
+}
+
+fn resolve_identifier(ident: &IdentifierReference, ctx: &LintContext) -> Option<SymbolId> {
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+    ctx.scoping().symbol_flags(symbol_id).intersects(SymbolFlags::Namespace).then_some(symbol_id)
+}
+
+fn resolve_namespace_member(
+    namespace: SymbolId,
+    name: &str,
+    ctx: &LintContext,
+) -> Option<SymbolId> {
+    let member = resolve_member(namespace, name, ctx)?;
+    ctx.scoping().symbol_flags(member).intersects(SymbolFlags::Namespace).then_some(member)
+}
+
+/// Finds the member `name` declared in the namespace or enum `namespace`.
+fn resolve_member(namespace: SymbolId, name: &str, ctx: &LintContext) -> Option<SymbolId> {
+    declarations(namespace, ctx).into_iter().find_map(|declaration| {
+        let scope_id = declaration_scope(declaration, ctx)?;
+        ctx.scoping().get_binding(scope_id, name)
+    })
+}
+
+/// All declarations of a symbol, as namespaces and enums can be declared more than once.
+fn declarations(symbol_id: SymbolId, ctx: &LintContext) -> Vec<NodeId> {
+    let redeclarations = ctx.scoping().symbol_redeclarations(symbol_id);
+    if redeclarations.is_empty() {
+        vec![ctx.scoping().symbol_declaration(symbol_id)]
+    } else {
+        redeclarations.iter().map(|redeclaration| redeclaration.declaration).collect()
+    }
+}
+
+/// The scope holding the members of a namespace or enum declaration.
+fn declaration_scope(declaration: NodeId, ctx: &LintContext) -> Option<ScopeId> {
+    match ctx.nodes().kind(declaration) {
+        AstKind::TSModuleDeclaration(module) => module.scope_id.get(),
+        AstKind::TSEnumDeclaration(decl) => decl.scope_id.get(),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "
+        namespace X {
+            export type T = number;
+        }
+        namespace Y {
+            export const x: X.T = 3;
+        }
+        ",
+        "
+        enum A {
+            B,
+            C,
+        }
+        namespace D {
+            const x: A.B = A.C;
+        }
+        ",
+        "
+        namespace X {
+            export type T = number;
+            namespace Y {
+                type T = string;
+                const x: X.T = 0;
+            }
+        }
+        ",
+        "const x: A.B = 3;",
+        "
+        namespace X {
+            const z = X.y;
+        }
+        ",
+        "
+        namespace A {
+            export const x = 1;
+            function f(x: number) {
+                return A.x;
+            }
+        }
+        ",
+        "
+        namespace A {
+            export const x = 1;
+            function f() {
+                const x = 2;
+                return A.x;
+            }
+        }
+        ",
+        "
+        namespace A {
+            export namespace B {
+                export const x = 1;
+            }
+        }
+        const y = A.B.x;
+        ",
+        "
+        namespace A {
+            export const x = 1;
+            const y = A?.x;
+        }
+        ",
+        "
+        namespace A {
+            export const x = 1;
+            const y = A[x];
+        }
+        ",
+        "
+        enum A {
+            B,
+        }
+        enum C {
+            D = A.B,
+        }
+        ",
+    ];
+
+    let fail = vec![
+        "
+        namespace A {
+            export type B = number;
+            const x: A.B = 3;
+        }
+        ",
+        "
+        namespace A {
+            export const x = 3;
+            export const y = A.x;
+        }
+        ",
+        "
+        namespace A {
+            export type T = number;
+            export namespace B {
+                const x: A.T = 3;
+            }
+        }
+        ",
+        "
+        namespace A {
+            export namespace B {
+                export type T = number;
+                const x: A.B.T = 3;
+            }
+        }
+        ",
+        "
+        namespace A {
+            export namespace B {
+                export const x = 3;
+                const y = A.B.x;
+            }
+        }
+        ",
+        "
+        namespace A {
+            export namespace B {
+                export type T = number;
+            }
+            const x: A.B.T = 3;
+        }
+        ",
+        "
+        namespace A.B {
+            export const x = 1;
+            const y = A.B.x;
+        }
+        ",
+        "
+        namespace A {
+            enum E {
+                X,
+            }
+            let x = A.E.X;
+        }
+        ",
+        "
+        enum A {
+            B,
+            C = A.B,
+        }
+        ",
+        "
+        namespace A {
+            export const x = 1;
+            const y = typeof A.x;
+            type T = typeof A.x;
+        }
+        ",
+        "
+        namespace A {
+            export const x = 3;
+            const y = A./* x */x;
+        }
+        ",
+    ];
+
+    let fix = vec![
+        (
+            "namespace A { export type B = number; const x: A.B = 3; }",
+            "namespace A { export type B = number; const x: B = 3; }",
+        ),
+        (
+            "namespace A { export const x = 3; export const y = A.x; }",
+            "namespace A { export const x = 3; export const y = x; }",
+        ),
+        (
+            "namespace A { export namespace B { export type T = number; const x: A.B.T = 3; } }",
+            "namespace A { export namespace B { export type T = number; const x: T = 3; } }",
+        ),
+        (
+            "namespace A { export namespace B { export type T = number; } const x: A.B.T = 3; }",
+            "namespace A { export namespace B { export type T = number; } const x: B.T = 3; }",
+        ),
+        (
+            "namespace A { enum E { X } let x = A.E.X; }",
+            "namespace A { enum E { X } let x = E.X; }",
+        ),
+        ("enum A { B, C = A.B }", "enum A { B, C = B }"),
+        (
+            "namespace A { export const x = 3; const y = A./* x */x; }",
+            "namespace A { export const x = 3; const y = A./* x */x; }",
+        ),
+    ];
+
+    Tester::new(NoUnnecessaryQualifier::NAME, NoUnnecessaryQualifier::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
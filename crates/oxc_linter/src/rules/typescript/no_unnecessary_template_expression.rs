@@ -0,0 +1,254 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unnecessary_template_expression_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Template literal expression is unnecessary and can be simplified.")
+        .with_help(
+            "Use the interpolated value directly instead of wrapping it in a template literal.",
+        )
+        .with_label(span)
+}
+
+fn interpolated_literal_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Interpolating a literal into a template literal is unnecessary.")
+        .with_help("Inline the literal into the surrounding template literal.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryTemplateExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow template literals that consist of nothing but a single
+    /// interpolated string literal or identifier, and interpolations of string
+    /// or template literals, which can be inlined into the template.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Wrapping a value in a template literal without adding any text around
+    /// it makes the code harder to read without changing the resulting string.
+    /// The same goes for interpolating a literal, such as `${'b'}` in
+    /// `` `a${'b'}` ``, whose text can be written into the template directly.
+    ///
+    /// This rule does not use type information, so for identifiers it can not
+    /// know whether the value is already a string. Removing the template
+    /// literal around a non-string identifier changes behavior, which is why
+    /// the fix for identifiers is marked as dangerous.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const ab1 = `${'a'}`;
+    ///
+    /// const text = 'a';
+    /// const wrappedText = `${text}`;
+    ///
+    /// const ab2 = `a${'b'}`;
+    /// const ab3 = `a${`b${text}`}`;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const ab1 = 'a';
+    ///
+    /// const text = 'a';
+    /// const wrappedText = text;
+    ///
+    /// const ab2 = `ab`;
+    /// const ab3 = `ab${text}`;
+    ///
+    /// const greeting = `Hello, ${text}!`;
+    /// const tagged = tag`${text}`;
+    /// ```
+    NoUnnecessaryTemplateExpression,
+    typescript,
+    pedantic,
+    fix_dangerous
+);
+
+impl Rule for NoUnnecessaryTemplateExpression {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TemplateLiteral(template) = node.kind() else {
+            return;
+        };
+        if matches!(
+            ctx.nodes().parent_kind(node.id()),
+            AstKind::TaggedTemplateExpression(tagged) if tagged.quasi.span == template.span
+        ) {
+            return;
+        }
+        // `${expr}` without any static text around the expression.
+        if template.expressions.len() == 1
+            && template.quasis.iter().all(|quasi| quasi.value.raw.is_empty())
+        {
+            let expression = template.expressions[0].get_inner_expression();
+            // The fix replaces the whole template, which would drop comments inside it.
+            let has_comments = ctx.has_comments_between(template.span);
+            match expression {
+                Expression::StringLiteral(_) | Expression::Identifier(_) if has_comments => {
+                    ctx.diagnostic(no_unnecessary_template_expression_diagnostic(template.span));
+                    return;
+                }
+                Expression::StringLiteral(literal) => {
+                    ctx.diagnostic_with_fix(
+                        no_unnecessary_template_expression_diagnostic(template.span),
+                        |fixer| fixer.replace_with(template, &literal.span),
+                    );
+                    return;
+                }
+                Expression::Identifier(ident) => {
+                    ctx.diagnostic_with_dangerous_fix(
+                        no_unnecessary_template_expression_diagnostic(template.span),
+                        |fixer| fixer.replace_with(template, &ident.span),
+                    );
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        // Literals interpolated among other text, e.g. `foo${'bar'}` or `foo${`bar${baz}`}`.
+        for (index, expression) in template.expressions.iter().enumerate() {
+            // Template elements don't include the `${` and `}` around the interpolation.
+            let span =
+                Span::new(template.quasis[index].span.end, template.quasis[index + 1].span.start);
+            let text = match expression.get_inner_expression() {
+                Expression::StringLiteral(literal) => {
+                    let raw = ctx.source_range(literal.span);
+                    string_to_template_text(&raw[1..raw.len() - 1])
+                }
+                Expression::TemplateLiteral(inner) => {
+                    let raw = ctx.source_range(inner.span);
+                    let text = &raw[1..raw.len() - 1];
+                    // `${`a$`}{b}` must not become `a${b}`.
+                    (!text.ends_with('$')).then(|| text.to_string())
+                }
+                _ => continue,
+            };
+            // `a$${'{b}'}` must not become `a${b}`.
+            let text = text.filter(|text| {
+                !template.quasis[index].value.raw.ends_with('$')
+                    || !(text.is_empty() || text.starts_with('{'))
+            });
+
+            let diagnostic = interpolated_literal_diagnostic(span);
+            match text {
+                Some(text) if !ctx.has_comments_between(span) => {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, text));
+                }
+                _ => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// Converts the raw text of a string literal, without its quotes, to the text of a template
+/// literal. Returns `None` for legacy octal escapes such as `\1`, which aren't allowed in
+/// templates.
+fn string_to_template_text(raw: &str) -> Option<String> {
+    let mut result = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let next = chars.next()?;
+                let is_octal_escape = next.is_ascii_digit()
+                    && (next != '0' || chars.peek().is_some_and(char::is_ascii_digit));
+                if is_octal_escape {
+                    return None;
+                }
+                result.push(c);
+                result.push(next);
+            }
+            '`' => result.push_str("\\`"),
+            // Escape `$` before `{`, and at the end in case the template continues with `{`.
+            '$' if chars.peek().is_none_or(|next| *next == '{') => result.push_str("\\$"),
+            _ => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const string = 'a';",
+        "const string = `a`;",
+        "const string = `${foo}bar`;",
+        "const string = `foo${bar}`;",
+        "const string = `${foo}${bar}`;",
+        "const string = `${foo}\n`;",
+        "const string = `${1}`;",
+        "const string = `${foo.bar}`;",
+        "const string = `${foo()}`;",
+        "const string = tag`${foo}`;",
+        "const string = tag`${'bar'}`;",
+        "const string = String.raw`${'a'}`;",
+    ];
+
+    let fail = vec![
+        "const string = `${'a'}`;",
+        r#"const string = `${"a"}`;"#,
+        "const string = `${foo}`;",
+        "const string = `${(foo)}`;",
+        "const string = `${('a')}`;",
+        "foo(`${bar}`);",
+        "const string = tag(`${foo}`);",
+        "const string = `foo${'bar'}baz`;",
+        "const string = `${'a'}${'b'}`;",
+        "const string = `${a}${'b'}`;",
+        "const string = `a${('b')}`;",
+        "const string = `${`nested`}`;",
+        "const string = `a${`b${c}`}d`;",
+        "const string = `a${/* comment */ 'b'}`;",
+        "const string = `${/* comment */ 'a'}`;",
+        "const string = `${foo /* comment */}`;",
+    ];
+
+    let fix = vec![
+        ("const string = `${'a'}`;", "const string = 'a';"),
+        (r#"const string = `${"a"}`;"#, r#"const string = "a";"#),
+        ("const string = `${foo}`;", "const string = foo;"),
+        ("const string = `${(foo)}`;", "const string = foo;"),
+        ("const string = `${('a')}`;", "const string = 'a';"),
+        ("foo(`${bar}`);", "foo(bar);"),
+        ("const string = `foo${'bar'}baz`;", "const string = `foobarbaz`;"),
+        ("const string = `${'a'}${'b'}`;", "const string = `ab`;"),
+        ("const string = `${a}${'b'}`;", "const string = `${a}b`;"),
+        ("const string = `a${('b')}`;", "const string = `ab`;"),
+        ("const string = `a${'`b`'}`;", r"const string = `a\`b\``;"),
+        ("const string = `a${'${b}'}`;", r"const string = `a\${b}`;"),
+        ("const string = `a${'$'}{b}`;", r"const string = `a\${b}`;"),
+        ("const string = `a${'$b'}`;", "const string = `a$b`;"),
+        (r"const string = `a${'\n'}`;", r"const string = `a\n`;"),
+        (r#"const string = `a${"'"}`;"#, "const string = `a'`;"),
+        ("const string = `${`nested`}`;", "const string = `nested`;"),
+        ("const string = `a${`b${c}`}d`;", "const string = `ab${c}d`;"),
+        ("const string = `a${`b$`}{c}`;", "const string = `a${`b$`}{c}`;"),
+        ("const string = `a$${'{b}'}`;", "const string = `a$${'{b}'}`;"),
+        ("const string = `a$${`{b}`}`;", "const string = `a$${`{b}`}`;"),
+        ("const string = `a$${''}{b}`;", "const string = `a$${''}{b}`;"),
+        ("const string = `a$${'b'}`;", "const string = `a$b`;"),
+        ("const string = `a${/* comment */ 'b'}`;", "const string = `a${/* comment */ 'b'}`;"),
+        ("const string = `${/* comment */ 'a'}`;", "const string = `${/* comment */ 'a'}`;"),
+        ("const string = `${foo /* comment */}`;", "const string = `${foo /* comment */}`;"),
+    ];
+
+    Tester::new(
+        NoUnnecessaryTemplateExpression::NAME,
+        NoUnnecessaryTemplateExpression::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
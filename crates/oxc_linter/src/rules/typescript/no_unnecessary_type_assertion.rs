@@ -0,0 +1,247 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSType, TSTypeName},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_unnecessary_type_assertion_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "This assertion is unnecessary since it does not change the type of the expression.",
+    )
+    .with_help("Remove the type assertion.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryTypeAssertion;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow type assertions that do not change the type of an expression.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript can be told an expression is a different type than expected
+    /// using type assertions. Leaving an assertion in the code that does not
+    /// change the type of the expression is unnecessary noise.
+    ///
+    /// This rule does not use type information, so it only reports assertions
+    /// which are redundant on their own:
+    /// - `as const` on an expression that already has a `const` assertion
+    /// - `as typeof foo` on `foo` itself
+    /// - `!` on a literal, which can never be `null` or `undefined`
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const a = ['a', 'b'] as const as const;
+    /// const b = foo as typeof foo;
+    /// const c = 'foo'!;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const a = ['a', 'b'] as const;
+    /// const b = foo as typeof bar;
+    /// const c = foo!;
+    /// ```
+    NoUnnecessaryTypeAssertion,
+    typescript,
+    suspicious,
+    fix
+);
+
+impl Rule for NoUnnecessaryTypeAssertion {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSAsExpression(expr) => {
+                check_type_assertion(&expr.expression, &expr.type_annotation, expr.span, ctx);
+            }
+            AstKind::TSTypeAssertion(expr) => {
+                check_type_assertion(&expr.expression, &expr.type_annotation, expr.span, ctx);
+            }
+            AstKind::TSNonNullExpression(expr) => {
+                if !is_non_nullable_literal(expr.expression.without_parentheses()) {
+                    return;
+                }
+                let diagnostic = no_unnecessary_type_assertion_diagnostic(expr.span);
+                let end = expr.span.end;
+                // `1!.toFixed()` can't become `1.toFixed()`
+                if matches!(expr.expression, Expression::NumericLiteral(_))
+                    && ctx.source_text()[end as usize..].trim_start().starts_with('.')
+                {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.delete_range(Span::new(end - 1, end))
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn check_type_assertion(
+    expression: &Expression,
+    type_annotation: &TSType,
+    span: Span,
+    ctx: &LintContext,
+) {
+    let inner = expression.without_parentheses();
+    let is_unnecessary = match type_annotation {
+        TSType::TSTypeQuery(query) => {
+            query.type_arguments.is_none()
+                && query.expr_name.as_ts_type_name().is_some_and(|name| is_same_name(name, inner))
+        }
+        _ if is_const_type(type_annotation) => is_const_assertion(inner),
+        _ => false,
+    };
+    if !is_unnecessary {
+        return;
+    }
+
+    let diagnostic = no_unnecessary_type_assertion_diagnostic(span);
+    if ctx.has_comments_between(span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        fixer.replace(span, ctx.source_range(expression.span()).to_string())
+    });
+}
+
+/// `const` in `foo as const` or `<const>foo`.
+fn is_const_type(ty: &TSType) -> bool {
+    let TSType::TSTypeReference(reference) = ty else {
+        return false;
+    };
+    let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+        return false;
+    };
+    ident.name == "const" && reference.type_arguments.is_none()
+}
+
+fn is_const_assertion(expr: &Expression) -> bool {
+    match expr {
+        Expression::TSAsExpression(expr) => is_const_type(&expr.type_annotation),
+        Expression::TSTypeAssertion(expr) => is_const_type(&expr.type_annotation),
+        _ => false,
+    }
+}
+
+/// Whether the type name `foo.bar` in `typeof foo.bar` refers to the expression `foo.bar`.
+fn is_same_name(name: &TSTypeName, expr: &Expression) -> bool {
+    match (name, expr) {
+        (TSTypeName::IdentifierReference(name), Expression::Identifier(ident)) => {
+            name.name == ident.name
+        }
+        (TSTypeName::QualifiedName(name), Expression::StaticMemberExpression(member)) => {
+            !member.optional
+                && name.right.name == member.property.name
+                && is_same_name(&name.left, member.object.without_parentheses())
+        }
+        _ => false,
+    }
+}
+
+fn is_non_nullable_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::NullLiteral(_) => false,
+        _ if expr.is_literal() => true,
+        Expression::TemplateLiteral(_)
+        | Expression::ObjectExpression(_)
+        | Expression::ArrayExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ClassExpression(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const a = ['a', 'b'] as const;",
+        "const a = <const>['a', 'b'];",
+        "const a = foo as typeof bar;",
+        "const a = foo.bar as typeof foo.baz;",
+        "const a = foo?.bar as typeof foo.bar;",
+        "const a = foo as typeof foo<string>;",
+        "const a = foo as Foo;",
+        "const a = foo as unknown as Foo;",
+        "const a = (foo as const) as Foo;",
+        "const a = foo!;",
+        "const a = foo.bar!;",
+        "const a = foo()!;",
+        "const a = null!;",
+        "let a = 'a' as 'a';",
+        "const a = 1 as number;",
+        "const a = foo as const;",
+    ];
+
+    let fail = vec![
+        "const a = ['a', 'b'] as const as const;",
+        "const a = <const><const>['a', 'b'];",
+        "const a = <const>['a', 'b'] as const;",
+        "const a = (['a', 'b'] as const) as const;",
+        "const a = foo as typeof foo;",
+        "const a = <typeof foo>foo;",
+        "const a = foo.bar.baz as typeof foo.bar.baz;",
+        "const a = (foo) as typeof foo;",
+        "const a = 'foo'!;",
+        "const a = 1!;",
+        "const a = 1n!;",
+        "const a = true!;",
+        "const a = /foo/!;",
+        "const a = `foo${bar}`!;",
+        "const a = {}!;",
+        "const a = []!;",
+        "const a = (() => {})!;",
+        "const a = ('foo')!;",
+        "const a = 1!.toFixed();",
+        "const a = foo as /* comment */ typeof foo;",
+    ];
+
+    let fix = vec![
+        ("const a = ['a', 'b'] as const as const;", "const a = ['a', 'b'] as const;"),
+        ("const a = <const><const>['a', 'b'];", "const a = <const>['a', 'b'];"),
+        ("const a = <const>['a', 'b'] as const;", "const a = <const>['a', 'b'];"),
+        ("const a = (['a', 'b'] as const) as const;", "const a = (['a', 'b'] as const);"),
+        ("const a = foo as typeof foo;", "const a = foo;"),
+        ("const a = <typeof foo>foo;", "const a = foo;"),
+        ("const a = foo.bar.baz as typeof foo.bar.baz;", "const a = foo.bar.baz;"),
+        ("const a = (foo) as typeof foo;", "const a = (foo);"),
+        ("const a = 'foo'!;", "const a = 'foo';"),
+        ("const a = 1!;", "const a = 1;"),
+        ("const a = 'foo'!.length;", "const a = 'foo'.length;"),
+        ("const a = (1)!.toFixed();", "const a = (1).toFixed();"),
+        ("const a = ('foo')!;", "const a = ('foo');"),
+        ("const a = 1!.toFixed();", "const a = 1!.toFixed();"),
+        (
+            "const a = foo as /* comment */ typeof foo;",
+            "const a = foo as /* comment */ typeof foo;",
+        ),
+    ];
+
+    Tester::new(NoUnnecessaryTypeAssertion::NAME, NoUnnecessaryTypeAssertion::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
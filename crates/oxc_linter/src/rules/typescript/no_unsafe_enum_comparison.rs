@@ -0,0 +1,231 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSEnumDeclaration},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_unsafe_enum_comparison_diagnostic(literal: &str, name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The two values in this comparison do not have a shared enum type.")
+        .with_help(format!(
+            "`{literal}` is not a value of the enum `{name}`, compare with one of its members instead."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeEnumComparison;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow comparing an enum value with a literal which isn't one of its values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The values of an enum are meant to be compared with the members of the enum. Comparing
+    /// them with a literal which isn't a value of the enum, e.g. a number with a string enum,
+    /// is always `false` (or `true` for `!==`), and is most likely a mistake.
+    ///
+    /// This rule does not use type information. It only checks comparisons of a member of an
+    /// enum declared in the same file, e.g. `Color.Red`, with a string or number literal. Enums
+    /// whose values can't be determined syntactically, such as computed members, are ignored.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// enum Color {
+    ///   Red = 'red',
+    ///   Green = 'green',
+    /// }
+    ///
+    /// declare const color: Color;
+    ///
+    /// if (Color.Red === 5) {}
+    /// if (Color.Green !== 'blue') {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// enum Color {
+    ///   Red = 'red',
+    ///   Green = 'green',
+    /// }
+    ///
+    /// declare const color: Color;
+    ///
+    /// if (color === Color.Red) {}
+    /// if (Color.Green !== 'red') {}
+    /// ```
+    NoUnsafeEnumComparison,
+    typescript,
+    suspicious
+);
+
+impl Rule for NoUnsafeEnumComparison {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(binary) = node.kind() else {
+            return;
+        };
+        if !binary.operator.is_equality() && !binary.operator.is_compare() {
+            return;
+        }
+
+        let (enum_decl, literal) = match (
+            get_enum_declaration(&binary.left, ctx),
+            get_enum_declaration(&binary.right, ctx),
+        ) {
+            (Some(enum_decl), None) => (enum_decl, &binary.right),
+            (None, Some(enum_decl)) => (enum_decl, &binary.left),
+            _ => return,
+        };
+        let Some(value) = EnumValue::from_literal(literal) else {
+            return;
+        };
+        let Some(values) = get_enum_values(enum_decl) else {
+            return;
+        };
+        if values.contains(&value) {
+            return;
+        }
+
+        ctx.diagnostic(no_unsafe_enum_comparison_diagnostic(
+            ctx.source_range(literal.span()),
+            &enum_decl.id.name,
+            binary.span,
+        ));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EnumValue<'a> {
+    String(&'a str),
+    Number(f64),
+}
+
+impl<'a> EnumValue<'a> {
+    /// The value of a string or number literal, such as `'a'`, `` `a` `` or `-1`.
+    fn from_literal(expr: &Expression<'a>) -> Option<Self> {
+        match expr.without_parentheses() {
+            Expression::StringLiteral(lit) => Some(Self::String(lit.value.as_str())),
+            Expression::TemplateLiteral(template) if template.expressions.is_empty() => {
+                template.quasis.first()?.value.cooked.map(|cooked| Self::String(cooked.as_str()))
+            }
+            Expression::NumericLiteral(lit) => Some(Self::Number(lit.value)),
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation =>
+            {
+                match &unary.argument {
+                    Expression::NumericLiteral(lit) => Some(Self::Number(-lit.value)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The declaration of the enum `expr` is a member of, e.g. `Color` of `Color.Red`.
+fn get_enum_declaration<'a>(
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a TSEnumDeclaration<'a>> {
+    let member = expr.without_parentheses().as_member_expression()?;
+    member.static_property_name()?;
+    let Expression::Identifier(ident) = member.object() else {
+        return None;
+    };
+
+    let scoping = ctx.scoping();
+    let symbol_id = scoping.get_reference(ident.reference_id()).symbol_id()?;
+    // The values of merged enums are spread over several declarations.
+    if !scoping.symbol_redeclarations(symbol_id).is_empty() {
+        return None;
+    }
+    match ctx.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+        AstKind::TSEnumDeclaration(decl) => Some(decl),
+        _ => None,
+    }
+}
+
+/// The values of all members of an enum, or `None` if some of them can't be determined.
+fn get_enum_values<'a>(decl: &TSEnumDeclaration<'a>) -> Option<Vec<EnumValue<'a>>> {
+    let mut next_number = Some(0.0);
+    decl.body
+        .members
+        .iter()
+        .map(|member| {
+            let value = match &member.initializer {
+                Some(initializer) => EnumValue::from_literal(initializer)?,
+                // Members without an initializer are numbered after the previous member.
+                None => EnumValue::Number(next_number?),
+            };
+            next_number = match value {
+                EnumValue::Number(number) => Some(number + 1.0),
+                EnumValue::String(_) => None,
+            };
+            Some(value)
+        })
+        .collect()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "enum Color { Red = 'red', Green = 'green' } Color.Red === 'red';",
+        "enum Color { Red = 'red', Green = 'green' } Color.Red === 'green';",
+        "enum Color { Red = 'red', Green = 'green' } Color.Red !== `green`;",
+        "enum Color { Red = 'red', Green = 'green' } 'red' == Color.Green;",
+        "enum Color { Red = 'red', Green = 'green' } Color['Red'] === 'red';",
+        "enum Fruit { Apple, Banana } Fruit.Apple === 0;",
+        "enum Fruit { Apple, Banana } Fruit.Banana === 1;",
+        "enum Fruit { Apple = 5, Banana } Fruit.Apple < 6;",
+        "enum Fruit { Apple = -1, Banana } Fruit.Banana === 0;",
+        "enum Fruit { Apple = 'apple', Banana = 2, Cherry } Fruit.Cherry === 3;",
+        "enum Fruit { Apple = 'apple', Banana = 2, Cherry } Fruit.Apple === -1 + 1;",
+        "enum Color { Red = 'red' } Color.Red === Color.Red;",
+        "enum Color { Red = 'red' } declare const color: Color; color === Color.Red;",
+        "enum Color { Red = 'red' } Color.Red + 5;",
+        "enum Color { Red = 'red' } Color.Red === foo;",
+        "enum Color { Red = 'red' } Color.Red === null;",
+        "enum Color { Red = getRed() } Color.Red === 'red';",
+        "enum Color { Red = 'red', Green = Red } Color.Green === 'red';",
+        "enum Color { Red = 'red' } enum Color { Green = 'green' } Color.Red === 'green';",
+        "const Color = { Red: 'red' }; Color.Red === 5;",
+        "Color.Red === 5;",
+        "enum Color { Red = 'red' } function f(Color: any) { return Color.Red === 5; }",
+    ];
+
+    let fail = vec![
+        "enum Color { Red = 'red', Green = 'green' } Color.Red === 5;",
+        "enum Color { Red = 'red', Green = 'green' } Color.Red === 'blue';",
+        "enum Color { Red = 'red', Green = 'green' } Color.Green !== `blue`;",
+        "enum Color { Red = 'red', Green = 'green' } 'blue' == Color.Green;",
+        "enum Color { Red = 'red', Green = 'green' } Color['Red'] === 'blue';",
+        "enum Color { Red = 'red', Green = 'green' } (Color.Red) === ('blue');",
+        "enum Fruit { Apple, Banana } Fruit.Apple === 2;",
+        "enum Fruit { Apple, Banana } Fruit.Apple === 'apple';",
+        "enum Fruit { Apple = 5, Banana } Fruit.Apple < 0;",
+        "enum Fruit { Apple = -1, Banana } Fruit.Banana >= 1;",
+        "enum Fruit { Apple = 'apple', Banana = 2, Cherry } Fruit.Cherry === -3;",
+        "const enum Color { Red = 'red' } Color.Red === 'green';",
+    ];
+
+    Tester::new(NoUnsafeEnumComparison::NAME, NoUnsafeEnumComparison::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
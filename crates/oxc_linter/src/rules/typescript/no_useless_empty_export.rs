@@ -1,9 +1,9 @@
-use oxc_ast::AstKind;
+use oxc_ast::ast::{ExportNamedDeclaration, Statement};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule};
 
 fn no_useless_empty_export_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Empty exports do nothing in module files")
@@ -56,26 +56,41 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessEmptyExport {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ExportNamedDeclaration(decl) = node.kind() else { return };
-        if decl.declaration.is_some() || !decl.specifiers.is_empty() {
-            return;
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(program) = ctx.nodes().program() else { return };
+
+        let mut empty_exports = vec![];
+        let mut has_other_module_declaration = false;
+        for statement in &program.body {
+            match statement {
+                Statement::ExportNamedDeclaration(decl) if is_empty_export(decl) => {
+                    empty_exports.push(decl.span);
+                }
+                Statement::TSImportEqualsDeclaration(_) => has_other_module_declaration = true,
+                // `export as namespace` doesn't make a file a module.
+                Statement::TSNamespaceExportDeclaration(_) => {}
+                _ if statement.is_module_declaration() => has_other_module_declaration = true,
+                _ => {}
+            }
         }
-        let module_record = ctx.module_record();
-        if module_record.exported_bindings.is_empty()
-            && module_record.local_export_entries.is_empty()
-            && module_record.indirect_export_entries.is_empty()
-            && module_record.star_export_entries.is_empty()
-            && module_record.export_default.is_none()
-        {
+        // An empty export is needed to make a file a module if nothing else does.
+        if !has_other_module_declaration {
             return;
         }
-        ctx.diagnostic_with_fix(no_useless_empty_export_diagnostic(decl.span), |fixer| {
-            fixer.delete(&decl.span)
-        });
+
+        for span in empty_exports {
+            ctx.diagnostic_with_fix(no_useless_empty_export_diagnostic(span), |fixer| {
+                fixer.delete(&span)
+            });
+        }
     }
 }
 
+/// Whether `decl` is `export {}`.
+fn is_empty_export(decl: &ExportNamedDeclaration) -> bool {
+    decl.declaration.is_none() && decl.specifiers.is_empty() && decl.source.is_none()
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -96,6 +111,20 @@ fn test() {
             export = {};
         ",
         "export {};",
+        "
+            export {};
+            export {};
+        ",
+        "export {} from '_';",
+        "
+            export as namespace _;
+            export {};
+        ",
+        "
+            declare module '_' {
+                export {};
+            }
+        ",
     ];
 
     let fail = vec![
@@ -126,10 +155,23 @@ fn test() {
             export { _ };
             export {};
         ",
-        // "
-        // import _ = require('_');
-        // export {};
-        // ",
+        "
+            import _ = require('_');
+            export {};
+        ",
+        "
+            import {} from '_';
+            export {};
+        ",
+        "
+            import _ from '_';
+            export {};
+            export {};
+        ",
+        "
+            export {} from '_';
+            export {};
+        ",
     ];
 
     let fix = vec![
@@ -139,7 +181,9 @@ fn test() {
         ("const _ = {};export default _;export {};", "const _ = {};export default _;"),
         ("export {};const _ = {};export default _;", "const _ = {};export default _;"),
         ("const _ = {};export { _ };export {};", "const _ = {};export { _ };"),
-        // ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import {} from '_';export {};", "import {} from '_';"),
+        ("import _ from '_';export {};export {};", "import _ from '_';"),
     ];
 
     Tester::new(NoUselessEmptyExport::NAME, NoUselessEmptyExport::PLUGIN, pass, fail)
@@ -0,0 +1,216 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TemplateLiteral},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_interpolation_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Template literal has no interpolations.")
+        .with_help("Use a string literal instead.")
+        .with_label(span)
+}
+
+fn single_expression_diagnostic(help: &'static str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Template literal only contains a single expression.")
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessTemplateLiterals;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow template literals which can be replaced by a string literal or a
+    /// `String()` call.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A template literal without interpolations, such as `` `hello` ``, is just a more
+    /// surprising way to write a string literal. A template literal consisting of a single
+    /// interpolation, such as `` `${value}` ``, hides that its only purpose is to convert
+    /// `value` to a string, which `String(value)` expresses directly.
+    ///
+    /// Tagged templates are ignored, as the tag may rely on the template.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const a = `hello`;
+    /// const b = `${'hello'}`;
+    /// const c = `${count}`;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const a = 'hello';
+    /// const b = 'hello';
+    /// const c = String(count);
+    /// const d = `hello ${name}`;
+    /// const e = `line 1
+    /// line 2`;
+    /// const f = tag`hello`;
+    /// ```
+    NoUselessTemplateLiterals,
+    typescript,
+    style,
+    fix
+);
+
+impl Rule for NoUselessTemplateLiterals {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TemplateLiteral(template) = node.kind() else {
+            return;
+        };
+        match ctx.nodes().parent_kind(node.id()) {
+            AstKind::TaggedTemplateExpression(tagged) if tagged.quasi.span == template.span => {
+                return;
+            }
+            // Template literal types, e.g. `type T = \`a\``, can't be replaced by a string.
+            AstKind::TSLiteralType(_) => return,
+            _ => {}
+        }
+
+        match template.expressions.as_slice() {
+            [] => check_no_interpolation(template, ctx),
+            [expression] if template.quasis.iter().all(|quasi| quasi.value.raw.is_empty()) => {
+                check_single_expression(template, expression, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_no_interpolation(template: &TemplateLiteral, ctx: &LintContext) {
+    let Some(quasi) = template.quasis.first() else {
+        return;
+    };
+    // Multiline templates are more readable than a string literal with `\n`s.
+    if quasi.value.raw.contains(['\n', '\r']) {
+        return;
+    }
+    ctx.diagnostic_with_fix(no_interpolation_diagnostic(template.span), |fixer| {
+        fixer.replace(template.span, to_string_literal(&quasi.value.raw))
+    });
+}
+
+fn check_single_expression<'a>(
+    template: &TemplateLiteral<'a>,
+    expression: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) {
+    let text = ctx.source_range(expression.span());
+    let (help, replacement) = match (expression.get_inner_expression(), expression) {
+        (Expression::StringLiteral(literal), _) => {
+            ("Use the string literal directly.", ctx.source_range(literal.span).to_string())
+        }
+        // `${a, b}` must not become `String(a, b)`.
+        (_, Expression::SequenceExpression(_)) => {
+            ("Use `String()` instead.", format!("String(({text}))"))
+        }
+        _ => ("Use `String()` instead.", format!("String({text})")),
+    };
+
+    let diagnostic = single_expression_diagnostic(help, template.span);
+    if ctx.has_comments_between(template.span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(template.span, replacement));
+}
+
+/// Converts the raw text of a template literal without interpolations and line breaks to a
+/// single quoted string literal. Escape sequences of templates are also valid in strings, so
+/// only unescaped `'` need to be escaped.
+fn to_string_literal(raw: &str) -> String {
+    let mut result = String::with_capacity(raw.len() + 2);
+    result.push('\'');
+    let mut escaped = false;
+    for c in raw.chars() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '\'' {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result.push('\'');
+    result
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const string = 'a';",
+        "const string = `a ${b}`;",
+        "const string = `${a} b`;",
+        "const string = `${a}${b}`;",
+        "const string = `${'a'}${'b'}`;",
+        "const string = `${a}\n`;",
+        "const string = `a\nb`;",
+        "const string = `a\r\nb`;",
+        "const string = tag`a`;",
+        "const string = tag`${a}`;",
+        "const string = String.raw`a\\nb`;",
+        "type T = `a`;",
+        "type T = `${A}`;",
+        "let a: `a` = 'a';",
+    ];
+
+    let fail = vec![
+        "const string = ``;",
+        "const string = `a`;",
+        "const string = `it's`;",
+        "const string = `\\`a\\``;",
+        "const string = `a\\nb`;",
+        "foo(`a`);",
+        "const string = tag(`a`);",
+        "const string = `${'a'}`;",
+        r#"const string = `${"a"}`;"#,
+        "const string = `${('a')}`;",
+        "const string = `${a}`;",
+        "const string = `${a.b}`;",
+        "const string = `${1}`;",
+        "const string = `${a()}`;",
+        "const string = `${a, b}`;",
+        "const string = `${/* comment */ a}`;",
+        "const string = `${/* comment */ 'a'}`;",
+    ];
+
+    let fix = vec![
+        ("const string = ``;", "const string = '';"),
+        ("const string = `a`;", "const string = 'a';"),
+        ("const string = `it's`;", r"const string = 'it\'s';"),
+        ("const string = `it\\'s`;", r"const string = 'it\'s';"),
+        ("const string = `\\`a\\``;", r"const string = '\`a\`';"),
+        (r#"const string = `"a"`;"#, r#"const string = '"a"';"#),
+        ("const string = `a\\nb`;", r"const string = 'a\nb';"),
+        ("const string = `\\${a}`;", r"const string = '\${a}';"),
+        ("foo(`a`);", "foo('a');"),
+        ("const string = `${'a'}`;", "const string = 'a';"),
+        (r#"const string = `${"a"}`;"#, r#"const string = "a";"#),
+        ("const string = `${('a')}`;", "const string = 'a';"),
+        ("const string = `${a}`;", "const string = String(a);"),
+        ("const string = `${a.b}`;", "const string = String(a.b);"),
+        ("const string = `${1}`;", "const string = String(1);"),
+        ("const string = `${a()}`;", "const string = String(a());"),
+        ("const string = `${a, b}`;", "const string = String((a, b));"),
+        ("const string = `${(a, b)}`;", "const string = String((a, b));"),
+        ("const string = `${/* comment */ a}`;", "const string = `${/* comment */ a}`;"),
+        ("const string = `${/* comment */ 'a'}`;", "const string = `${/* comment */ 'a'}`;"),
+    ];
+
+    Tester::new(NoUselessTemplateLiterals::NAME, NoUselessTemplateLiterals::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -1,23 +1,34 @@
-use oxc_ast::{AstKind, ast::TSEnumMemberName};
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSEnumMember},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
 
 use crate::{
     AstNode,
     context::{ContextHost, LintContext},
+    fixer::{RuleFix, RuleFixer},
     rule::Rule,
 };
 
 fn prefer_enum_initializers_diagnostic(
     member_name: &str,
-    init: usize,
+    member: &str,
+    initializer: Option<&str>,
     span: Span,
 ) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!(
+    let diagnostic = OxcDiagnostic::warn(format!(
         "The value of the member {member_name:?} should be explicitly defined."
-    ))
-    .with_help(format!("Can be fixed to {member_name:?} = {init:?}."))
+    ));
+    match initializer {
+        Some(initializer) => {
+            diagnostic.with_help(format!("Can be fixed to `{member} = {initializer}`."))
+        }
+        None => diagnostic.with_help("Add an explicit initializer to the member."),
+    }
     .with_label(span)
 }
 
@@ -33,6 +44,17 @@ declare_oxc_lint!(
     ///
     /// In projects where the value of `enum` members are important, allowing implicit values for enums can cause bugs if enums are modified over time.
     ///
+    /// For example, adding a member in the middle of an auto-incremented enum renumbers all
+    /// members after it. Any value that was persisted or sent to another system, such as a
+    /// database column or an API response, silently changes meaning:
+    /// ```typescript
+    /// enum Status {
+    ///   Open, // 0
+    ///   Pending, // 1, added later
+    ///   Close, // was 1, is now 2
+    /// }
+    /// ```
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -53,8 +75,8 @@ declare_oxc_lint!(
     /// ```
     PreferEnumInitializers,
     typescript,
-    pedantic,
-    pending
+    restriction,
+    suggestion
 );
 
 impl Rule for PreferEnumInitializers {
@@ -63,16 +85,38 @@ impl Rule for PreferEnumInitializers {
             return;
         };
 
-        for (index, member) in enum_body.members.iter().enumerate() {
-            if member.initializer.is_none() {
-                if let TSEnumMemberName::Identifier(i) = &member.id {
-                    ctx.diagnostic(prefer_enum_initializers_diagnostic(
-                        i.name.as_str(),
-                        index + 1,
-                        member.span,
-                    ));
-                }
+        // The implicit value of a member is the value of the previous member plus one, which is
+        // only known if that value is a number literal.
+        let mut next_value = Some(0.0);
+        for member in &enum_body.members {
+            if let Some(initializer) = &member.initializer {
+                next_value = numeric_value(initializer).map(|value| value + 1.0);
+                continue;
             }
+
+            let name = member.id.static_name();
+            let member_text = ctx.source_range(member.id.span());
+            let Some(value) = next_value else {
+                ctx.diagnostic(prefer_enum_initializers_diagnostic(
+                    &name,
+                    member_text,
+                    None,
+                    member.span,
+                ));
+                continue;
+            };
+            next_value = Some(value + 1.0);
+
+            let initializer = value.to_string();
+            ctx.diagnostic_with_suggestion(
+                prefer_enum_initializers_diagnostic(
+                    &name,
+                    member_text,
+                    Some(&initializer),
+                    member.span,
+                ),
+                |fixer| fix_member(fixer, member, &initializer),
+            );
         }
     }
 
@@ -81,6 +125,28 @@ impl Rule for PreferEnumInitializers {
     }
 }
 
+fn fix_member<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    member: &TSEnumMember,
+    initializer: &str,
+) -> RuleFix<'a> {
+    fixer.insert_text_after_range(member.id.span(), format!(" = {initializer}"))
+}
+
+/// The value of a number literal initializer such as `1` or `-1`.
+fn numeric_value(initializer: &Expression) -> Option<f64> {
+    match initializer.get_inner_expression() {
+        Expression::NumericLiteral(literal) => Some(literal.value),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            match unary.argument.get_inner_expression() {
+                Expression::NumericLiteral(literal) => Some(-literal.value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -106,6 +172,11 @@ fn test() {
 			  Down = 'Down',
 			}
 			    ",
+        "const enum Direction { Up = 0, Down = 1 }",
+        "declare enum Direction { Up = 1 }",
+        "enum Direction { Up = 1 << 0, Down = 1 << 1, Both = Up | Down }",
+        "enum Direction { Up = 'Up', Down = `Down` }",
+        "enum Direction { 'Up Left' = 0, 'Down Right' = 1 }",
     ];
 
     let fail = vec![
@@ -132,8 +203,26 @@ fn test() {
 			  Down = 'Down',
 			}
 			      ",
+        "const enum Direction { Up }",
+        "enum Direction { Up = 1, Down, Left = 3 }",
+        "enum Direction { 'Up Left' }",
+        "enum Direction { Up = -1, Down }",
+    ];
+
+    let fix = vec![
+        ("enum Direction { Up }", "enum Direction { Up = 0 }"),
+        ("enum Direction { Up, Down }", "enum Direction { Up = 0, Down = 1 }"),
+        ("const enum Direction { Up, Down }", "const enum Direction { Up = 0, Down = 1 }"),
+        ("enum Direction { Up = 'Up', Down }", "enum Direction { Up = 'Up', Down }"),
+        (
+            "enum Direction { Up = 1, Down, Left = 3 }",
+            "enum Direction { Up = 1, Down = 2, Left = 3 }",
+        ),
+        ("enum Direction { Up = -1, Down }", "enum Direction { Up = -1, Down = 0 }"),
+        ("enum Direction { 'Up Left' }", "enum Direction { 'Up Left' = 0 }"),
     ];
 
     Tester::new(PreferEnumInitializers::NAME, PreferEnumInitializers::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -1,8 +1,12 @@
-use oxc_ast::{AstKind, ast::Expression};
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSEnumDeclaration},
+    match_member_expression,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_syntax::operator::UnaryOperator;
 
 use crate::{
     AstNode,
@@ -46,6 +50,34 @@ declare_oxc_lint!(
     ///   c = b,
     /// }
     /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// enum Foo {
+    ///   a = 1,
+    ///   b = 'b',
+    ///   c = -1,
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowBitwiseExpressions
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allow bitwise expressions of literals and other members of the same enum, which are
+    /// commonly used to define flags.
+    ///
+    /// Examples of **correct** code for this rule with `{ "allowBitwiseExpressions": true }`:
+    /// ```ts
+    /// enum Foo {
+    ///   A = 1 << 0,
+    ///   B = 1 << 1,
+    ///   C = A | B,
+    ///   D = Foo.A | Foo.C,
+    /// }
+    /// ```
     PreferLiteralEnumMember,
     typescript,
     restriction
@@ -64,65 +96,81 @@ impl Rule for PreferLiteralEnumMember {
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::TSEnumMember(decl) = node.kind() else {
+        let AstKind::TSEnumDeclaration(decl) = node.kind() else {
             return;
         };
-        let Some(initializer) = &decl.initializer else {
-            return;
-        };
-        if initializer.is_literal() {
-            return;
-        }
-
-        if let Expression::TemplateLiteral(template) = initializer {
-            if template.expressions.is_empty() {
-                return;
+        for member in &decl.body.members {
+            let Some(initializer) = &member.initializer else {
+                continue;
+            };
+            if !self.is_allowed_initializer(decl, initializer) {
+                ctx.diagnostic(prefer_literal_enum_member_diagnostic(member.span));
             }
         }
+    }
 
-        if let Expression::UnaryExpression(unary_expr) = initializer {
-            if unary_expr.argument.is_literal() {
-                if matches!(
-                    unary_expr.operator,
-                    UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation,
-                ) {
-                    return;
-                }
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
 
-                if self.allow_bitwise_expressions
-                    && matches!(unary_expr.operator, UnaryOperator::BitwiseNot)
-                {
-                    return;
+impl PreferLiteralEnumMember {
+    fn is_allowed_initializer(&self, decl: &TSEnumDeclaration, initializer: &Expression) -> bool {
+        match initializer {
+            expr if expr.is_literal() => true,
+            Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+            Expression::UnaryExpression(unary_expr) => match unary_expr.operator {
+                UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation => {
+                    unary_expr.argument.is_literal()
                 }
-            }
-        }
-
-        if self.allow_bitwise_expressions {
-            if let Expression::BinaryExpression(binary_expr) = initializer {
-                if matches!(
-                    binary_expr.operator,
-                    BinaryOperator::BitwiseOR
-                        | BinaryOperator::BitwiseAnd
-                        | BinaryOperator::BitwiseXOR
-                        | BinaryOperator::ShiftLeft
-                        | BinaryOperator::ShiftRight
-                        | BinaryOperator::ShiftRightZeroFill
-                ) && binary_expr.left.is_literal()
-                    && binary_expr.right.is_literal()
-                {
-                    return;
+                UnaryOperator::BitwiseNot => {
+                    self.allow_bitwise_expressions && is_bitwise_operand(decl, &unary_expr.argument)
                 }
+                _ => false,
+            },
+            Expression::BinaryExpression(binary_expr) => {
+                self.allow_bitwise_expressions
+                    && binary_expr.operator.is_bitwise()
+                    && is_bitwise_operand(decl, &binary_expr.left)
+                    && is_bitwise_operand(decl, &binary_expr.right)
             }
+            _ => false,
         }
-
-        ctx.diagnostic(prefer_literal_enum_member_diagnostic(decl.span));
     }
+}
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+/// Whether `expr` is a literal, a reference to a member of the enum `decl`, or a bitwise
+/// expression of those.
+fn is_bitwise_operand(decl: &TSEnumDeclaration, expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        expr if expr.is_literal() => true,
+        // `A`
+        Expression::Identifier(ident) => has_member(decl, &ident.name),
+        // `Foo.A` and `Foo['A']`
+        expr @ match_member_expression!(Expression) => {
+            let member_expr = expr.to_member_expression();
+            matches!(
+                member_expr.object(),
+                Expression::Identifier(object) if object.name == decl.id.name
+            ) && member_expr.static_property_name().is_some_and(|name| has_member(decl, name))
+        }
+        Expression::UnaryExpression(unary_expr) => {
+            unary_expr.operator == UnaryOperator::BitwiseNot
+                && is_bitwise_operand(decl, &unary_expr.argument)
+        }
+        Expression::BinaryExpression(binary_expr) => {
+            binary_expr.operator.is_bitwise()
+                && is_bitwise_operand(decl, &binary_expr.left)
+                && is_bitwise_operand(decl, &binary_expr.right)
+        }
+        _ => false,
     }
 }
 
+fn has_member(decl: &TSEnumDeclaration, name: &str) -> bool {
+    decl.body.members.iter().any(|member| member.id.static_name() == name)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -222,6 +270,18 @@ fn test() {
         	      ",
             Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
         ),
+        (
+            "enum Foo { A = 1 << 0, B = 1 << 1, C = A | B }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "const enum Foo { A = 1 << 0, B = 1 << 1, C = A | B, D = Foo.A | Foo['B'], E = ~(A | B) }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "enum Foo { A = 1, B = 2, C = A | B | 4, 'D' = 8, E = C & D }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -359,6 +419,27 @@ fn test() {
         	      ",
             Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
         ),
+        ("const enum Foo { A = 1 << 0, B = 1 << 1, C = A | B }", None),
+        (
+            "const enum Foo { A = 1 << 0, B = 1 << 1, C = A | B }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": false }])),
+        ),
+        (
+            "const x = 1; enum Foo { A = 1, B = A | x }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "enum Bar { A = 1 } enum Foo { A = 1, B = Bar.A | 2 }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "enum Foo { A = 1, B = A + 1 }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "enum Foo { A = 1, B = A }",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
     ];
 
     Tester::new(PreferLiteralEnumMember::NAME, PreferLiteralEnumMember::PLUGIN, pass, fail)
@@ -0,0 +1,257 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, LogicalExpression, match_member_expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::LogicalOperator;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_same_expression};
+
+fn prefer_optional_chain_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Prefer using an optional chain expression instead, as it's more concise and easier to read.",
+    )
+    .with_help("Replace the `&&` checks with an optional chain, e.g. `foo?.bar`.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferOptionalChain;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using concise optional chain expressions instead of chained logical ands.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `?.` optional chain expressions provide `undefined` if an object is `null` or `undefined`.
+    /// Because the optional chain operator only chains when the property value is `null` or
+    /// `undefined`, it is much safer than relying upon logical AND operator chaining `&&`;
+    /// which chains on any truthy value. It is also often less code to use `?.` optional
+    /// chaining than `&&` truthiness checks.
+    ///
+    /// This rule does not use type information. It only reports `&&` operands that access a
+    /// member of, or call, the operand before them, such as `foo && foo.bar`. The suggested
+    /// fix changes the result when a checked value is falsy but not nullish, e.g. `0 && 0.a`
+    /// is `0` while `0?.a` is `undefined`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// foo && foo.a;
+    /// foo && foo.a && foo.a.b && foo.a.b.c;
+    /// foo && foo['a'] && foo['a'].b && foo['a'].b.c;
+    /// foo && foo.a && foo.a.b && foo.a.b.method && foo.a.b.method();
+    /// foo && foo();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// foo?.a;
+    /// foo?.a?.b?.c;
+    /// foo?.['a']?.b?.c;
+    /// foo?.a?.b?.method?.();
+    /// foo?.();
+    ///
+    /// // not a member access of the previous operand
+    /// foo && bar.a;
+    /// ```
+    PreferOptionalChain,
+    typescript,
+    style,
+    suggestion
+);
+
+impl Rule for PreferOptionalChain {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::LogicalExpression(logical_expr) = node.kind() else {
+            return;
+        };
+        if logical_expr.operator != LogicalOperator::And {
+            return;
+        }
+        // only check the outermost `&&` of `a && b && c`
+        if let AstKind::LogicalExpression(parent) = ctx.nodes().parent_kind(node.id()) {
+            if parent.operator == LogicalOperator::And {
+                return;
+            }
+        }
+
+        let mut operands = vec![];
+        collect_operands(&logical_expr.left, &mut operands);
+        operands.push(&logical_expr.right);
+
+        let mut start = 0;
+        while start < operands.len() {
+            let mut end = start;
+            while end + 1 < operands.len()
+                && find_optional_access(operands[end + 1], operands[end], ctx).is_some()
+            {
+                end += 1;
+            }
+            if end > start {
+                report(&operands[start..=end], logical_expr, ctx);
+            }
+            start = end + 1;
+        }
+    }
+}
+
+fn collect_operands<'a, 'b>(expr: &'b Expression<'a>, operands: &mut Vec<&'b Expression<'a>>) {
+    match expr {
+        Expression::LogicalExpression(logical_expr)
+            if logical_expr.operator == LogicalOperator::And =>
+        {
+            collect_operands(&logical_expr.left, operands);
+            operands.push(&logical_expr.right);
+        }
+        _ => operands.push(expr),
+    }
+}
+
+/// Finds the member access or call in `expr` whose object or callee is the same as `object`,
+/// e.g. `foo.a` in `foo.a.b` for `foo`. Returns the text between them to replace to make the
+/// access optional, and its replacement.
+fn find_optional_access(
+    expr: &Expression,
+    object: &Expression,
+    ctx: &LintContext,
+) -> Option<(Span, &'static str)> {
+    #[expect(clippy::unnested_or_patterns)]
+    if !matches!(
+        object,
+        Expression::Identifier(_)
+            | Expression::ThisExpression(_)
+            | match_member_expression!(Expression)
+    ) {
+        return None;
+    }
+
+    let mut current = expr;
+    loop {
+        let (inner, access) = match current {
+            Expression::CallExpression(call) => {
+                (&call.callee, (Span::empty(call.callee.span().end), "?."))
+            }
+            Expression::StaticMemberExpression(member) => (
+                &member.object,
+                (Span::new(member.object.span().end, member.property.span.start), "?."),
+            ),
+            Expression::ComputedMemberExpression(member) => (
+                &member.object,
+                (Span::new(member.object.span().end, member.expression.span().start), "?.["),
+            ),
+            Expression::PrivateFieldExpression(member) => (
+                &member.object,
+                (Span::new(member.object.span().end, member.field.span.start), "?."),
+            ),
+            _ => return None,
+        };
+        if is_same_expression(inner, object, ctx) {
+            return Some(access);
+        }
+        current = inner;
+    }
+}
+
+fn report(operands: &[&Expression], logical_expr: &LogicalExpression, ctx: &LintContext) {
+    let first = operands[0];
+    let last = operands[operands.len() - 1];
+    let span = Span::new(first.span().start, last.span().end);
+    let diagnostic = prefer_optional_chain_diagnostic(span);
+    if ctx.has_comments_between(logical_expr.span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+        // make the access of every checked operand in `last` optional
+        let mut accesses = operands[..operands.len() - 1]
+            .iter()
+            .filter_map(|operand| find_optional_access(last, operand, ctx))
+            .collect::<Vec<_>>();
+        accesses.sort_unstable_by_key(|(span, _)| span.start);
+
+        let mut text = String::new();
+        let mut position = last.span().start;
+        for (access_span, replacement) in accesses {
+            text.push_str(ctx.source_range(Span::new(position, access_span.start)));
+            text.push_str(replacement);
+            position = access_span.end;
+        }
+        text.push_str(ctx.source_range(Span::new(position, last.span().end)));
+        fixer.replace(span, text).with_message("Change to an optional chain")
+    });
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "foo?.a;",
+        "foo?.a?.b?.c;",
+        "foo?.();",
+        "foo && bar.a;",
+        "foo && bar;",
+        "foo.a && foo.b;",
+        "foo.a.b && foo.a;",
+        "foo || foo.a;",
+        "foo && foo;",
+        "foo() && foo().a;",
+        "(foo && bar) && foo.a;",
+        "foo && new foo.a();",
+        "foo[a] && foo[b].c;",
+    ];
+
+    let fail = vec![
+        "foo && foo.a;",
+        "foo && foo.a && foo.a.b && foo.a.b.c;",
+        "foo && foo['a'] && foo['a'].b && foo['a'].b.c;",
+        "foo && foo.a && foo.a.b && foo.a.b.method && foo.a.b.method();",
+        "foo && foo();",
+        "foo.a && foo.a();",
+        "foo && foo.a.b;",
+        "foo && foo.a && foo.a.b.c;",
+        "this.foo && this.foo.a;",
+        "foo && foo[a] && foo[a].b;",
+        "x && foo && foo.a;",
+        "foo && foo.a && x;",
+        "foo && foo.a && bar && bar.b;",
+        "if (foo && foo.a) {}",
+        "foo && /* comment */ foo.a;",
+        "class C { #a; m() { this && this.#a; } }",
+    ];
+
+    let fix = vec![
+        ("foo && foo.a;", "foo?.a;"),
+        ("foo && foo.a && foo.a.b && foo.a.b.c;", "foo?.a?.b?.c;"),
+        ("foo && foo['a'] && foo['a'].b && foo['a'].b.c;", "foo?.['a']?.b?.c;"),
+        (
+            "foo && foo.a && foo.a.b && foo.a.b.method && foo.a.b.method();",
+            "foo?.a?.b?.method?.();",
+        ),
+        ("foo && foo();", "foo?.();"),
+        ("foo.a && foo.a();", "foo.a?.();"),
+        ("foo && foo.a.b;", "foo?.a.b;"),
+        ("foo && foo.a && foo.a.b.c;", "foo?.a?.b.c;"),
+        ("this.foo && this.foo.a;", "this.foo?.a;"),
+        ("foo && foo[a] && foo[a].b;", "foo?.[a]?.b;"),
+        ("x && foo && foo.a;", "x && foo?.a;"),
+        ("foo && foo.a && x;", "foo?.a && x;"),
+        ("foo && foo.a && bar && bar.b;", "foo?.a && bar?.b;"),
+        ("if (foo && foo.a) {}", "if (foo?.a) {}"),
+        ("foo && foo .a;", "foo?.a;"),
+        ("foo && foo<string>();", "foo?.<string>();"),
+        ("foo && /* comment */ foo.a;", "foo && /* comment */ foo.a;"),
+        ("class C { #a; m() { this && this.#a; } }", "class C { #a; m() { this?.#a; } }"),
+    ];
+
+    Tester::new(PreferOptionalChain::NAME, PreferOptionalChain::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
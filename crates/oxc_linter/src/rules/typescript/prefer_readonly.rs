@@ -0,0 +1,307 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, Class, ClassElement, Expression, FormalParameter, Function,
+        MemberExpression, MethodDefinitionKind, PropertyDefinition, PropertyDefinitionType,
+        PropertyKey, SimpleAssignmentTarget, TSAccessibility,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    AstNode,
+    ast_util::binding_identifier_span,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn prefer_readonly_diagnostic(name: MemberName, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Member '{name}' is never reassigned; mark it as `readonly`."))
+        .with_help("Add the `readonly` modifier.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferReadonly;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require private members to be marked as `readonly` if they're never modified outside of
+    /// the constructor.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Private member variables (whether using the `private` modifier or private `#` fields) are
+    /// only permitted to be modified within their declaring class. If that class never modifies
+    /// their value outside of the constructor, they may safely be marked as `readonly`, which
+    /// makes this intent explicit and prevents accidental reassignments later on.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// class Container {
+    ///   private neverModifiedMember = true;
+    ///   #neverModifiedPrivateField = 1;
+    ///
+    ///   constructor(private onlyModifiedInConstructor: number) {
+    ///     this.onlyModifiedInConstructor = onlyModifiedInConstructor * 2;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// class Container {
+    ///   private readonly neverModifiedMember = true;
+    ///   private modifiedLater = 'unchanged';
+    ///
+    ///   public mutate() {
+    ///     this.modifiedLater = 'mutated';
+    ///   }
+    /// }
+    /// ```
+    PreferReadonly,
+    typescript,
+    style,
+    fix
+);
+
+/// Name of a class member, either declared with the `private` modifier or as a `#private` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MemberName<'a> {
+    Public(&'a str),
+    Private(&'a str),
+}
+
+impl std::fmt::Display for MemberName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Public(name) => write!(f, "{name}"),
+            Self::Private(name) => write!(f, "#{name}"),
+        }
+    }
+}
+
+/// A private member that is not `readonly` yet.
+struct Candidate<'a> {
+    name: MemberName<'a>,
+    /// Span of the member name, where the diagnostic is reported.
+    span: Span,
+    /// The `readonly` modifier is inserted in front of this span.
+    insert_before: Span,
+}
+
+impl Rule for PreferReadonly {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+
+        let candidates = collect_candidates(class);
+        if candidates.is_empty() {
+            return;
+        }
+
+        let mut visitor = ModificationVisitor::default();
+        for element in &class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    if let Some(body) = &method.value.body {
+                        visitor.in_constructor = true;
+                        visitor.visit_function_body(body);
+                        visitor.in_constructor = false;
+                    }
+                }
+                _ => visitor.visit_class_element(element),
+            }
+        }
+
+        for candidate in candidates {
+            if visitor.modified.contains(&candidate.name) {
+                continue;
+            }
+            ctx.diagnostic_with_fix(
+                prefer_readonly_diagnostic(candidate.name, candidate.span),
+                |fixer| fixer.insert_text_before_range(candidate.insert_before, "readonly "),
+            );
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn collect_candidates<'a>(class: &Class<'a>) -> Vec<Candidate<'a>> {
+    let mut candidates = vec![];
+    for element in &class.body.body {
+        match element {
+            ClassElement::PropertyDefinition(prop) => {
+                if let Some(candidate) = property_candidate(prop) {
+                    candidates.push(candidate);
+                }
+            }
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                candidates.extend(method.value.params.items.iter().filter_map(parameter_candidate));
+            }
+            _ => {}
+        }
+    }
+    candidates
+}
+
+fn property_candidate<'a>(prop: &PropertyDefinition<'a>) -> Option<Candidate<'a>> {
+    if prop.r#static
+        || prop.readonly
+        || prop.declare
+        || prop.r#type != PropertyDefinitionType::PropertyDefinition
+    {
+        return None;
+    }
+    let name = match &prop.key {
+        PropertyKey::PrivateIdentifier(ident) => MemberName::Private(ident.name.as_str()),
+        PropertyKey::StaticIdentifier(ident)
+            if prop.accessibility == Some(TSAccessibility::Private) =>
+        {
+            MemberName::Public(ident.name.as_str())
+        }
+        _ => return None,
+    };
+    let span = prop.key.span();
+    Some(Candidate { name, span, insert_before: span })
+}
+
+/// Parameter properties, e.g. `private foo` in `constructor(private foo: string) {}`.
+fn parameter_candidate<'a>(param: &FormalParameter<'a>) -> Option<Candidate<'a>> {
+    if param.readonly || param.accessibility != Some(TSAccessibility::Private) {
+        return None;
+    }
+    let ident = param.pattern.get_binding_identifier()?;
+    Some(Candidate {
+        name: MemberName::Public(ident.name.as_str()),
+        span: binding_identifier_span(&param.pattern, ident),
+        insert_before: param.pattern.span(),
+    })
+}
+
+/// Collects the members which are written to anywhere but directly on `this` in the
+/// constructor.
+#[derive(Default)]
+struct ModificationVisitor<'a> {
+    in_constructor: bool,
+    modified: FxHashSet<MemberName<'a>>,
+}
+
+impl<'a> Visit<'a> for ModificationVisitor<'a> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let in_constructor = std::mem::replace(&mut self.in_constructor, false);
+        walk::walk_function(self, func, flags);
+        self.in_constructor = in_constructor;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let in_constructor = std::mem::replace(&mut self.in_constructor, false);
+        walk::walk_arrow_function_expression(self, arrow);
+        self.in_constructor = in_constructor;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        let in_constructor = std::mem::replace(&mut self.in_constructor, false);
+        walk::walk_class(self, class);
+        self.in_constructor = in_constructor;
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        let member = match target.get_expression() {
+            Some(expr) => expr.get_inner_expression().as_member_expression(),
+            None => target.as_member_expression(),
+        };
+        if let Some(member) = member {
+            let name = match member {
+                MemberExpression::PrivateFieldExpression(expr) => {
+                    Some(MemberName::Private(expr.field.name.as_str()))
+                }
+                _ => member.static_property_name().map(MemberName::Public),
+            };
+            let is_this =
+                matches!(member.object().without_parentheses(), Expression::ThisExpression(_));
+            if let Some(name) = name {
+                if !(self.in_constructor && is_this) {
+                    self.modified.insert(name);
+                }
+            }
+        }
+        walk::walk_simple_assignment_target(self, target);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class Foo { private readonly x = 1; }",
+        "class Foo { public x = 1; }",
+        "class Foo { protected x = 1; }",
+        "class Foo { x = 1; }",
+        "class Foo { private static x = 1; }",
+        "class Foo { private x = 1; bar() { this.x = 2; } }",
+        "class Foo { private x = 1; bar() { this.x++; } }",
+        "class Foo { private x = 1; bar() { [this.x] = [2]; } }",
+        "class Foo { private x = 1; bar() { ({ a: this.x } = { a: 2 }); } }",
+        "class Foo { private x = 1; bar(other: Foo) { other.x = 2; } }",
+        "class Foo { private x = 1; bar() { (this.x as number) = 2; } }",
+        "class Foo { #x = 1; bar() { this.#x = 2; } }",
+        "class Foo { #x = 1; bar() { this.#x += 2; } }",
+        "class Foo { private x: number; constructor() { const f = () => { this.x = 1; }; } }",
+        "class Foo { private x: number; constructor() { function f() { this.x = 1; } } }",
+        "class Foo { private x = () => { this.x = () => {}; }; }",
+        "class Foo { constructor(private readonly x: number) {} }",
+        "class Foo { constructor(public x: number) {} }",
+        "class Foo { constructor(private x: number) {} bar() { this.x = 1; } }",
+        "class Foo { declare private x: number; }",
+    ];
+
+    let fail = vec![
+        "class Foo { private x = 1; }",
+        "class Foo { #x = 1; }",
+        "class Foo { private x: number; constructor() { this.x = 1; } }",
+        "class Foo { #x: number; constructor() { this.#x = 1; } }",
+        "class Foo { constructor(private x: number) {} }",
+        "class Foo { constructor(private x: number) { this.x = x * 2; } }",
+        "class Foo { private x = 1; bar() { return this.x; } }",
+        "class Foo { private x = 1; private y = 2; bar() { this.y = 3; } }",
+    ];
+
+    let fix = vec![
+        ("class Foo { private x = 1; }", "class Foo { private readonly x = 1; }"),
+        ("class Foo { #x = 1; }", "class Foo { readonly #x = 1; }"),
+        (
+            "class Foo { constructor(private x: number) {} }",
+            "class Foo { constructor(private readonly x: number) {} }",
+        ),
+        (
+            "class Foo { constructor(private x = 1) {} }",
+            "class Foo { constructor(private readonly x = 1) {} }",
+        ),
+        (
+            "class Foo { private x = 1; private y = 2; bar() { this.y = 3; } }",
+            "class Foo { private readonly x = 1; private y = 2; bar() { this.y = 3; } }",
+        ),
+    ];
+
+    Tester::new(PreferReadonly::NAME, PreferReadonly::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,184 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn prefer_reduce_type_parameter_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Unnecessary cast: `Array#reduce` accepts a type parameter for the initial value.",
+    )
+    .with_help("Pass the type as a type argument to `reduce` instead.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferReduceTypeParameter;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using a type parameter for `Array#reduce` instead of casting the initial value.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A common pattern is to cast the initial value of `Array#reduce` to set the type of the
+    /// accumulator, as the type of the initial value would otherwise be too narrow, e.g. `[]`
+    /// is `never[]`. However, a type assertion is unsafe: it also allows initial values which
+    /// don't match the asserted type. `Array#reduce` accepts a type parameter for the type of
+    /// the accumulator, which is type checked against the initial value.
+    ///
+    /// This rule does not use type information. It checks every `reduce` call, not only calls
+    /// on arrays.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// [1, 2, 3].reduce((arr, num) => arr.concat(num * 2), [] as number[]);
+    ///
+    /// ['a', 'b'].reduce(
+    ///   (accum, name) => ({ ...accum, [name]: true }),
+    ///   {} as Record<string, boolean>,
+    /// );
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// [1, 2, 3].reduce<number[]>((arr, num) => arr.concat(num * 2), []);
+    ///
+    /// ['a', 'b'].reduce<Record<string, boolean>>(
+    ///   (accum, name) => ({ ...accum, [name]: true }),
+    ///   {},
+    /// );
+    /// ```
+    PreferReduceTypeParameter,
+    typescript,
+    style,
+    suggestion
+);
+
+impl Rule for PreferReduceTypeParameter {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        if call.type_arguments.is_some() || call.arguments.len() != 2 {
+            return;
+        }
+        let Some(member) = call.callee.without_parentheses().as_member_expression() else {
+            return;
+        };
+        if member.static_property_name() != Some("reduce") {
+            return;
+        }
+        let Some(initial_value) = call.arguments[1].as_expression() else {
+            return;
+        };
+        let (assertion_span, expression, type_annotation) =
+            match initial_value.without_parentheses() {
+                Expression::TSAsExpression(assertion) => {
+                    (assertion.span, &assertion.expression, &assertion.type_annotation)
+                }
+                Expression::TSTypeAssertion(assertion) => {
+                    (assertion.span, &assertion.expression, &assertion.type_annotation)
+                }
+                _ => return,
+            };
+        // `as const` can't be a type argument.
+        if type_annotation.is_const_type_reference() {
+            return;
+        }
+
+        let diagnostic = prefer_reduce_type_parameter_diagnostic(assertion_span);
+        if ctx.has_comments_between(assertion_span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        // Without type information it isn't known whether the initial value is assignable to the
+        // type, which a type argument requires, unlike an assertion.
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            let type_text = ctx.source_range(type_annotation.span());
+            fixer
+                .insert_text_after_range(call.callee.span(), format!("<{type_text}>"))
+                .extend(fixer.replace(assertion_span, ctx.source_range(expression.span())))
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "[1, 2, 3].reduce<number[]>((arr, num) => arr.concat(num * 2), []);",
+        "[1, 2, 3].reduce<number[]>((arr, num) => arr.concat(num * 2), [] as number[]);",
+        "['a', 'b'].reduce<Record<string, boolean>>((accum, name) => ({ ...accum, [name]: true }), {});",
+        "[1, 2, 3].reduce((sum, num) => sum + num, 0);",
+        "[1, 2, 3].reduce((sum, num) => sum + num);",
+        "[1, 2, 3].reduce((arr, num) => arr.concat(num), [] as const);",
+        "[1, 2, 3].reduceRight((arr, num) => arr.concat(num), [] as number[]);",
+        "[1, 2, 3].map((num) => num, [] as number[]);",
+        "reduce((arr, num) => arr.concat(num), [] as number[]);",
+        "[1, 2, 3][reduce]((arr, num) => arr.concat(num), [] as number[]);",
+        "[1, 2, 3].reduce((arr, num) => arr.concat(num), [] satisfies number[]);",
+        "[1, 2, 3].reduce((arr, num) => arr.concat(num), [], [] as number[]);",
+        "[1, 2, 3].reduce([] as number[], (arr, num) => arr.concat(num));",
+    ];
+
+    let fail = vec![
+        "[1, 2, 3].reduce((arr, num) => arr.concat(num * 2), [] as number[]);",
+        "['a', 'b'].reduce((accum, name) => ({ ...accum, [name]: true }), {} as Record<string, boolean>);",
+        "[1, 2, 3].reduce((arr, num) => arr.concat(num * 2), ([] as number[]));",
+        "[1, 2, 3]?.reduce((arr, num) => arr.concat(num * 2), [] as number[]);",
+        "[1, 2, 3]['reduce']((arr, num) => arr.concat(num * 2), [] as number[]);",
+        "(arr.reduce)((acc, num) => acc.concat(num), [] as number[]);",
+        "arr.reduce((acc, num) => acc + num, 0 as number);",
+        "arr.reduce((acc, num) => acc.concat(num), [] as /* numbers */ number[]);",
+        "const result = arr.reduce((acc, x) => acc.concat(x.reduce((a, b) => a + b, 0 as number)), [] as number[]);",
+    ];
+
+    let fix = vec![
+        (
+            "[1, 2, 3].reduce((arr, num) => arr.concat(num * 2), [] as number[]);",
+            "[1, 2, 3].reduce<number[]>((arr, num) => arr.concat(num * 2), []);",
+        ),
+        (
+            "['a', 'b'].reduce((accum, name) => ({ ...accum, [name]: true }), {} as Record<string, boolean>);",
+            "['a', 'b'].reduce<Record<string, boolean>>((accum, name) => ({ ...accum, [name]: true }), {});",
+        ),
+        (
+            "[1, 2, 3].reduce((arr, num) => arr.concat(num * 2), ([] as number[]));",
+            "[1, 2, 3].reduce<number[]>((arr, num) => arr.concat(num * 2), ([]));",
+        ),
+        (
+            "[1, 2, 3]?.reduce((arr, num) => arr.concat(num * 2), [] as number[]);",
+            "[1, 2, 3]?.reduce<number[]>((arr, num) => arr.concat(num * 2), []);",
+        ),
+        (
+            "[1, 2, 3]['reduce']((arr, num) => arr.concat(num * 2), [] as number[]);",
+            "[1, 2, 3]['reduce']<number[]>((arr, num) => arr.concat(num * 2), []);",
+        ),
+        (
+            "(arr.reduce)((acc, num) => acc.concat(num), [] as number[]);",
+            "(arr.reduce)<number[]>((acc, num) => acc.concat(num), []);",
+        ),
+        (
+            "arr.reduce((acc, num) => acc.concat(num), [] as /* numbers */ number[]);",
+            "arr.reduce((acc, num) => acc.concat(num), [] as /* numbers */ number[]);",
+        ),
+    ];
+
+    Tester::new(PreferReduceTypeParameter::NAME, PreferReduceTypeParameter::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
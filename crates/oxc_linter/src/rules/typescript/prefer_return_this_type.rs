@@ -0,0 +1,281 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, Class, ClassElement, Expression, Function, FunctionBody,
+        MethodDefinitionKind, ReturnStatement, TSType, TSTypeAnnotation, TSTypeName,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn prefer_return_this_type_diagnostic(class_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use `this` type instead.")
+        .with_help(format!(
+            "This method only returns `this`, annotating it with `{class_name}` loses the type of subclasses."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferReturnThisType;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that `this` is used when only `this` type is returned.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Method chaining is a common pattern in OOP languages. When a method returns `this`, its
+    /// return type should be the `this` type rather than the class itself. Otherwise, calling
+    /// the method on a subclass returns the type of the base class, which breaks chaining of
+    /// the subclass' methods.
+    ///
+    /// This rule does not use type information. It checks methods and function properties of
+    /// a class annotated with the class' name, whose `return` statements all return `this`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// class Foo {
+    ///   f1(): Foo {
+    ///     return this;
+    ///   }
+    ///   f2 = (): Foo => {
+    ///     return this;
+    ///   };
+    ///   f3(): Foo | undefined {
+    ///     return Math.random() > 0.5 ? this : undefined;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// class Foo {
+    ///   f1(): this {
+    ///     return this;
+    ///   }
+    ///   f2 = (): this => {
+    ///     return this;
+    ///   };
+    ///   f3(): Foo {
+    ///     return new Foo();
+    ///   }
+    /// }
+    /// ```
+    PreferReturnThisType,
+    typescript,
+    style,
+    fix
+);
+
+impl Rule for PreferReturnThisType {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+        let Some(class_id) = &class.id else {
+            return;
+        };
+
+        for element in &class.body.body {
+            let (return_type, body) = match element {
+                ClassElement::MethodDefinition(method)
+                    if !method.r#static
+                        && matches!(
+                            method.kind,
+                            MethodDefinitionKind::Method | MethodDefinitionKind::Get
+                        ) =>
+                {
+                    let Some(body) = &method.value.body else {
+                        continue;
+                    };
+                    (&method.value.return_type, Body::Function(body))
+                }
+                ClassElement::PropertyDefinition(prop) if !prop.r#static => match &prop.value {
+                    Some(Expression::ArrowFunctionExpression(arrow)) => {
+                        (&arrow.return_type, Body::Arrow(arrow))
+                    }
+                    Some(Expression::FunctionExpression(func)) => {
+                        let Some(body) = &func.body else {
+                            continue;
+                        };
+                        (&func.return_type, Body::Function(body))
+                    }
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            let Some(span) = get_class_type_span(return_type.as_deref(), &class_id.name) else {
+                continue;
+            };
+            if !body.returns_only_this() {
+                continue;
+            }
+            ctx.diagnostic_with_fix(
+                prefer_return_this_type_diagnostic(&class_id.name, span),
+                |fixer| fixer.replace(span, "this"),
+            );
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+/// The span of the class' name in a return type like `Foo`, `Foo<T>` or `Foo | undefined`.
+fn get_class_type_span(return_type: Option<&TSTypeAnnotation>, class_name: &str) -> Option<Span> {
+    let class_type_span = |ty: &TSType| match ty {
+        TSType::TSTypeReference(reference) => match &reference.type_name {
+            TSTypeName::IdentifierReference(ident) if ident.name == class_name => {
+                Some(reference.span)
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match &return_type?.type_annotation {
+        TSType::TSUnionType(union) => union.types.iter().find_map(class_type_span),
+        ty => class_type_span(ty),
+    }
+}
+
+enum Body<'a, 'b> {
+    Function(&'b FunctionBody<'a>),
+    Arrow(&'b ArrowFunctionExpression<'a>),
+}
+
+impl Body<'_, '_> {
+    /// Whether the function returns something, and all returned values are `this`.
+    fn returns_only_this(&self) -> bool {
+        let body: &FunctionBody = match self {
+            Self::Arrow(arrow) if arrow.expression => {
+                return arrow.get_expression().is_some_and(is_this);
+            }
+            Self::Arrow(arrow) => &arrow.body,
+            Self::Function(body) => body,
+        };
+
+        let mut finder = ReturnFinder::default();
+        finder.visit_function_body(body);
+        finder.returns_this && !finder.returns_other
+    }
+}
+
+/// `this`, or a conditional expression returning `this` or `null` / `undefined`.
+fn is_this(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::ThisExpression(_) => true,
+        Expression::ConditionalExpression(conditional) => {
+            (is_this(&conditional.consequent) || is_nullish(&conditional.consequent))
+                && (is_this(&conditional.alternate) || is_nullish(&conditional.alternate))
+                && !(is_nullish(&conditional.consequent) && is_nullish(&conditional.alternate))
+        }
+        _ => false,
+    }
+}
+
+fn is_nullish(expr: &Expression) -> bool {
+    expr.without_parentheses().is_null_or_undefined()
+}
+
+/// Checks the `return` statements of a function body, excluding those of nested functions.
+#[derive(Default)]
+struct ReturnFinder {
+    returns_this: bool,
+    returns_other: bool,
+}
+
+impl<'a> Visit<'a> for ReturnFinder {
+    fn visit_return_statement(&mut self, ret: &ReturnStatement<'a>) {
+        match &ret.argument {
+            Some(argument) if is_this(argument) => self.returns_this = true,
+            _ => self.returns_other = true,
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class Foo { f1() {} f2(): Foo { return new Foo(); } }",
+        "class Foo { f(): this { return this; } }",
+        "class Foo { f = (): this => this; }",
+        "class Foo { f = (): this => { return this; }; }",
+        "class Foo { f = function (): this { return this; }; }",
+        "class Foo { f(): Foo {} }",
+        "class Foo { f(): Foo { return; } }",
+        "class Foo { f(): Foo { if (x) return this; return new Foo(); } }",
+        "class Foo { f(): Foo { const self = this; return self; } }",
+        "class Foo { f(): Bar { return this; } }",
+        "class Foo { f(): Foo.Bar { return this; } }",
+        "class Foo { static f(): Foo { return this; } }",
+        "class Foo { f(): Foo { return (() => this)(); } }",
+        "class Foo { f(): Foo { function g() { return this; } return new Foo(); } }",
+        "class Foo { f(): Foo | undefined { return undefined; } }",
+        "class Foo { f(): Foo { return x ? this : new Foo(); } }",
+        "const Foo = class { f(): Foo { return this; } };",
+        "class Foo { f = (): Foo => new Foo(); }",
+        "class Foo { constructor() {} }",
+        "declare class Foo { f(): Foo; }",
+        "abstract class Foo { abstract f(): Foo; }",
+    ];
+
+    let fail = vec![
+        "class Foo { f(): Foo { return this; } }",
+        "class Foo { f(): Foo { if (x) return this; return this; } }",
+        "class Foo { f = (): Foo => this; }",
+        "class Foo { f = (): Foo => { return this; }; }",
+        "class Foo { f = function (): Foo { return this; }; }",
+        "class Foo<T> { f(): Foo<T> { return this; } }",
+        "class Foo { f(): Foo | undefined { return this; } }",
+        "class Foo { f(): Foo | undefined { return x ? this : undefined; } }",
+        "class Foo { get f(): Foo { return this; } }",
+        "class Foo { f(): Foo { const g = () => new Foo(); return this; } }",
+        "const Bar = class Foo { f(): Foo { return this; } };",
+        "class Animal { setName(name: string): Animal { this.name = name; return this; } setAge(age: number): Animal { this.age = age; return this; } }",
+    ];
+
+    let fix = vec![
+        ("class Foo { f(): Foo { return this; } }", "class Foo { f(): this { return this; } }"),
+        ("class Foo { f = (): Foo => this; }", "class Foo { f = (): this => this; }"),
+        (
+            "class Foo<T> { f(): Foo<T> { return this; } }",
+            "class Foo<T> { f(): this { return this; } }",
+        ),
+        (
+            "class Foo { f(): Foo | undefined { return this; } }",
+            "class Foo { f(): this | undefined { return this; } }",
+        ),
+        (
+            "class Animal { setName(name: string): Animal { this.name = name; return this; } setAge(age: number): Animal { this.age = age; return this; } }",
+            "class Animal { setName(name: string): this { this.name = name; return this; } setAge(age: number): this { this.age = age; return this; } }",
+        ),
+    ];
+
+    Tester::new(PreferReturnThisType::NAME, PreferReturnThisType::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
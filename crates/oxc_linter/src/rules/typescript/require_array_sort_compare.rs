@@ -0,0 +1,202 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ArrayExpression, ArrayExpressionElement, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn require_array_sort_compare_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Require 'compare' argument.")
+        .with_help("Provide a compare function, as elements are compared as strings by default.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct RequireArraySortCompare {
+    ignore_string_arrays: bool,
+}
+
+impl Default for RequireArraySortCompare {
+    fn default() -> Self {
+        Self { ignore_string_arrays: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `Array#sort` and `Array#toSorted` calls to always provide a compare function.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When called without a compare function, `sort()` and `toSorted()` convert all
+    /// elements to strings and compare them by their UTF-16 code units. This is rarely the
+    /// intended order for anything but strings, e.g. `[1, 2, 10].sort()` results in
+    /// `[1, 10, 2]`.
+    ///
+    /// This rule does not use type information. It only checks array literals whose elements
+    /// are all number, bigint or string literals.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// [1, 2, 10].sort();
+    ///
+    /// [3n, 1n].toSorted();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// [1, 2, 10].sort((a, b) => a - b);
+    ///
+    /// ['b', 'a'].sort();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignoreStringArrays
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Don't report arrays whose elements are all strings, which are sorted as expected
+    /// without a compare function.
+    ///
+    /// Example:
+    /// ```json
+    /// "typescript/require-array-sort-compare": [
+    ///   "error",
+    ///   { "ignoreStringArrays": false }
+    /// ]
+    /// ```
+    RequireArraySortCompare,
+    typescript,
+    pedantic
+);
+
+impl Rule for RequireArraySortCompare {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_string_arrays = value
+            .get(0)
+            .and_then(|config| config.get("ignoreStringArrays"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+
+        Self { ignore_string_arrays }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        if !call.arguments.is_empty() {
+            return;
+        }
+        let Some(member) = call.callee.without_parentheses().as_member_expression() else {
+            return;
+        };
+        if !matches!(member.static_property_name(), Some("sort" | "toSorted")) {
+            return;
+        }
+        let Expression::ArrayExpression(array) = member.object().get_inner_expression() else {
+            return;
+        };
+
+        match get_element_kind(array) {
+            Some(ElementKind::String) if self.ignore_string_arrays => {}
+            Some(_) => ctx.diagnostic(require_array_sort_compare_diagnostic(call.span)),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    String,
+    Other,
+}
+
+/// `String` if all elements of `array` are string literals, `Other` if they are number,
+/// bigint or string literals, and `None` if the array is empty or contains other elements.
+fn get_element_kind(array: &ArrayExpression) -> Option<ElementKind> {
+    let mut kind = None;
+    for element in &array.elements {
+        let element_kind = match element {
+            ArrayExpressionElement::StringLiteral(_) => ElementKind::String,
+            ArrayExpressionElement::TemplateLiteral(template)
+                if template.expressions.is_empty() =>
+            {
+                ElementKind::String
+            }
+            ArrayExpressionElement::NumericLiteral(_)
+            | ArrayExpressionElement::BigIntLiteral(_) => ElementKind::Other,
+            ArrayExpressionElement::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    && matches!(
+                        unary.argument,
+                        Expression::NumericLiteral(_) | Expression::BigIntLiteral(_)
+                    ) =>
+            {
+                ElementKind::Other
+            }
+            _ => return None,
+        };
+        kind = match kind {
+            None | Some(ElementKind::String) => Some(element_kind),
+            Some(ElementKind::Other) => Some(ElementKind::Other),
+        };
+    }
+    kind
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("[1, 2, 10].sort((a, b) => a - b);", None),
+        ("[1, 2, 10].toSorted((a, b) => a - b);", None),
+        ("[1, 2, 10].sort(compare);", None),
+        ("['b', 'a'].sort();", None),
+        ("['b', `a`].toSorted();", None),
+        ("[].sort();", None),
+        ("[a, b].sort();", None),
+        ("[1, a].sort();", None),
+        ("[1, ...a].sort();", None),
+        ("[1, , 2].sort();", None),
+        ("[`${a}`, 1].sort();", None),
+        ("array.sort();", None),
+        ("[1, 2].reverse();", None),
+        ("[1, 2][sort]();", None),
+        ("sort([1, 2]);", None),
+        (
+            "[1, 2].sort((a, b) => a - b);",
+            Some(serde_json::json!([{ "ignoreStringArrays": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("[1, 2, 10].sort();", None),
+        ("[1, 2, 10].toSorted();", None),
+        ("[-1, 2].sort();", None),
+        ("[1.5, 0x10].sort();", None),
+        ("[3n, 1n, -2n].toSorted();", None),
+        ("[1, 'a'].sort();", None),
+        ("['a', 1].sort();", None),
+        ("([1, 2]).sort();", None),
+        ("([1, 2] as number[]).sort();", None),
+        ("[1, 2]?.sort();", None),
+        ("[1, 2]['sort']();", None),
+        ("const sorted = [10, 9].sort().map(String);", None),
+        ("['b', 'a'].sort();", Some(serde_json::json!([{ "ignoreStringArrays": false }]))),
+        ("['b', `a`].toSorted();", Some(serde_json::json!([{ "ignoreStringArrays": false }]))),
+        ("[2, 1].sort();", Some(serde_json::json!([{ "ignoreStringArrays": true }]))),
+    ];
+
+    Tester::new(RequireArraySortCompare::NAME, RequireArraySortCompare::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
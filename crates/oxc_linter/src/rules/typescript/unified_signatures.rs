@@ -0,0 +1,476 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BindingPatternKind, ClassElement, Declaration, ExportDefaultDeclarationKind,
+        FormalParameter, FormalParameters, Function, Statement, TSSignature, TSThisParameter,
+        TSTypeAnnotation, TSTypeParameterDeclaration,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, ContentEq, GetSpan, Span};
+
+use crate::{
+    AstNode,
+    ast_util::binding_identifier_span,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn omitting_single_parameter_diagnostic(redundant: Span, param: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "These overloads can be combined into one signature with an optional parameter.",
+    )
+    .with_help("Remove this overload and make the extra parameter of the other one optional.")
+    .with_labels([
+        redundant.label("This overload is redundant"),
+        param.label("This parameter can be made optional"),
+    ])
+}
+
+fn omitting_rest_parameter_diagnostic(redundant: Span, param: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("These overloads can be combined into one signature with a rest parameter.")
+        .with_help("Remove this overload, the rest parameter of the other one already covers it.")
+        .with_labels([
+            redundant.label("This overload is redundant"),
+            param.label("This rest parameter accepts zero or more arguments"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct UnifiedSignatures;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow two overloads that could be unified into one with an optional or rest parameter.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Function overload signatures represent multiple ways a function can be called.
+    /// When two overloads only differ by a trailing parameter, a single signature with an
+    /// optional or rest parameter describes the same calls and is easier to read and maintain.
+    ///
+    /// This rule compares parameter and return types syntactically, so it only reports
+    /// overloads whose shared parameters are written with identical types.
+    ///
+    /// The suggested fix removes the redundant overload and makes the extra parameter of the
+    /// other overload optional. It is not offered if the redundant overload contains comments,
+    /// or if the extra parameter is a destructuring pattern.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// function x(a: string): void;
+    /// function x(a: string, b: number): void;
+    ///
+    /// function y(): void;
+    /// function y(...args: string[]): void;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// function x(a: string, b?: number): void;
+    ///
+    /// function y(...args: string[]): void;
+    ///
+    /// // Different return types can not be unified.
+    /// function z(a: string): string;
+    /// function z(a: string, b: number): number;
+    /// ```
+    UnifiedSignatures,
+    typescript,
+    style,
+    suggestion
+);
+
+/// An overload signature, along with the name of the function or method it belongs to.
+struct Signature<'a, 'b> {
+    name: CompactStr,
+    r#static: bool,
+    span: Span,
+    type_parameters: Option<&'b TSTypeParameterDeclaration<'a>>,
+    this_param: Option<&'b TSThisParameter<'a>>,
+    params: &'b FormalParameters<'a>,
+    return_type: Option<&'b TSTypeAnnotation<'a>>,
+}
+
+impl<'a, 'b> Signature<'a, 'b> {
+    fn from_function(name: CompactStr, r#static: bool, span: Span, func: &'b Function<'a>) -> Self {
+        Self {
+            name,
+            r#static,
+            span,
+            type_parameters: func.type_parameters.as_deref(),
+            this_param: func.this_param.as_deref(),
+            params: &func.params,
+            return_type: func.return_type.as_deref(),
+        }
+    }
+
+    fn from_statement(stmt: &'b Statement<'a>) -> Option<Self> {
+        let func = match stmt {
+            Statement::FunctionDeclaration(func) => func,
+            Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => func,
+                _ => return None,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => func,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if !func.is_ts_declare_function() {
+            return None;
+        }
+        let id = func.id.as_ref()?;
+        // Use the span of the statement to include any `export` keyword.
+        Some(Self::from_function(id.name.to_compact_str(), false, stmt.span(), func))
+    }
+
+    fn from_class_element(element: &'b ClassElement<'a>) -> Option<Self> {
+        let ClassElement::MethodDefinition(method) = element else {
+            return None;
+        };
+        if method.value.body.is_some() {
+            return None;
+        }
+        let name = method.key.static_name()?;
+        Some(Self::from_function(name.into(), method.r#static, method.span, &method.value))
+    }
+
+    fn from_ts_signature(signature: &'b TSSignature<'a>) -> Option<Self> {
+        match signature {
+            TSSignature::TSMethodSignature(sig) => Some(Self {
+                name: sig.key.static_name()?.into(),
+                r#static: false,
+                span: sig.span,
+                type_parameters: sig.type_parameters.as_deref(),
+                this_param: sig.this_param.as_deref(),
+                params: &sig.params,
+                return_type: sig.return_type.as_deref(),
+            }),
+            TSSignature::TSCallSignatureDeclaration(sig) => Some(Self {
+                name: "call".into(),
+                r#static: true,
+                span: sig.span,
+                type_parameters: sig.type_parameters.as_deref(),
+                this_param: sig.this_param.as_deref(),
+                params: &sig.params,
+                return_type: sig.return_type.as_deref(),
+            }),
+            TSSignature::TSConstructSignatureDeclaration(sig) => Some(Self {
+                name: "new".into(),
+                r#static: true,
+                span: sig.span,
+                type_parameters: sig.type_parameters.as_deref(),
+                this_param: None,
+                params: &sig.params,
+                return_type: sig.return_type.as_deref(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Call and construct signatures are marked `static` so they never share a group with a
+    /// method named `call` or `new`.
+    fn is_overload_of(&self, other: &Self) -> bool {
+        self.name == other.name && self.r#static == other.r#static
+    }
+}
+
+enum Unification<'a, 'b> {
+    /// `f(a)` and `f(a, b)` can become `f(a, b?)`.
+    SingleParameter(&'b FormalParameter<'a>),
+    /// `f(a)` and `f(a, ...b)` can become `f(a, ...b)`.
+    RestParameter(Span),
+}
+
+/// Checks whether `short` is made redundant by `long`, which accepts one more trailing
+/// parameter or a rest parameter.
+fn unify<'a, 'b>(
+    short: &Signature<'a, 'b>,
+    long: &Signature<'a, 'b>,
+) -> Option<Unification<'a, 'b>> {
+    if !is_same(short.type_parameters, long.type_parameters)
+        || !is_same(short.this_param, long.this_param)
+        || !is_same(short.return_type, long.return_type)
+        || short.params.rest.is_some()
+    {
+        return None;
+    }
+
+    let short_items = &short.params.items;
+    let long_items = &long.params.items;
+    if !short_items.iter().zip(long_items.iter()).all(|(a, b)| is_same_parameter(a, b)) {
+        return None;
+    }
+
+    match (long_items.len() - short_items.len(), &long.params.rest) {
+        (0, Some(rest)) => Some(Unification::RestParameter(rest.span)),
+        (1, None) => long_items.last().map(Unification::SingleParameter),
+        _ => None,
+    }
+}
+
+fn is_same<T: ContentEq>(a: Option<&T>, b: Option<&T>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.content_eq(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Parameter names do not matter, only their types and whether they are optional.
+fn is_same_parameter(a: &FormalParameter, b: &FormalParameter) -> bool {
+    a.pattern.optional == b.pattern.optional
+        && is_same(a.pattern.type_annotation.as_deref(), b.pattern.type_annotation.as_deref())
+}
+
+fn check_and_report(signatures: &[Signature], ctx: &LintContext<'_>) {
+    let mut reported = vec![false; signatures.len()];
+
+    for (i, first) in signatures.iter().enumerate() {
+        for (j, second) in signatures.iter().enumerate().skip(i + 1) {
+            if reported[i] || reported[j] || !first.is_overload_of(second) {
+                continue;
+            }
+
+            let arity = |sig: &Signature| (sig.params.items.len(), sig.params.rest.is_some());
+            let (redundant, unification) = if arity(first) <= arity(second) {
+                (i, unify(first, second))
+            } else {
+                (j, unify(second, first))
+            };
+            let Some(unification) = unification else {
+                continue;
+            };
+
+            reported[redundant] = true;
+            let span = signatures[redundant].span;
+            let removal = removal_span(span, ctx);
+            let can_fix = !ctx.has_comments_between(removal);
+            match unification {
+                Unification::SingleParameter(param) => {
+                    let diagnostic = omitting_single_parameter_diagnostic(span, param.span);
+                    if !can_fix {
+                        ctx.diagnostic(diagnostic);
+                    } else if param.pattern.optional {
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            fixer.delete_range(removal)
+                        });
+                    } else if let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind
+                    {
+                        let name = binding_identifier_span(&param.pattern, ident);
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            fixer
+                                .delete_range(removal)
+                                .extend(fixer.insert_text_after_range(name, "?"))
+                        });
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
+                }
+                Unification::RestParameter(param) => {
+                    let diagnostic = omitting_rest_parameter_diagnostic(span, param);
+                    if can_fix {
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            fixer.delete_range(removal)
+                        });
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Extends the span of an overload over the whitespace before it, or after it if it is at the
+/// start of the file, so that removing it does not leave an empty line behind.
+#[expect(clippy::cast_possible_truncation)]
+fn removal_span(span: Span, ctx: &LintContext) -> Span {
+    let source_text = ctx.source_text();
+    let before = &source_text[..span.start as usize];
+    let start = before.trim_end().len();
+    if start > 0 {
+        return Span::new(start as u32, span.end);
+    }
+    let after = &source_text[span.end as usize..];
+    let end = source_text.len() - after.trim_start().len();
+    Span::new(span.start, end as u32)
+}
+
+impl Rule for UnifiedSignatures {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let signatures: Vec<Signature> = match node.kind() {
+            AstKind::Program(program) => {
+                program.body.iter().filter_map(Signature::from_statement).collect()
+            }
+            AstKind::TSModuleBlock(block) => {
+                block.body.iter().filter_map(Signature::from_statement).collect()
+            }
+            AstKind::BlockStatement(stmt) => {
+                stmt.body.iter().filter_map(Signature::from_statement).collect()
+            }
+            AstKind::FunctionBody(body) => {
+                body.statements.iter().filter_map(Signature::from_statement).collect()
+            }
+            AstKind::Class(class) => {
+                class.body.body.iter().filter_map(Signature::from_class_element).collect()
+            }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                decl.body.body.iter().filter_map(Signature::from_ts_signature).collect()
+            }
+            AstKind::TSTypeLiteral(literal) => {
+                literal.members.iter().filter_map(Signature::from_ts_signature).collect()
+            }
+            _ => return,
+        };
+
+        if signatures.len() > 1 {
+            check_and_report(&signatures, ctx);
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "
+        function f(a: string, b?: number): void;
+        function f(a: string, b?: number): void {}
+        ",
+        "
+        function f(a: string): string;
+        function f(a: string, b: number): number;
+        function f(a: string, b?: number): string | number {}
+        ",
+        "
+        function f(a: string): void;
+        function f(a: number, b: number): void;
+        ",
+        "
+        function f(a: string): void;
+        function f(a: string, b: number, c: number): void;
+        ",
+        "
+        function f<T>(a: T): void;
+        function f(a: string, b: number): void;
+        ",
+        "
+        function f(a: string): void;
+        function g(a: string, b: number): void;
+        ",
+        "
+        function f(...args: string[]): void;
+        function f(a: string, ...args: string[]): void;
+        ",
+        "
+        interface I {
+            foo(a: string): void;
+            bar(a: string, b: number): void;
+        }
+        ",
+        "
+        class C {
+            foo(a: string): void;
+            static foo(a: string, b: number): void;
+            foo(a?: string, b?: number) {}
+        }
+        ",
+        "
+        interface I {
+            (a: string): void;
+            new (a: string, b: number): I;
+        }
+        ",
+    ];
+
+    let fail = vec![
+        "
+        function f(a: string): void;
+        function f(a: string, b: number): void;
+        ",
+        "
+        export function f(): void;
+        export function f(...args: string[]): void;
+        ",
+        "
+        function f(a: string, b: number): void;
+        function f(a: string): void;
+        function f(a: string, b?: number) {}
+        ",
+        "
+        declare namespace N {
+            function f(a: string): void;
+            function f(a: string, b: number): void;
+        }
+        ",
+        "
+        interface I {
+            foo(a: string): void;
+            foo(a: string, b: number): void;
+        }
+        ",
+        "
+        type T = {
+            (a: string): void;
+            (a: string, ...rest: number[]): void;
+        };
+        ",
+        "
+        class C {
+            foo(x: number): void;
+            foo(y: number, z: string): void;
+            foo(x?: number, z?: string) {}
+        }
+        ",
+        "
+        function f(a: string): void;
+        function f(a: string, { b }: { b: number }): void;
+        ",
+        "
+        interface I {
+            // Called with just a string
+            foo(a: string): void;
+            foo(a: string, b: number): void;
+        }
+        ",
+    ];
+
+    let fix = vec![
+        (
+            "function f(a: string): void;\nfunction f(a: string, b: number): void;",
+            "function f(a: string, b?: number): void;",
+        ),
+        (
+            "function f(a: string): void;\nfunction f(a: string, b?: number): void;",
+            "function f(a: string, b?: number): void;",
+        ),
+        (
+            "export function f(): void;\nexport function f(...args: string[]): void;",
+            "export function f(...args: string[]): void;",
+        ),
+        (
+            "interface I {\n    foo(a: string): void;\n    foo(a: string, b: number): void;\n}",
+            "interface I {\n    foo(a: string, b?: number): void;\n}",
+        ),
+        (
+            "class C {\n    foo(x: number, z: string): void;\n    foo(y: number): void;\n    foo(x?: number, z?: string) {}\n}",
+            "class C {\n    foo(x: number, z?: string): void;\n    foo(x?: number, z?: string) {}\n}",
+        ),
+    ];
+
+    Tester::new(UnifiedSignatures::NAME, UnifiedSignatures::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -99,14 +99,14 @@ declare_oxc_lint!(
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
-    /// if (arr instanceof Array) { … }
-    /// if (el instanceof HTMLElement) { … }
+    /// if (arr instanceof Array) { /* … */ }
+    /// if (el instanceof HTMLElement) { /* … */ }
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
-    /// if (Array.isArray(arr)) { … }
-    /// if (el?.nodeType === 1) { … }
+    /// if (Array.isArray(arr)) { /* … */ }
+    /// if (el?.nodeType === 1) { /* … */ }
     /// ```
     NoInstanceofBuiltins,
     unicorn,
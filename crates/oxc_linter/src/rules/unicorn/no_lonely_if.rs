@@ -1,7 +1,13 @@
-use oxc_ast::AstKind;
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, IfStatement, Statement},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::LogicalOperator;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -42,7 +48,8 @@ declare_oxc_lint!(
     /// ```
     NoLonelyIf,
     unicorn,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for NoLonelyIf {
@@ -57,7 +64,7 @@ impl Rule for NoLonelyIf {
 
         let parent = ctx.nodes().parent_node(node.id());
 
-        let parent_if_stmt_span = match parent.kind() {
+        let parent_if_stmt = match parent.kind() {
             AstKind::BlockStatement(block_stmt) => {
                 if block_stmt.body.len() != 1 {
                     return;
@@ -71,25 +78,71 @@ impl Rule for NoLonelyIf {
                 if parent_if_stmt.alternate.is_some() {
                     return;
                 }
-                parent_if_stmt.span
+                parent_if_stmt
             }
             AstKind::IfStatement(parent_if_stmt) => {
                 if parent_if_stmt.alternate.is_some() {
                     return;
                 }
 
-                parent_if_stmt.span
+                parent_if_stmt
             }
             _ => return,
         };
 
-        ctx.diagnostic(no_lonely_if_diagnostic(
+        let diagnostic = no_lonely_if_diagnostic(
             Span::sized(if_stmt.span.start, 2),
-            Span::sized(parent_if_stmt_span.start, 2),
-        ));
+            Span::sized(parent_if_stmt.span.start, 2),
+        );
+        if ctx.has_comments_between(parent_if_stmt.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let outer_test = test_text(&parent_if_stmt.test, ctx);
+            let inner_test = test_text(&if_stmt.test, ctx);
+            let mut consequent = ctx.source_range(if_stmt.consequent.span()).to_string();
+            if needs_semicolon(parent_if_stmt, if_stmt, ctx) {
+                consequent.push(';');
+            }
+            // `if (a && b);` rather than `if (a && b) ;`
+            let separator = if consequent.starts_with(';') { "" } else { " " };
+            fixer.replace(
+                parent_if_stmt.span,
+                format!("if ({outer_test} && {inner_test}){separator}{consequent}"),
+            )
+        });
     }
 }
 
+/// The source text of `test` as an operand of `&&`.
+fn test_text<'a>(test: &Expression, ctx: &LintContext<'a>) -> Cow<'a, str> {
+    let text = ctx.source_range(test.span());
+    let needs_parentheses = match test {
+        Expression::LogicalExpression(logical) => logical.operator != LogicalOperator::And,
+        Expression::ConditionalExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::SequenceExpression(_)
+        | Expression::ArrowFunctionExpression(_) => true,
+        _ => false,
+    };
+    if needs_parentheses { format!("({text})").into() } else { text.into() }
+}
+
+/// Whether the merged `if` needs a trailing semicolon, because the outer block was the only
+/// thing separating the inner consequent from a following line starting with e.g. `[` or `(`.
+fn needs_semicolon(parent_if_stmt: &IfStatement, if_stmt: &IfStatement, ctx: &LintContext) -> bool {
+    if !matches!(parent_if_stmt.consequent, Statement::BlockStatement(_))
+        || matches!(if_stmt.consequent, Statement::BlockStatement(_))
+        || ctx.source_range(if_stmt.consequent.span()).ends_with(';')
+    {
+        return false;
+    }
+    let rest = &ctx.source_text()[parent_if_stmt.span.end as usize..];
+    rest.trim_start().chars().next().is_some_and(|c| matches!(c, '[' | '(' | '/' | '+' | '-' | '`'))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -207,5 +260,73 @@ fn test() {
     ",
     ];
 
-    Tester::new(NoLonelyIf::NAME, NoLonelyIf::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (
+            r"
+        if (a) {
+            if (b) {
+                foo();
+            }
+        }
+    ",
+            r"
+        if (a && b) {
+                foo();
+            }
+    ",
+        ),
+        (r"if (a) if (b) foo();", r"if (a && b) foo();"),
+        (r"if (a) { if (b) foo() }", r"if (a && b) foo()"),
+        (r"if (a) if (b);", r"if (a && b);"),
+        (r"if (a || b) { if (c ?? d) foo(); }", r"if ((a || b) && (c ?? d)) foo();"),
+        (r"if (a ? b : c) if (d = e) foo();", r"if ((a ? b : c) && (d = e)) foo();"),
+        (r"if (a, b) if (() => c) foo();", r"if ((a, b) && (() => c)) foo();"),
+        (r"if ((a || b)) if (c && d) foo();", r"if ((a || b) && c && d) foo();"),
+        (
+            r"async function f() { if (await a) if (a.b) foo(); }",
+            r"async function f() { if (await a && a.b) foo(); }",
+        ),
+        (r"if (!a) if (b === c) foo();", r"if (!a && b === c) foo();"),
+        (
+            r"
+        if (a) {
+            if (b) foo()
+        }
+        [].forEach(bar)
+    ",
+            r"
+        if (a && b) foo();
+        [].forEach(bar)
+    ",
+        ),
+        (
+            r"
+        if (a) {
+            if (b) foo()
+        }
+        ;[].forEach(bar)
+    ",
+            r"
+        if (a && b) foo()
+        ;[].forEach(bar)
+    ",
+        ),
+        (
+            r"
+        if (a) {
+            if (b) foo()
+        }
+        bar()
+    ",
+            r"
+        if (a && b) foo()
+        bar()
+    ",
+        ),
+        (r"if (a) { /* comment */ if (b) foo(); }", r"if (a) { /* comment */ if (b) foo(); }"),
+    ];
+
+    Tester::new(NoLonelyIf::NAME, NoLonelyIf::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -16,7 +16,7 @@ use serde_json::{Value, json};
 use crate::{
     AllowWarnDeny, BuiltinLintPlugins, ConfigStore, ConfigStoreBuilder, LintPlugins, LintService,
     LintServiceOptions, Linter, Oxlintrc, RuleEnum,
-    fixer::{FixKind, Fixer},
+    fixer::{Fix, FixKind, Fixer, Message, PossibleFixes},
     options::LintOptions,
     rules::RULES,
     service::RuntimeFileSystem,
@@ -554,6 +554,12 @@ impl Tester {
         }
 
         if fix_kind.is_some() {
+            if let Some((first, second)) = find_conflicting_fixes(&result, fix_index) {
+                panic!(
+                    "fixes of two diagnostics have the same span, so the order they are applied in is undefined:\n\n-------- source --------\n\n{source_text}\n\n-------- fixes --------\n\n{:?} -> {:?}\n{:?} -> {:?}\n",
+                    first.span, first.content, second.span, second.content
+                );
+            }
             let fix_result = Fixer::new(source_text, result).with_fix_index(fix_index).fix();
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
@@ -587,3 +593,85 @@ impl Tester {
             })
     }
 }
+
+/// Finds the fixes of two diagnostics which the [`Fixer`] doesn't apply in a well-defined order.
+///
+/// The [`Fixer`] applies fixes in the order of their spans and skips a fix which overlaps with an
+/// already applied one, leaving it for another run, so fixes may overlap. Fixes with the same
+/// span are applied in an arbitrary order though: only an arbitrary one of two different
+/// replacements is applied, and two insertions at the same position are both applied.
+fn find_conflicting_fixes<'m, 'a>(
+    messages: &'m [Message<'a>],
+    fix_index: u8,
+) -> Option<(&'m Fix<'a>, &'m Fix<'a>)> {
+    let mut fixes = messages
+        .iter()
+        .filter_map(|message| {
+            let fix = match &message.fixes {
+                PossibleFixes::None => None,
+                PossibleFixes::Single(fix) => Some(fix),
+                PossibleFixes::Multiple(fixes) => fixes.get(fix_index as usize),
+            };
+            // The fixer orders the fixes by the span of all fixes of a diagnostic.
+            fix.map(|fix| (message.fixes.span(), fix))
+        })
+        .collect::<Vec<_>>();
+    fixes.sort_unstable_by_key(|(span, _)| *span);
+
+    fixes.windows(2).find_map(|pair| {
+        let [(first_span, first), (second_span, second)] = pair else { unreachable!() };
+        let conflicts = first_span == second_span
+            && (first.span != second.span
+                || first.content != second.content
+                || first.span.is_empty());
+        conflicts.then_some((*first, *second))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+
+    use super::find_conflicting_fixes;
+    use crate::fixer::{Fix, Message, PossibleFixes};
+
+    fn message(span: Span, content: &'static str) -> Message<'static> {
+        Message::new(
+            OxcDiagnostic::warn("test").with_label(span),
+            PossibleFixes::Single(Fix::new(content, span)),
+        )
+    }
+
+    #[test]
+    fn detect_conflicting_fixes() {
+        // `let foo = 1;`: only one of the replacements of `foo` can be applied
+        let messages = vec![
+            message(Span::new(4, 7), "bar"),
+            message(Span::new(0, 3), "const"),
+            message(Span::new(4, 7), "baz"),
+        ];
+        let (first, second) = find_conflicting_fixes(&messages, 0).unwrap();
+        assert_eq!(first.span, Span::new(4, 7));
+        assert_eq!(second.span, Span::new(4, 7));
+        assert_ne!(first.content, second.content);
+
+        // `foo()`: both insertions are applied, in an arbitrary order
+        let messages = vec![message(Span::new(5, 5), ";"), message(Span::new(5, 5), ";")];
+        assert!(find_conflicting_fixes(&messages, 0).is_some());
+    }
+
+    #[test]
+    fn allow_overlapping_fixes() {
+        // `let a = 1, b = 2;`: the fixer removes `a = 1, ` and leaves `b = 2` for another run
+        let messages = vec![
+            message(Span::new(4, 11), ""),
+            message(Span::new(9, 16), ""),
+            message(Span::new(4, 16), "c = 3"),
+            message(Span::new(0, 3), "const"),
+            message(Span::new(4, 11), ""),
+            message(Span::new(16, 16), ";"),
+        ];
+        assert!(find_conflicting_fixes(&messages, 0).is_none());
+    }
+}
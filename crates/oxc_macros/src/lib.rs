@@ -21,6 +21,9 @@ mod declare_oxc_lint;
 /// concise. Remember, end users will depend on it to understand the purpose of
 /// the lint and how to use it!
 ///
+/// Code examples in `js`, `jsx`, `ts` and `tsx` blocks must be syntactically
+/// valid. Tests will fail if they don't parse.
+///
 /// ## Category
 /// Please see the [rule category
 /// documentation](https://oxc.rs/docs/contribute/linter.html#rule-category) for